@@ -13,9 +13,16 @@ fn main() {
         .out_dir(&out_dir)
         .type_attribute(".", "#[derive(serde::Serialize, serde::Deserialize)]")
         .extern_path(".google.protobuf.Timestamp", "::prost_wkt_types::Timestamp")
+        .type_attribute(".document_collection.DocumentItem", "#[serde(default)]")
+        .type_attribute(".document_collection.Item1", "#[serde(default)]")
+        .type_attribute(".document_collection.Item0", "#[serde(default)]")
         .extern_path(".google.protobuf.Struct", "::prost_wkt_types::Struct")
+        .extern_path(".google.protobuf.Value", "::prost_wkt_types::Value")
         .file_descriptor_set_path(&descriptor_file)
-        .compile(&["protos/document_collection.proto"], &["protos"])
+        .compile(
+            &["protos/document_collection.proto", "protos/user.proto"],
+            &["protos"],
+        )
         .unwrap_or_else(|e| panic!("Failed to compile protos {:?}", e));
     let descriptor_bytes = std::fs::read(descriptor_file.clone()).unwrap();
 
@@ -23,9 +30,10 @@ fn main() {
 
     prost_wkt_build::add_serde(out_dir, descriptor);
 
-    Command::new("cargo").args(&["fmt"]).status().unwrap();
+    Command::new("cargo").args(["fmt"]).status().unwrap();
 
     println!("cargo:rerun-if-changed=protos/document_collection.proto");
+    println!("cargo:rerun-if-changed=protos/user.proto");
 
     fs::remove_file(descriptor_file).unwrap();
     fs::remove_file("src/pb/google.protobuf.rs").unwrap();
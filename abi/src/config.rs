@@ -4,10 +4,23 @@ use serde::{Deserialize, Serialize};
 
 use crate::Error;
 
+/// longest wait between two webhook retries that may be configured, 30 days
+const MAX_BACKOFF_SECS: u64 = 30 * 24 * 3600;
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Config {
     pub db: DbConfig,
     pub server: ServerConfig,
+    #[serde(default)]
+    pub trash: TrashConfig,
+    #[serde(default)]
+    pub changes: ChangesConfig,
+    #[serde(default)]
+    pub auth: AuthConfig,
+    #[serde(default)]
+    pub webhooks: WebhookConfig,
+    #[serde(default)]
+    pub idempotency: IdempotencyConfig,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -31,10 +44,188 @@ pub struct ServerConfig {
     pub port: u16,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrashConfig {
+    /// how long deleted documents are kept before they are purged, unless their collection has its own retention
+    #[serde(default = "default_retention_days")]
+    pub retention_days: u32,
+    /// how often the purge task runs, at least once a second
+    #[serde(default = "default_purge_interval_secs")]
+    pub purge_interval_secs: u64,
+}
+
+fn default_retention_days() -> u32 {
+    30
+}
+
+fn default_purge_interval_secs() -> u64 {
+    3600
+}
+
+impl Default for TrashConfig {
+    fn default() -> Self {
+        Self {
+            retention_days: default_retention_days(),
+            purge_interval_secs: default_purge_interval_secs(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChangesConfig {
+    /// how long changes are kept for watchers, consumers and document history, removed by the purge
+    /// task, the current revision of a document is always kept
+    #[serde(default = "default_changes_retention_days")]
+    pub retention_days: u32,
+}
+
+fn default_changes_retention_days() -> u32 {
+    90
+}
+
+impl Default for ChangesConfig {
+    fn default() -> Self {
+        Self {
+            retention_days: default_changes_retention_days(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WebhookConfig {
+    /// how often new changes are turned into deliveries and due deliveries are posted, at least
+    /// once a second
+    #[serde(default = "default_poll_interval_secs")]
+    pub poll_interval_secs: u64,
+    /// attempts after which a failing delivery is moved to the dead letters
+    #[serde(default = "default_max_attempts")]
+    pub max_attempts: u32,
+    /// wait before the first retry, doubled for every further one
+    #[serde(default = "default_backoff_secs")]
+    pub backoff_secs: u64,
+    /// longest wait between two retries, at most 30 days
+    #[serde(default = "default_max_backoff_secs")]
+    pub max_backoff_secs: u64,
+    /// how long to wait for an endpoint to respond
+    #[serde(default = "default_timeout_secs")]
+    pub timeout_secs: u64,
+    /// post to loopback, link-local and private addresses too, for endpoints on the same network
+    #[serde(default)]
+    pub allow_private_hosts: bool,
+}
+
+fn default_poll_interval_secs() -> u64 {
+    5
+}
+
+fn default_max_attempts() -> u32 {
+    8
+}
+
+fn default_backoff_secs() -> u64 {
+    10
+}
+
+fn default_max_backoff_secs() -> u64 {
+    3600
+}
+
+fn default_timeout_secs() -> u64 {
+    10
+}
+
+impl Default for WebhookConfig {
+    fn default() -> Self {
+        Self {
+            poll_interval_secs: default_poll_interval_secs(),
+            max_attempts: default_max_attempts(),
+            backoff_secs: default_backoff_secs(),
+            max_backoff_secs: default_max_backoff_secs(),
+            timeout_secs: default_timeout_secs(),
+            allow_private_hosts: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IdempotencyConfig {
+    /// how long a retry with the same idempotency key returns the document of the first attempt
+    #[serde(default = "default_idempotency_ttl_secs")]
+    pub ttl_secs: u64,
+}
+
+fn default_idempotency_ttl_secs() -> u64 {
+    86400
+}
+
+impl Default for IdempotencyConfig {
+    fn default() -> Self {
+        Self {
+            ttl_secs: default_idempotency_ttl_secs(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuthConfig {
+    /// keys bearer tokens may be signed with, without any key every request is rejected
+    #[serde(default)]
+    pub keys: Vec<AuthKey>,
+    /// required `iss` claim, if set
+    #[serde(default)]
+    pub issuer: Option<String>,
+    /// required `aud` claim, if set
+    #[serde(default)]
+    pub audience: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuthKey {
+    /// matched against the `kid` header of a token, keys without one match any token
+    #[serde(default)]
+    pub kid: Option<String>,
+    #[serde(flatten)]
+    pub key: AuthKeyKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "algorithm")]
+pub enum AuthKeyKind {
+    /// shared secret
+    HS256 { secret: String },
+    /// PEM encoded RSA public key
+    RS256 { public_key: String },
+    /// PEM encoded Ed25519 public key
+    EdDSA { public_key: String },
+}
+
 impl Config {
     pub fn load(filename: impl AsRef<Path>) -> Result<Self, Error> {
         let config = fs::read_to_string(filename.as_ref()).map_err(|_| Error::ConfigReadError)?;
-        serde_yaml::from_str(&config).map_err(|_| Error::ConfigParseError)
+        let config: Self = serde_yaml::from_str(&config).map_err(|_| Error::ConfigParseError)?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// reject settings the server cannot run with
+    fn validate(&self) -> Result<(), Error> {
+        if self.trash.purge_interval_secs == 0 {
+            return Err(Error::InvalidConfig(
+                "trash.purge_interval_secs must be at least 1".to_string(),
+            ));
+        }
+        if self.webhooks.poll_interval_secs == 0 {
+            return Err(Error::InvalidConfig(
+                "webhooks.poll_interval_secs must be at least 1".to_string(),
+            ));
+        }
+        if self.webhooks.max_backoff_secs > MAX_BACKOFF_SECS {
+            return Err(Error::InvalidConfig(format!(
+                "webhooks.max_backoff_secs must be at most {}",
+                MAX_BACKOFF_SECS
+            )));
+        }
+        Ok(())
     }
 }
 
@@ -63,3 +254,38 @@ impl ServerConfig {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(extra: &str) -> Config {
+        let config = format!(
+            "db:\n  host: localhost\n  port: 5432\n  user: postgres\n  password: ''\n  dbname: dc\n\
+            server:\n  host: 0.0.0.0\n  port: 50051\n{}",
+            extra
+        );
+        serde_yaml::from_str(&config).unwrap()
+    }
+
+    #[test]
+    fn zero_purge_interval_should_be_rejected() {
+        assert!(parse("").validate().is_ok());
+        assert!(matches!(
+            parse("trash:\n  purge_interval_secs: 0\n").validate(),
+            Err(Error::InvalidConfig(_))
+        ));
+    }
+
+    #[test]
+    fn webhook_intervals_out_of_bounds_should_be_rejected() {
+        assert!(matches!(
+            parse("webhooks:\n  poll_interval_secs: 0\n").validate(),
+            Err(Error::InvalidConfig(_))
+        ));
+        assert!(matches!(
+            parse("webhooks:\n  max_backoff_secs: 18446744073709551615\n").validate(),
+            Err(Error::InvalidConfig(_))
+        ));
+    }
+}
@@ -1,7 +1,9 @@
+mod status;
+
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
     #[error("Database error")]
-    DbError(#[from] sqlx::Error),
+    DbError(sqlx::Error),
 
     #[error("Failed to read configuration file")]
     ConfigReadError,
@@ -9,31 +11,123 @@ pub enum Error {
     #[error("Failed to parse configuration file")]
     ConfigParseError,
 
+    #[error("Invalid configuration: {0}")]
+    InvalidConfig(String),
+
+    #[error("Invalid request: {}", describe(.0))]
+    InvalidRequest(Vec<FieldViolation>),
+
     #[error("Invalid user id: {0}")]
     InvalidUserId(String),
 
+    #[error("Invalid document id: {0}")]
+    InvalidDocumentId(String),
+
+    #[error("Invalid document data: {0}")]
+    InvalidData(String),
+
+    #[error("Invalid document item: {0}")]
+    InvalidItem(String),
+
+    #[error("Invalid patch: {0}")]
+    InvalidPatch(String),
+
+    #[error("Invalid filter: {0}")]
+    InvalidFilter(String),
+
+    #[error("Invalid query: {0}")]
+    InvalidQuery(String),
+
     #[error("Invalid start or end time range")]
     InvalidTime,
 
+    #[error("Invalid time zone: {0}")]
+    InvalidTimeZone(String),
+
     #[error("No document found by the given id")]
     NotFound,
 
+    #[error("No user found by the given id")]
+    UserNotFound,
+
+    #[error("Invalid user: {0}")]
+    InvalidUser(String),
+
+    #[error("User is deactivated: {0}")]
+    InactiveUser(String),
+
+    #[error("Email is already taken: {0}")]
+    EmailTaken(String),
+
+    #[error("No schema registered for document type: {0}")]
+    SchemaNotFound(String),
+
+    #[error("Invalid schema: {0}")]
+    InvalidSchema(String),
+
+    #[error("Document data does not match its schema: {}", describe(.0))]
+    SchemaViolation(Vec<FieldViolation>),
+
+    #[error("No collection found by the given name: {0}")]
+    CollectionNotFound(String),
+
+    #[error("Collection already exists: {0}")]
+    CollectionExists(String),
+
+    #[error("Collection still has documents: {0}")]
+    CollectionNotEmpty(String),
+
+    #[error("Invalid collection: {0}")]
+    InvalidCollection(String),
+
+    #[error("No webhook found by the given id: {0}")]
+    WebhookNotFound(String),
+
+    #[error("Invalid webhook: {0}")]
+    InvalidWebhook(String),
+
+    #[error("No organization or store found by the given id")]
+    OrganizationNotFound,
+
+    #[error("No api key found by the given id")]
+    ApiKeyNotFound,
+
+    #[error("Unauthenticated: {0}")]
+    Unauthenticated(String),
+
+    #[error("Permission denied: {0}")]
+    PermissionDenied(String),
+
+    #[error("Document was modified concurrently: expected version {expected}, found {actual}")]
+    Conflict { expected: i64, actual: i64 },
+
+    #[error("Not run, another request of the atomic batch failed")]
+    BatchAborted,
+
     #[error("unknown error")]
     Unknown,
 }
 
-impl From<Error> for tonic::Status {
-    fn from(e: Error) -> Self {
+/// One problem with one field of a request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldViolation {
+    pub field: String,
+    pub description: String,
+}
+
+fn describe(violations: &[FieldViolation]) -> String {
+    violations
+        .iter()
+        .map(|v| format!("{}: {}", v.field, v.description))
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+impl From<sqlx::Error> for Error {
+    fn from(e: sqlx::Error) -> Self {
         match e {
-            Error::ConfigReadError | Error::ConfigParseError => {
-                tonic::Status::internal(e.to_string())
-            }
-            Error::DbError(e) => tonic::Status::internal(e.to_string()),
-            Error::InvalidTime | Error::InvalidUserId(_) => {
-                tonic::Status::invalid_argument(e.to_string())
-            }
-            Error::NotFound => tonic::Status::not_found("No document found by the given id"),
-            Error::Unknown => tonic::Status::unknown("unknown error"),
+            sqlx::Error::RowNotFound => Error::NotFound,
+            e => Error::DbError(e),
         }
     }
 }
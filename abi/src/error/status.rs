@@ -0,0 +1,252 @@
+use std::{collections::HashMap, time::Duration};
+
+use tonic::Code;
+use tonic_types::{ErrorDetails, StatusExt};
+use tracing::error;
+use uuid::Uuid;
+
+use super::Error;
+
+/// domain of the ErrorInfo attached to every error
+const DOMAIN: &str = "dc";
+/// how long clients should wait before retrying after a transient database failure
+const RETRY_DELAY: Duration = Duration::from_secs(1);
+
+impl Error {
+    /// Stable, machine readable code of the error, sent as the ErrorInfo reason.
+    pub fn reason(&self) -> &'static str {
+        match self {
+            Error::DbError(_) => "DB_ERROR",
+            Error::ConfigReadError => "CONFIG_READ_ERROR",
+            Error::ConfigParseError => "CONFIG_PARSE_ERROR",
+            Error::InvalidConfig(_) => "INVALID_CONFIG",
+            Error::InvalidRequest(_) => "INVALID_REQUEST",
+            Error::InvalidUserId(_) => "INVALID_USER_ID",
+            Error::InvalidDocumentId(_) => "INVALID_DOCUMENT_ID",
+            Error::InvalidData(_) => "INVALID_DATA",
+            Error::InvalidItem(_) => "INVALID_ITEM",
+            Error::InvalidPatch(_) => "INVALID_PATCH",
+            Error::InvalidFilter(_) => "INVALID_FILTER",
+            Error::InvalidQuery(_) => "INVALID_QUERY",
+            Error::InvalidTime => "INVALID_TIME",
+            Error::InvalidTimeZone(_) => "INVALID_TIME_ZONE",
+            Error::NotFound => "DOCUMENT_NOT_FOUND",
+            Error::UserNotFound => "USER_NOT_FOUND",
+            Error::InvalidUser(_) => "INVALID_USER",
+            Error::InactiveUser(_) => "INACTIVE_USER",
+            Error::EmailTaken(_) => "EMAIL_TAKEN",
+            Error::SchemaNotFound(_) => "SCHEMA_NOT_FOUND",
+            Error::InvalidSchema(_) => "INVALID_SCHEMA",
+            Error::SchemaViolation(_) => "SCHEMA_VIOLATION",
+            Error::CollectionNotFound(_) => "COLLECTION_NOT_FOUND",
+            Error::CollectionExists(_) => "COLLECTION_EXISTS",
+            Error::CollectionNotEmpty(_) => "COLLECTION_NOT_EMPTY",
+            Error::InvalidCollection(_) => "INVALID_COLLECTION",
+            Error::WebhookNotFound(_) => "WEBHOOK_NOT_FOUND",
+            Error::InvalidWebhook(_) => "INVALID_WEBHOOK",
+            Error::OrganizationNotFound => "ORGANIZATION_NOT_FOUND",
+            Error::ApiKeyNotFound => "API_KEY_NOT_FOUND",
+            Error::Unauthenticated(_) => "UNAUTHENTICATED",
+            Error::PermissionDenied(_) => "PERMISSION_DENIED",
+            Error::Conflict { .. } => "VERSION_CONFLICT",
+            Error::BatchAborted => "BATCH_ABORTED",
+            Error::Unknown => "UNKNOWN",
+        }
+    }
+
+    fn code(&self) -> Code {
+        match self {
+            Error::DbError(_) if self.is_transient() => Code::Unavailable,
+            Error::DbError(_)
+            | Error::ConfigReadError
+            | Error::ConfigParseError
+            | Error::InvalidConfig(_) => Code::Internal,
+            Error::InvalidTime
+            | Error::InvalidTimeZone(_)
+            | Error::InvalidRequest(_)
+            | Error::InvalidUserId(_)
+            | Error::InvalidDocumentId(_)
+            | Error::InvalidData(_)
+            | Error::InvalidItem(_)
+            | Error::InvalidPatch(_)
+            | Error::InvalidFilter(_)
+            | Error::InvalidQuery(_)
+            | Error::InvalidSchema(_)
+            | Error::SchemaViolation(_)
+            | Error::InvalidCollection(_)
+            | Error::InvalidWebhook(_)
+            | Error::InvalidUser(_) => Code::InvalidArgument,
+            Error::NotFound
+            | Error::UserNotFound
+            | Error::SchemaNotFound(_)
+            | Error::CollectionNotFound(_)
+            | Error::WebhookNotFound(_)
+            | Error::OrganizationNotFound
+            | Error::ApiKeyNotFound => Code::NotFound,
+            Error::Conflict { .. } | Error::BatchAborted => Code::Aborted,
+            Error::InactiveUser(_) | Error::CollectionNotEmpty(_) => Code::FailedPrecondition,
+            Error::EmailTaken(_) | Error::CollectionExists(_) => Code::AlreadyExists,
+            Error::Unauthenticated(_) => Code::Unauthenticated,
+            Error::PermissionDenied(_) => Code::PermissionDenied,
+            Error::Unknown => Code::Unknown,
+        }
+    }
+
+    /// the request field an invalid argument is about
+    fn field(&self) -> Option<&'static str> {
+        match self {
+            Error::InvalidUserId(_) => Some("user_id"),
+            Error::InvalidDocumentId(_) => Some("id"),
+            Error::InvalidData(_) => Some("data"),
+            Error::InvalidItem(_) => Some("items"),
+            Error::InvalidPatch(_) => Some("patch"),
+            Error::InvalidFilter(_) => Some("query.filter"),
+            Error::InvalidQuery(_) => Some("query"),
+            Error::InvalidTime => Some("query.start"),
+            Error::InvalidTimeZone(_) => Some("query.time_zone"),
+            Error::InvalidSchema(_) => Some("schema"),
+            Error::InvalidCollection(_) => Some("collection"),
+            Error::InvalidWebhook(_) => Some("webhook"),
+            Error::InvalidUser(_) => Some("user"),
+            _ => None,
+        }
+    }
+
+    /// database failures that may go away if the request is retried
+    fn is_transient(&self) -> bool {
+        match self {
+            Error::DbError(sqlx::Error::PoolTimedOut | sqlx::Error::Io(_)) => true,
+            // serialization failure, deadlock, lock not available, admin shutdown
+            Error::DbError(sqlx::Error::Database(e)) => matches!(
+                e.code().as_deref(),
+                Some("40001" | "40P01" | "55P03" | "57P01")
+            ),
+            _ => false,
+        }
+    }
+
+    /// errors whose text must not reach the client
+    fn is_internal(&self) -> bool {
+        matches!(
+            self,
+            Error::DbError(_)
+                | Error::ConfigReadError
+                | Error::ConfigParseError
+                | Error::InvalidConfig(_)
+                | Error::Unknown
+        )
+    }
+}
+
+impl From<Error> for tonic::Status {
+    fn from(e: Error) -> Self {
+        let mut details = ErrorDetails::new();
+        let mut metadata = HashMap::new();
+
+        let message = if e.is_internal() {
+            let correlation_id = Uuid::new_v4().to_string();
+            error!(correlation_id, error = ?e, "request failed");
+            if e.is_transient() {
+                details.set_retry_info(Some(RETRY_DELAY));
+            }
+            metadata.insert("correlation_id".to_string(), correlation_id.clone());
+            format!("{}, correlation id {}", e, correlation_id)
+        } else {
+            e.to_string()
+        };
+
+        if let Error::Conflict { expected, actual } = &e {
+            metadata.insert("expected_version".to_string(), expected.to_string());
+            metadata.insert("actual_version".to_string(), actual.to_string());
+        }
+        if let Error::InvalidRequest(violations) | Error::SchemaViolation(violations) = &e {
+            for v in violations {
+                details.add_bad_request_violation(&v.field, &v.description);
+            }
+        }
+        if let Some(field) = e.field() {
+            details.add_bad_request_violation(field, e.to_string());
+        }
+        details.set_error_info(e.reason(), DOMAIN, metadata);
+
+        tonic::Status::with_error_details(e.code(), message, details)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FieldViolation;
+
+    #[test]
+    fn invalid_argument_should_carry_field_violation() {
+        let status = tonic::Status::from(Error::InvalidDocumentId("x".to_string()));
+        assert_eq!(status.code(), Code::InvalidArgument);
+
+        let info = status.get_details_error_info().unwrap();
+        assert_eq!(info.reason, "INVALID_DOCUMENT_ID");
+        assert_eq!(info.domain, DOMAIN);
+
+        let bad_request = status.get_details_bad_request().unwrap();
+        assert_eq!(bad_request.field_violations[0].field, "id");
+    }
+
+    #[test]
+    fn database_error_should_only_return_correlation_id() {
+        let status = tonic::Status::from(Error::DbError(sqlx::Error::Protocol(
+            "secret table layout".to_string(),
+        )));
+        assert_eq!(status.code(), Code::Internal);
+        assert!(!status.message().contains("secret"));
+
+        let info = status.get_details_error_info().unwrap();
+        let correlation_id = &info.metadata["correlation_id"];
+        assert!(status.message().contains(correlation_id.as_str()));
+        assert!(status.get_details_retry_info().is_none());
+    }
+
+    #[test]
+    fn transient_database_error_should_ask_for_retry() {
+        let status = tonic::Status::from(Error::DbError(sqlx::Error::PoolTimedOut));
+        assert_eq!(status.code(), Code::Unavailable);
+
+        let retry = status.get_details_retry_info().unwrap();
+        assert_eq!(retry.retry_delay, Some(RETRY_DELAY));
+    }
+
+    #[test]
+    fn conflict_should_carry_versions() {
+        let status = tonic::Status::from(Error::Conflict {
+            expected: 1,
+            actual: 2,
+        });
+        assert_eq!(status.code(), Code::Aborted);
+
+        let info = status.get_details_error_info().unwrap();
+        assert_eq!(info.reason, "VERSION_CONFLICT");
+        assert_eq!(info.metadata["actual_version"], "2");
+    }
+
+    #[test]
+    fn invalid_request_should_list_every_violation() {
+        let status = tonic::Status::from(Error::InvalidRequest(vec![
+            FieldViolation {
+                field: "id".to_string(),
+                description: "not a uuid".to_string(),
+            },
+            FieldViolation {
+                field: "data".to_string(),
+                description: "too deep".to_string(),
+            },
+        ]));
+        assert_eq!(status.code(), Code::InvalidArgument);
+
+        let bad_request = status.get_details_bad_request().unwrap();
+        let fields: Vec<_> = bad_request
+            .field_violations
+            .iter()
+            .map(|v| v.field.as_str())
+            .collect();
+        assert_eq!(fields, ["id", "data"]);
+    }
+}
@@ -4,7 +4,7 @@ mod pb;
 mod types;
 
 pub use config::*;
-pub use error::Error;
+pub use error::{Error, FieldViolation};
 pub use pb::*;
 pub use types::*;
 
@@ -12,6 +12,75 @@ pub struct Document {
     pub created_at: ::core::option::Option<::prost_wkt_types::Timestamp>,
     #[prost(message, optional, tag = "5")]
     pub updated_at: ::core::option::Option<::prost_wkt_types::Timestamp>,
+    #[prost(message, repeated, tag = "6")]
+    pub items: ::prost::alloc::vec::Vec<DocumentItem>,
+    /// computed by the server: sum of all item totals
+    #[prost(string, tag = "7")]
+    pub total: ::prost::alloc::string::String,
+    /// incremented on every update, used for optimistic concurrency control
+    #[prost(int64, tag = "8")]
+    pub version: i64,
+    /// set if the document is in the trash
+    #[prost(message, optional, tag = "9")]
+    pub deleted_at: ::core::option::Option<::prost_wkt_types::Timestamp>,
+    /// organization and store of the user who created the document
+    #[prost(string, tag = "10")]
+    pub org_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "11")]
+    pub store_id: ::prost::alloc::string::String,
+    /// the registered type the data conforms to, empty for untyped documents
+    #[prost(string, tag = "12")]
+    pub document_type: ::prost::alloc::string::String,
+    /// version of the schema the data was last validated against, 0 for untyped documents
+    #[prost(int32, tag = "13")]
+    pub schema_version: i32,
+    /// the collection the document lives in
+    #[prost(string, tag = "14")]
+    pub collection: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DocumentItem {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "2")]
+    pub items1: ::prost::alloc::vec::Vec<Item1>,
+    #[prost(message, repeated, tag = "3")]
+    pub items0: ::prost::alloc::vec::Vec<Item0>,
+    /// computed by the server
+    #[prost(string, tag = "4")]
+    pub total: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Item1 {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "2")]
+    pub items: ::prost::alloc::vec::Vec<Item0>,
+    /// computed by the server
+    #[prost(string, tag = "3")]
+    pub total: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Item0 {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(int32, tag = "2")]
+    pub quantity: i32,
+    /// decimal string, e.g. "12.50"
+    #[prost(string, tag = "3")]
+    pub price: ::prost::alloc::string::String,
+    /// computed by the server: quantity * price
+    #[prost(string, tag = "4")]
+    pub total: ::prost::alloc::string::String,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -19,6 +88,15 @@ pub struct Document {
 pub struct GetRequest {
     #[prost(string, tag = "1")]
     pub id: ::prost::alloc::string::String,
+    /// if set (non-zero), return the document as it was at this revision
+    #[prost(int64, tag = "2")]
+    pub revision: i64,
+    /// return the document even if it is in the trash
+    #[prost(bool, tag = "3")]
+    pub include_deleted: bool,
+    /// defaults to `documents`
+    #[prost(string, tag = "4")]
+    pub collection: ::prost::alloc::string::String,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -33,10 +111,83 @@ pub struct GetResponse {
 pub struct DocumentQuery {
     #[prost(string, tag = "1")]
     pub user_id: ::prost::alloc::string::String,
+    /// lower bound, unbounded if not set
     #[prost(message, optional, tag = "2")]
     pub start: ::core::option::Option<::prost_wkt_types::Timestamp>,
+    /// upper bound, in days mode it defaults to the end of today
     #[prost(message, optional, tag = "3")]
     pub end: ::core::option::Option<::prost_wkt_types::Timestamp>,
+    /// include documents that are in the trash
+    #[prost(bool, tag = "4")]
+    pub include_deleted: bool,
+    /// only return documents matching this filter
+    #[prost(message, optional, tag = "5")]
+    pub filter: ::core::option::Option<Filter>,
+    #[prost(enumeration = "TimeRangeMode", tag = "6")]
+    pub range_mode: i32,
+    /// the timestamp start and end apply to
+    #[prost(enumeration = "TimeField", tag = "7")]
+    pub time_field: i32,
+    /// in exact mode, include documents at exactly `end`
+    #[prost(bool, tag = "8")]
+    pub end_inclusive: bool,
+    /// IANA time zone the days of days mode are taken in, e.g. `Europe/Berlin`, defaults to UTC
+    #[prost(string, tag = "9")]
+    pub time_zone: ::prost::alloc::string::String,
+    /// only documents of this organization, all organizations if empty
+    #[prost(string, tag = "10")]
+    pub org_id: ::prost::alloc::string::String,
+    /// only documents of this store, all stores if empty
+    #[prost(string, tag = "11")]
+    pub store_id: ::prost::alloc::string::String,
+    /// only documents of this type, all types if empty
+    #[prost(string, tag = "12")]
+    pub document_type: ::prost::alloc::string::String,
+    /// defaults to `documents`
+    #[prost(string, tag = "13")]
+    pub collection: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Filter {
+    #[prost(oneof = "filter::Filter", tags = "1, 2, 3")]
+    pub filter: ::core::option::Option<filter::Filter>,
+}
+/// Nested message and enum types in `Filter`.
+pub mod filter {
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Filter {
+        #[prost(message, tag = "1")]
+        Field(super::FieldFilter),
+        /// every filter must match
+        #[prost(message, tag = "2")]
+        And(super::FilterList),
+        /// at least one filter must match
+        #[prost(message, tag = "3")]
+        Or(super::FilterList),
+    }
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FilterList {
+    #[prost(message, repeated, tag = "1")]
+    pub filters: ::prost::alloc::vec::Vec<Filter>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FieldFilter {
+    /// dot separated path starting with `data` or `items`, e.g. `data.store` or `items`
+    #[prost(string, tag = "1")]
+    pub path: ::prost::alloc::string::String,
+    #[prost(enumeration = "FilterOperator", tag = "2")]
+    pub op: i32,
+    #[prost(message, optional, tag = "3")]
+    pub value: ::core::option::Option<::prost_wkt_types::Value>,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -44,6 +195,93 @@ pub struct DocumentQuery {
 pub struct QueryRequest {
     #[prost(message, optional, tag = "1")]
     pub query: ::core::option::Option<DocumentQuery>,
+    /// defaults to created_at ascending
+    #[prost(message, optional, tag = "2")]
+    pub order_by: ::core::option::Option<OrderBy>,
+    /// maximum number of documents to return, list defaults to 50
+    #[prost(int32, tag = "3")]
+    pub page_size: i32,
+    /// next_page_token of a previous list call with the same query and order
+    #[prost(string, tag = "4")]
+    pub page_token: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OrderBy {
+    #[prost(enumeration = "OrderField", tag = "1")]
+    pub field: i32,
+    /// dot separated path starting with `data` or `items`, e.g. `data.store`
+    #[prost(string, tag = "2")]
+    pub path: ::prost::alloc::string::String,
+    #[prost(bool, tag = "3")]
+    pub descending: bool,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub documents: ::prost::alloc::vec::Vec<Document>,
+    /// empty if this is the last page
+    #[prost(string, tag = "2")]
+    pub next_page_token: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AggregateRequest {
+    /// the documents to aggregate, an empty user_id means every user
+    #[prost(message, optional, tag = "1")]
+    pub query: ::core::option::Option<DocumentQuery>,
+    #[prost(message, optional, tag = "2")]
+    pub group_by: ::core::option::Option<GroupBy>,
+    /// numeric fields to sum and average: `total` for the document total,
+    /// or a dot separated path starting with `data`, e.g. `data.amount`
+    #[prost(string, repeated, tag = "3")]
+    pub fields: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GroupBy {
+    #[prost(enumeration = "GroupKind", tag = "1")]
+    pub kind: i32,
+    /// dot separated path starting with `data` or `items`, e.g. `data.store`
+    #[prost(string, tag = "2")]
+    pub path: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AggregateResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub groups: ::prost::alloc::vec::Vec<AggregateGroup>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AggregateGroup {
+    /// user id, first day of the period as `YYYY-MM-DD`, or the text of the value at the path
+    #[prost(string, tag = "1")]
+    pub key: ::prost::alloc::string::String,
+    #[prost(int64, tag = "2")]
+    pub count: i64,
+    /// in the order of `AggregateRequest.fields`
+    #[prost(message, repeated, tag = "3")]
+    pub fields: ::prost::alloc::vec::Vec<FieldAggregate>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FieldAggregate {
+    #[prost(string, tag = "1")]
+    pub field: ::prost::alloc::string::String,
+    /// decimal strings, empty if no document has a number at the field
+    #[prost(string, tag = "2")]
+    pub sum: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub avg: ::prost::alloc::string::String,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -53,6 +291,18 @@ pub struct CreateRequest {
     pub user_id: ::prost::alloc::string::String,
     #[prost(message, optional, tag = "2")]
     pub data: ::core::option::Option<::prost_wkt_types::Struct>,
+    #[prost(message, repeated, tag = "3")]
+    pub items: ::prost::alloc::vec::Vec<DocumentItem>,
+    /// if set, data must conform to the latest schema registered for this type in the user's organization
+    #[prost(string, tag = "4")]
+    pub document_type: ::prost::alloc::string::String,
+    /// defaults to `documents`
+    #[prost(string, tag = "5")]
+    pub collection: ::prost::alloc::string::String,
+    /// retries with the same key within its time to live return the document of the first attempt,
+    /// taken from the `idempotency-key` header if empty
+    #[prost(string, tag = "6")]
+    pub idempotency_key: ::prost::alloc::string::String,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -67,8 +317,23 @@ pub struct CreateResponse {
 pub struct UpdateRequest {
     #[prost(string, tag = "1")]
     pub id: ::prost::alloc::string::String,
+    /// the stored data is kept if unset
     #[prost(message, optional, tag = "2")]
     pub data: ::core::option::Option<::prost_wkt_types::Struct>,
+    /// replace every stored item if any are given, the stored items are kept if empty,
+    /// a patch can remove every item
+    #[prost(message, repeated, tag = "3")]
+    pub items: ::prost::alloc::vec::Vec<DocumentItem>,
+    /// if set (non-zero), the update fails unless the document is still at this version
+    #[prost(int64, tag = "4")]
+    pub expected_version: i64,
+    /// defaults to `documents`
+    #[prost(string, tag = "5")]
+    pub collection: ::prost::alloc::string::String,
+    /// retries with the same key within its time to live return the document of the first attempt,
+    /// taken from the `idempotency-key` header if empty
+    #[prost(string, tag = "6")]
+    pub idempotency_key: ::prost::alloc::string::String,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -77,12 +342,78 @@ pub struct UpdateResponse {
     #[prost(message, optional, tag = "1")]
     pub document: ::core::option::Option<Document>,
 }
+/// Patches apply to the JSON object `{"data": {...}, "items": \[...\]}` of a document.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PatchRequest {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// if set (non-zero), the patch fails unless the document is still at this version
+    #[prost(int64, tag = "4")]
+    pub expected_version: i64,
+    /// defaults to `documents`
+    #[prost(string, tag = "5")]
+    pub collection: ::prost::alloc::string::String,
+    #[prost(oneof = "patch_request::Patch", tags = "2, 3")]
+    pub patch: ::core::option::Option<patch_request::Patch>,
+}
+/// Nested message and enum types in `PatchRequest`.
+pub mod patch_request {
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Patch {
+        /// RFC 7396 JSON merge patch
+        #[prost(message, tag = "2")]
+        MergePatch(::prost_wkt_types::Struct),
+        /// RFC 6902 JSON patch
+        #[prost(message, tag = "3")]
+        JsonPatch(super::JsonPatch),
+    }
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct JsonPatch {
+    #[prost(message, repeated, tag = "1")]
+    pub operations: ::prost::alloc::vec::Vec<JsonPatchOperation>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct JsonPatchOperation {
+    /// add, remove, replace, move, copy or test
+    #[prost(string, tag = "1")]
+    pub op: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub path: ::prost::alloc::string::String,
+    /// used by add, replace and test
+    #[prost(message, optional, tag = "3")]
+    pub value: ::core::option::Option<::prost_wkt_types::Value>,
+    /// used by move and copy
+    #[prost(string, tag = "4")]
+    pub from: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PatchResponse {
+    #[prost(message, optional, tag = "1")]
+    pub document: ::core::option::Option<Document>,
+}
 #[derive(serde::Serialize, serde::Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DeleteRequest {
     #[prost(string, tag = "1")]
     pub id: ::prost::alloc::string::String,
+    /// if set (non-zero), the delete fails unless the document is still at this version
+    #[prost(int64, tag = "2")]
+    pub expected_version: i64,
+    /// defaults to `documents`
+    #[prost(string, tag = "3")]
+    pub collection: ::prost::alloc::string::String,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -91,563 +422,5230 @@ pub struct DeleteResponse {
     #[prost(message, optional, tag = "1")]
     pub document: ::core::option::Option<Document>,
 }
-/// Generated client implementations.
-pub mod document_collection_client {
-    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
-    use tonic::codegen::http::Uri;
-    use tonic::codegen::*;
-    #[derive(Debug, Clone)]
-    pub struct DocumentCollectionClient<T> {
-        inner: tonic::client::Grpc<T>,
-    }
-    impl DocumentCollectionClient<tonic::transport::Channel> {
-        /// Attempt to create a new client by connecting to a given endpoint.
-        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
-        where
-            D: TryInto<tonic::transport::Endpoint>,
-            D::Error: Into<StdError>,
-        {
-            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
-            Ok(Self::new(conn))
-        }
-    }
-    impl<T> DocumentCollectionClient<T>
-    where
-        T: tonic::client::GrpcService<tonic::body::BoxBody>,
-        T::Error: Into<StdError>,
-        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
-        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
-    {
-        pub fn new(inner: T) -> Self {
-            let inner = tonic::client::Grpc::new(inner);
-            Self { inner }
-        }
-        pub fn with_origin(inner: T, origin: Uri) -> Self {
-            let inner = tonic::client::Grpc::with_origin(inner, origin);
-            Self { inner }
-        }
-        pub fn with_interceptor<F>(
-            inner: T,
-            interceptor: F,
-        ) -> DocumentCollectionClient<InterceptedService<T, F>>
-        where
-            F: tonic::service::Interceptor,
-            T::ResponseBody: Default,
-            T: tonic::codegen::Service<
-                http::Request<tonic::body::BoxBody>,
-                Response = http::Response<
-                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
-                >,
-            >,
-            <T as tonic::codegen::Service<http::Request<tonic::body::BoxBody>>>::Error:
-                Into<StdError> + Send + Sync,
-        {
-            DocumentCollectionClient::new(InterceptedService::new(inner, interceptor))
-        }
-        /// Compress requests with the given encoding.
-        ///
-        /// This requires the server to support it otherwise it might respond with an
-        /// error.
-        #[must_use]
-        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
-            self.inner = self.inner.send_compressed(encoding);
-            self
-        }
-        /// Enable decompressing responses.
-        #[must_use]
-        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
-            self.inner = self.inner.accept_compressed(encoding);
-            self
-        }
-        /// Limits the maximum size of a decoded message.
-        ///
-        /// Default: `4MB`
-        #[must_use]
-        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
-            self.inner = self.inner.max_decoding_message_size(limit);
-            self
-        }
-        /// Limits the maximum size of an encoded message.
-        ///
-        /// Default: `usize::MAX`
-        #[must_use]
-        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
-            self.inner = self.inner.max_encoding_message_size(limit);
-            self
-        }
-        pub async fn get(
-            &mut self,
-            request: impl tonic::IntoRequest<super::GetRequest>,
-        ) -> std::result::Result<tonic::Response<super::GetResponse>, tonic::Status> {
-            self.inner.ready().await.map_err(|e| {
-                tonic::Status::new(
-                    tonic::Code::Unknown,
-                    format!("Service was not ready: {}", e.into()),
-                )
-            })?;
-            let codec = tonic::codec::ProstCodec::default();
-            let path =
-                http::uri::PathAndQuery::from_static("/document_collection.DocumentCollection/get");
-            let mut req = request.into_request();
-            req.extensions_mut().insert(GrpcMethod::new(
-                "document_collection.DocumentCollection",
-                "get",
-            ));
-            self.inner.unary(req, path, codec).await
-        }
-        pub async fn query(
-            &mut self,
-            request: impl tonic::IntoRequest<super::QueryRequest>,
-        ) -> std::result::Result<
-            tonic::Response<tonic::codec::Streaming<super::Document>>,
-            tonic::Status,
-        > {
-            self.inner.ready().await.map_err(|e| {
-                tonic::Status::new(
-                    tonic::Code::Unknown,
-                    format!("Service was not ready: {}", e.into()),
-                )
-            })?;
-            let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/document_collection.DocumentCollection/query",
-            );
-            let mut req = request.into_request();
-            req.extensions_mut().insert(GrpcMethod::new(
-                "document_collection.DocumentCollection",
-                "query",
-            ));
-            self.inner.server_streaming(req, path, codec).await
-        }
-        pub async fn create(
-            &mut self,
-            request: impl tonic::IntoRequest<super::CreateRequest>,
-        ) -> std::result::Result<tonic::Response<super::CreateResponse>, tonic::Status> {
-            self.inner.ready().await.map_err(|e| {
-                tonic::Status::new(
-                    tonic::Code::Unknown,
-                    format!("Service was not ready: {}", e.into()),
-                )
-            })?;
-            let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/document_collection.DocumentCollection/create",
-            );
-            let mut req = request.into_request();
-            req.extensions_mut().insert(GrpcMethod::new(
-                "document_collection.DocumentCollection",
-                "create",
-            ));
-            self.inner.unary(req, path, codec).await
-        }
-        pub async fn update(
-            &mut self,
-            request: impl tonic::IntoRequest<super::UpdateRequest>,
-        ) -> std::result::Result<tonic::Response<super::UpdateResponse>, tonic::Status> {
-            self.inner.ready().await.map_err(|e| {
-                tonic::Status::new(
-                    tonic::Code::Unknown,
-                    format!("Service was not ready: {}", e.into()),
-                )
-            })?;
-            let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UndeleteRequest {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// defaults to `documents`
+    #[prost(string, tag = "2")]
+    pub collection: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UndeleteResponse {
+    #[prost(message, optional, tag = "1")]
+    pub document: ::core::option::Option<Document>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BatchCreateRequest {
+    #[prost(message, repeated, tag = "1")]
+    pub requests: ::prost::alloc::vec::Vec<CreateRequest>,
+    /// run every request or none, otherwise each on its own
+    #[prost(bool, tag = "2")]
+    pub atomic: bool,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BatchUpdateRequest {
+    #[prost(message, repeated, tag = "1")]
+    pub requests: ::prost::alloc::vec::Vec<UpdateRequest>,
+    /// run every request or none, otherwise each on its own
+    #[prost(bool, tag = "2")]
+    pub atomic: bool,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BatchDeleteRequest {
+    #[prost(message, repeated, tag = "1")]
+    pub requests: ::prost::alloc::vec::Vec<DeleteRequest>,
+    /// run every request or none, otherwise each on its own
+    #[prost(bool, tag = "2")]
+    pub atomic: bool,
+}
+/// The outcome of one request of a batch.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BatchResult {
+    /// the document created, updated or deleted, if the request succeeded
+    #[prost(message, optional, tag = "1")]
+    pub document: ::core::option::Option<Document>,
+    /// the google.rpc.Code the request failed with, 0 if it succeeded
+    #[prost(int32, tag = "2")]
+    pub code: i32,
+    #[prost(string, tag = "3")]
+    pub message: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BatchResponse {
+    /// in the order of the requests
+    #[prost(message, repeated, tag = "1")]
+    pub results: ::prost::alloc::vec::Vec<BatchResult>,
+}
+/// A snapshot of a document, recorded on every create, update and delete.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DocumentRevision {
+    #[prost(string, tag = "1")]
+    pub document_id: ::prost::alloc::string::String,
+    /// the document version this snapshot was taken at
+    #[prost(int64, tag = "2")]
+    pub revision: i64,
+    #[prost(string, tag = "3")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "4")]
+    pub data: ::core::option::Option<::prost_wkt_types::Struct>,
+    #[prost(message, repeated, tag = "5")]
+    pub items: ::prost::alloc::vec::Vec<DocumentItem>,
+    /// true if this revision records the deletion of the document
+    #[prost(bool, tag = "6")]
+    pub deleted: bool,
+    #[prost(message, optional, tag = "7")]
+    pub created_at: ::core::option::Option<::prost_wkt_types::Timestamp>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListRevisionsRequest {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// defaults to `documents`
+    #[prost(string, tag = "2")]
+    pub collection: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListRevisionsResponse {
+    /// oldest first, revisions older than the retention of changes are removed, except the current one
+    #[prost(message, repeated, tag = "1")]
+    pub revisions: ::prost::alloc::vec::Vec<DocumentRevision>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RestoreRequest {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(int64, tag = "2")]
+    pub revision: i64,
+    /// if set (non-zero), the restore fails unless the document is still at this version
+    #[prost(int64, tag = "3")]
+    pub expected_version: i64,
+    /// defaults to `documents`
+    #[prost(string, tag = "4")]
+    pub collection: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RestoreResponse {
+    #[prost(message, optional, tag = "1")]
+    pub document: ::core::option::Option<Document>,
+}
+/// A JSON Schema document data of one type must conform to. Registering a schema
+/// for an existing type adds a new version, documents are validated against the latest.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DocumentSchema {
+    #[prost(string, tag = "1")]
+    pub org_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub document_type: ::prost::alloc::string::String,
+    #[prost(int32, tag = "3")]
+    pub version: i32,
+    #[prost(message, optional, tag = "4")]
+    pub schema: ::core::option::Option<::prost_wkt_types::Struct>,
+    #[prost(message, optional, tag = "5")]
+    pub created_at: ::core::option::Option<::prost_wkt_types::Timestamp>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RegisterSchemaRequest {
+    /// defaults to the organization of the caller
+    #[prost(string, tag = "1")]
+    pub org_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub document_type: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub schema: ::core::option::Option<::prost_wkt_types::Struct>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RegisterSchemaResponse {
+    #[prost(message, optional, tag = "1")]
+    pub schema: ::core::option::Option<DocumentSchema>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetSchemaRequest {
+    /// defaults to the organization of the caller
+    #[prost(string, tag = "1")]
+    pub org_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub document_type: ::prost::alloc::string::String,
+    /// if set (non-zero), return this version instead of the latest
+    #[prost(int32, tag = "3")]
+    pub version: i32,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetSchemaResponse {
+    #[prost(message, optional, tag = "1")]
+    pub schema: ::core::option::Option<DocumentSchema>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListSchemasRequest {
+    /// defaults to the organization of the caller
+    #[prost(string, tag = "1")]
+    pub org_id: ::prost::alloc::string::String,
+    /// only versions of this type, every type if empty
+    #[prost(string, tag = "2")]
+    pub document_type: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListSchemasResponse {
+    /// ordered by type and version
+    #[prost(message, repeated, tag = "1")]
+    pub schemas: ::prost::alloc::vec::Vec<DocumentSchema>,
+}
+/// A named set of documents with its own settings, e.g. invoices, stock counts or delivery notes.
+/// Documents created before collections existed live in the default collection `documents`.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Collection {
+    /// lower case letters, digits and `_`, at most 48 characters
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// if set, documents of the collection default to and must have this type
+    #[prost(string, tag = "2")]
+    pub document_type: ::prost::alloc::string::String,
+    /// if set (non-zero), documents in the trash are purged this many days after deletion
+    /// instead of after the retention configured for the server
+    #[prost(int32, tag = "3")]
+    pub retention_days: i32,
+    /// dot separated paths starting with `data` or `items` to index, e.g. `data.store`
+    #[prost(string, repeated, tag = "4")]
+    pub indexes: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(message, optional, tag = "5")]
+    pub created_at: ::core::option::Option<::prost_wkt_types::Timestamp>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateCollectionRequest {
+    #[prost(message, optional, tag = "1")]
+    pub collection: ::core::option::Option<Collection>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateCollectionResponse {
+    #[prost(message, optional, tag = "1")]
+    pub collection: ::core::option::Option<Collection>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListCollectionsRequest {}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListCollectionsResponse {
+    /// ordered by name
+    #[prost(message, repeated, tag = "1")]
+    pub collections: ::prost::alloc::vec::Vec<Collection>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DropCollectionRequest {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// drop the collection with every document in it, otherwise only empty collections are dropped
+    #[prost(bool, tag = "2")]
+    pub force: bool,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DropCollectionResponse {
+    #[prost(message, optional, tag = "1")]
+    pub collection: ::core::option::Option<Collection>,
+}
+/// A create, update or delete of a document.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DocumentChange {
+    /// identifies the change, the same in watch, read_changes and webhook deliveries: resume a watch
+    /// with the last one it sent as `after_sequence`, or read_changes with its last one as `since_seq`.
+    /// Changes older than the retention configured for the server are removed and cannot be resumed after
+    #[prost(int64, tag = "1")]
+    pub sequence: i64,
+    #[prost(enumeration = "ChangeType", tag = "2")]
+    pub change_type: i32,
+    /// the document as it was after the change
+    #[prost(message, optional, tag = "3")]
+    pub document: ::core::option::Option<Document>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WatchRequest {
+    /// only changes of documents matching this query, its time range and include_deleted are ignored
+    #[prost(message, optional, tag = "1")]
+    pub query: ::core::option::Option<DocumentQuery>,
+    /// if set (non-zero), first replay every change after this sequence, otherwise only new changes are sent.
+    /// Changes are sent in the order read_changes returns them, and are held back the same way
+    #[prost(int64, tag = "2")]
+    pub after_sequence: i64,
+}
+/// Read the log every change is written to in the transaction making it. Changes are returned in a
+/// stable order once no transaction that could still add an earlier one is running, so a consumer
+/// resuming after the last change it processed sees every change exactly once.
+/// Transaction ids are shared by every database of the postgres server, so any transaction there
+/// that wrote something, like a long running or idle one, holds back the changes committed after it
+/// began until it ends. The server logs which transaction that is once changes wait for long.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReadChangesRequest {
+    /// resume after the change with this sequence, from the first change if not set (zero)
+    #[prost(int64, tag = "1")]
+    pub since_seq: i64,
+    /// only changes of this organization, defaults to the organization of the caller
+    #[prost(string, tag = "2")]
+    pub org_id: ::prost::alloc::string::String,
+    /// only changes in this collection, every collection if empty
+    #[prost(string, tag = "3")]
+    pub collection: ::prost::alloc::string::String,
+    /// if set (non-zero), return at most this many changes, otherwise every one available
+    #[prost(int32, tag = "4")]
+    pub limit: i32,
+}
+/// An HTTP endpoint changes are posted to as JSON `{"sequence", "change_type", "document"}`. Every
+/// request carries the headers `x-webhook-id` (the delivery), `x-webhook-timestamp` (unix seconds)
+/// and `x-webhook-signature`: `sha256=` followed by the hex encoded HMAC-SHA256 of
+/// `{timestamp}.{body}` keyed with the secret.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Webhook {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// defaults to the organization of the caller, changes of every organization are posted if empty
+    #[prost(string, tag = "2")]
+    pub org_id: ::prost::alloc::string::String,
+    /// http or https url
+    #[prost(string, tag = "3")]
+    pub url: ::prost::alloc::string::String,
+    /// the kinds of changes posted, every kind if empty
+    #[prost(enumeration = "ChangeType", repeated, tag = "4")]
+    pub event_types: ::prost::alloc::vec::Vec<i32>,
+    /// only changes of documents matching this query are posted, its time range and include_deleted are ignored
+    #[prost(message, optional, tag = "5")]
+    pub query: ::core::option::Option<DocumentQuery>,
+    /// generated if empty, only returned when the webhook is created
+    #[prost(string, tag = "6")]
+    pub secret: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "7")]
+    pub created_at: ::core::option::Option<::prost_wkt_types::Timestamp>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateWebhookRequest {
+    #[prost(message, optional, tag = "1")]
+    pub webhook: ::core::option::Option<Webhook>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateWebhookResponse {
+    #[prost(message, optional, tag = "1")]
+    pub webhook: ::core::option::Option<Webhook>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListWebhooksRequest {
+    /// defaults to the organization of the caller
+    #[prost(string, tag = "1")]
+    pub org_id: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListWebhooksResponse {
+    /// ordered by creation
+    #[prost(message, repeated, tag = "1")]
+    pub webhooks: ::prost::alloc::vec::Vec<Webhook>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteWebhookRequest {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteWebhookResponse {
+    #[prost(message, optional, tag = "1")]
+    pub webhook: ::core::option::Option<Webhook>,
+}
+/// One change posted, or to be posted, to a webhook.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WebhookDelivery {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub webhook_id: ::prost::alloc::string::String,
+    /// sequence of the change, as watch and read_changes number it
+    #[prost(int64, tag = "3")]
+    pub sequence: i64,
+    #[prost(enumeration = "ChangeType", tag = "4")]
+    pub change_type: i32,
+    #[prost(string, tag = "5")]
+    pub document_id: ::prost::alloc::string::String,
+    #[prost(enumeration = "DeliveryStatus", tag = "6")]
+    pub status: i32,
+    #[prost(int32, tag = "7")]
+    pub attempts: i32,
+    /// http status of the last attempt, 0 if it got no response
+    #[prost(int32, tag = "8")]
+    pub response_status: i32,
+    #[prost(string, tag = "9")]
+    pub last_error: ::prost::alloc::string::String,
+    /// when the next attempt is due, for pending deliveries
+    #[prost(message, optional, tag = "10")]
+    pub next_attempt_at: ::core::option::Option<::prost_wkt_types::Timestamp>,
+    #[prost(message, optional, tag = "11")]
+    pub created_at: ::core::option::Option<::prost_wkt_types::Timestamp>,
+    /// when it was delivered or moved to the dead letters
+    #[prost(message, optional, tag = "12")]
+    pub finished_at: ::core::option::Option<::prost_wkt_types::Timestamp>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListDeliveriesRequest {
+    #[prost(string, tag = "1")]
+    pub webhook_id: ::prost::alloc::string::String,
+    /// if set (non-zero), return at most this many deliveries, otherwise 50
+    #[prost(int32, tag = "2")]
+    pub limit: i32,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListDeliveriesResponse {
+    /// newest first
+    #[prost(message, repeated, tag = "1")]
+    pub deliveries: ::prost::alloc::vec::Vec<WebhookDelivery>,
+}
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    ::prost::Enumeration,
+)]
+#[repr(i32)]
+pub enum TimeRangeMode {
+    /// widen start and end to whole days, the day of end is included
+    Days = 0,
+    /// use start and end as given
+    Exact = 1,
+}
+impl TimeRangeMode {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            TimeRangeMode::Days => "TIME_RANGE_MODE_DAYS",
+            TimeRangeMode::Exact => "TIME_RANGE_MODE_EXACT",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "TIME_RANGE_MODE_DAYS" => Some(Self::Days),
+            "TIME_RANGE_MODE_EXACT" => Some(Self::Exact),
+            _ => None,
+        }
+    }
+}
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    ::prost::Enumeration,
+)]
+#[repr(i32)]
+pub enum TimeField {
+    CreatedAt = 0,
+    UpdatedAt = 1,
+}
+impl TimeField {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            TimeField::CreatedAt => "TIME_FIELD_CREATED_AT",
+            TimeField::UpdatedAt => "TIME_FIELD_UPDATED_AT",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "TIME_FIELD_CREATED_AT" => Some(Self::CreatedAt),
+            "TIME_FIELD_UPDATED_AT" => Some(Self::UpdatedAt),
+            _ => None,
+        }
+    }
+}
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    ::prost::Enumeration,
+)]
+#[repr(i32)]
+pub enum FilterOperator {
+    Unspecified = 0,
+    Eq = 1,
+    Ne = 2,
+    Lt = 3,
+    Gt = 4,
+    /// value must be a list, matches if the field equals any of its elements
+    In = 5,
+    /// matches if the field is present, value is ignored
+    Exists = 6,
+    /// matches if the field contains the value as in jsonb `@>`, e.g. an item list containing a product
+    Contains = 7,
+}
+impl FilterOperator {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            FilterOperator::Unspecified => "FILTER_OPERATOR_UNSPECIFIED",
+            FilterOperator::Eq => "FILTER_OPERATOR_EQ",
+            FilterOperator::Ne => "FILTER_OPERATOR_NE",
+            FilterOperator::Lt => "FILTER_OPERATOR_LT",
+            FilterOperator::Gt => "FILTER_OPERATOR_GT",
+            FilterOperator::In => "FILTER_OPERATOR_IN",
+            FilterOperator::Exists => "FILTER_OPERATOR_EXISTS",
+            FilterOperator::Contains => "FILTER_OPERATOR_CONTAINS",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "FILTER_OPERATOR_UNSPECIFIED" => Some(Self::Unspecified),
+            "FILTER_OPERATOR_EQ" => Some(Self::Eq),
+            "FILTER_OPERATOR_NE" => Some(Self::Ne),
+            "FILTER_OPERATOR_LT" => Some(Self::Lt),
+            "FILTER_OPERATOR_GT" => Some(Self::Gt),
+            "FILTER_OPERATOR_IN" => Some(Self::In),
+            "FILTER_OPERATOR_EXISTS" => Some(Self::Exists),
+            "FILTER_OPERATOR_CONTAINS" => Some(Self::Contains),
+            _ => None,
+        }
+    }
+}
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    ::prost::Enumeration,
+)]
+#[repr(i32)]
+pub enum OrderField {
+    CreatedAt = 0,
+    UpdatedAt = 1,
+    /// order by the value at `OrderBy.path`
+    Path = 2,
+}
+impl OrderField {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            OrderField::CreatedAt => "ORDER_FIELD_CREATED_AT",
+            OrderField::UpdatedAt => "ORDER_FIELD_UPDATED_AT",
+            OrderField::Path => "ORDER_FIELD_PATH",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "ORDER_FIELD_CREATED_AT" => Some(Self::CreatedAt),
+            "ORDER_FIELD_UPDATED_AT" => Some(Self::UpdatedAt),
+            "ORDER_FIELD_PATH" => Some(Self::Path),
+            _ => None,
+        }
+    }
+}
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    ::prost::Enumeration,
+)]
+#[repr(i32)]
+pub enum GroupKind {
+    /// a single group with every document
+    None = 0,
+    User = 1,
+    /// days, weeks and months are taken in the time zone and on the time field of the query
+    Day = 2,
+    Week = 3,
+    Month = 4,
+    /// the value at `GroupBy.path`
+    Path = 5,
+}
+impl GroupKind {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            GroupKind::None => "GROUP_KIND_NONE",
+            GroupKind::User => "GROUP_KIND_USER",
+            GroupKind::Day => "GROUP_KIND_DAY",
+            GroupKind::Week => "GROUP_KIND_WEEK",
+            GroupKind::Month => "GROUP_KIND_MONTH",
+            GroupKind::Path => "GROUP_KIND_PATH",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "GROUP_KIND_NONE" => Some(Self::None),
+            "GROUP_KIND_USER" => Some(Self::User),
+            "GROUP_KIND_DAY" => Some(Self::Day),
+            "GROUP_KIND_WEEK" => Some(Self::Week),
+            "GROUP_KIND_MONTH" => Some(Self::Month),
+            "GROUP_KIND_PATH" => Some(Self::Path),
+            _ => None,
+        }
+    }
+}
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    ::prost::Enumeration,
+)]
+#[repr(i32)]
+pub enum ChangeType {
+    Unspecified = 0,
+    Created = 1,
+    Updated = 2,
+    /// moved to the trash
+    Deleted = 3,
+}
+impl ChangeType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            ChangeType::Unspecified => "CHANGE_TYPE_UNSPECIFIED",
+            ChangeType::Created => "CREATED",
+            ChangeType::Updated => "UPDATED",
+            ChangeType::Deleted => "DELETED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "CHANGE_TYPE_UNSPECIFIED" => Some(Self::Unspecified),
+            "CREATED" => Some(Self::Created),
+            "UPDATED" => Some(Self::Updated),
+            "DELETED" => Some(Self::Deleted),
+            _ => None,
+        }
+    }
+}
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    ::prost::Enumeration,
+)]
+#[repr(i32)]
+pub enum DeliveryStatus {
+    Unspecified = 0,
+    /// waiting for its next attempt
+    Pending = 1,
+    Delivered = 2,
+    /// failed every attempt and moved to the dead letters
+    Dead = 3,
+}
+impl DeliveryStatus {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            DeliveryStatus::Unspecified => "DELIVERY_STATUS_UNSPECIFIED",
+            DeliveryStatus::Pending => "PENDING",
+            DeliveryStatus::Delivered => "DELIVERED",
+            DeliveryStatus::Dead => "DEAD",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "DELIVERY_STATUS_UNSPECIFIED" => Some(Self::Unspecified),
+            "PENDING" => Some(Self::Pending),
+            "DELIVERED" => Some(Self::Delivered),
+            "DEAD" => Some(Self::Dead),
+            _ => None,
+        }
+    }
+}
+/// Generated client implementations.
+pub mod document_collection_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::http::Uri;
+    use tonic::codegen::*;
+    #[derive(Debug, Clone)]
+    pub struct DocumentCollectionClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl DocumentCollectionClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> DocumentCollectionClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> DocumentCollectionClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<http::Request<tonic::body::BoxBody>>>::Error:
+                Into<StdError> + Send + Sync,
+        {
+            DocumentCollectionClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        pub async fn get(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/document_collection.DocumentCollection/get");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "document_collection.DocumentCollection",
+                "get",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn query(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::Document>>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/document_collection.DocumentCollection/query",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "document_collection.DocumentCollection",
+                "query",
+            ));
+            self.inner.server_streaming(req, path, codec).await
+        }
+        pub async fn list(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryRequest>,
+        ) -> std::result::Result<tonic::Response<super::ListResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/document_collection.DocumentCollection/list",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "document_collection.DocumentCollection",
+                "list",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn aggregate(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AggregateRequest>,
+        ) -> std::result::Result<tonic::Response<super::AggregateResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/document_collection.DocumentCollection/aggregate",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "document_collection.DocumentCollection",
+                "aggregate",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn create(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CreateRequest>,
+        ) -> std::result::Result<tonic::Response<super::CreateResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/document_collection.DocumentCollection/create",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "document_collection.DocumentCollection",
+                "create",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn update(
+            &mut self,
+            request: impl tonic::IntoRequest<super::UpdateRequest>,
+        ) -> std::result::Result<tonic::Response<super::UpdateResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
                 "/document_collection.DocumentCollection/update",
             );
             let mut req = request.into_request();
             req.extensions_mut().insert(GrpcMethod::new(
                 "document_collection.DocumentCollection",
-                "update",
+                "update",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn patch(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PatchRequest>,
+        ) -> std::result::Result<tonic::Response<super::PatchResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/document_collection.DocumentCollection/patch",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "document_collection.DocumentCollection",
+                "patch",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn delete(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DeleteRequest>,
+        ) -> std::result::Result<tonic::Response<super::DeleteResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/document_collection.DocumentCollection/delete",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "document_collection.DocumentCollection",
+                "delete",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn batch_create(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BatchCreateRequest>,
+        ) -> std::result::Result<tonic::Response<super::BatchResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/document_collection.DocumentCollection/batch_create",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "document_collection.DocumentCollection",
+                "batch_create",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn batch_update(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BatchUpdateRequest>,
+        ) -> std::result::Result<tonic::Response<super::BatchResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/document_collection.DocumentCollection/batch_update",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "document_collection.DocumentCollection",
+                "batch_update",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn batch_delete(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BatchDeleteRequest>,
+        ) -> std::result::Result<tonic::Response<super::BatchResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/document_collection.DocumentCollection/batch_delete",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "document_collection.DocumentCollection",
+                "batch_delete",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// for uploads too large for one message, every message is run as a batch of its own and answered
+        /// with its own response, a message failing as a whole ends the stream with its error
+        pub async fn batch_create_stream(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<Message = super::BatchCreateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::BatchResponse>>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/document_collection.DocumentCollection/batch_create_stream",
+            );
+            let mut req = request.into_streaming_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "document_collection.DocumentCollection",
+                "batch_create_stream",
+            ));
+            self.inner.streaming(req, path, codec).await
+        }
+        pub async fn batch_update_stream(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<Message = super::BatchUpdateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::BatchResponse>>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/document_collection.DocumentCollection/batch_update_stream",
+            );
+            let mut req = request.into_streaming_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "document_collection.DocumentCollection",
+                "batch_update_stream",
+            ));
+            self.inner.streaming(req, path, codec).await
+        }
+        pub async fn batch_delete_stream(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<Message = super::BatchDeleteRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::BatchResponse>>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/document_collection.DocumentCollection/batch_delete_stream",
+            );
+            let mut req = request.into_streaming_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "document_collection.DocumentCollection",
+                "batch_delete_stream",
+            ));
+            self.inner.streaming(req, path, codec).await
+        }
+        pub async fn undelete(
+            &mut self,
+            request: impl tonic::IntoRequest<super::UndeleteRequest>,
+        ) -> std::result::Result<tonic::Response<super::UndeleteResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/document_collection.DocumentCollection/undelete",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "document_collection.DocumentCollection",
+                "undelete",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn list_revisions(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListRevisionsRequest>,
+        ) -> std::result::Result<tonic::Response<super::ListRevisionsResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/document_collection.DocumentCollection/list_revisions",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "document_collection.DocumentCollection",
+                "list_revisions",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn restore(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RestoreRequest>,
+        ) -> std::result::Result<tonic::Response<super::RestoreResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/document_collection.DocumentCollection/restore",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "document_collection.DocumentCollection",
+                "restore",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn register_schema(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RegisterSchemaRequest>,
+        ) -> std::result::Result<tonic::Response<super::RegisterSchemaResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/document_collection.DocumentCollection/register_schema",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "document_collection.DocumentCollection",
+                "register_schema",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_schema(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetSchemaRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetSchemaResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/document_collection.DocumentCollection/get_schema",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "document_collection.DocumentCollection",
+                "get_schema",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn list_schemas(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListSchemasRequest>,
+        ) -> std::result::Result<tonic::Response<super::ListSchemasResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/document_collection.DocumentCollection/list_schemas",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "document_collection.DocumentCollection",
+                "list_schemas",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn create_collection(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CreateCollectionRequest>,
+        ) -> std::result::Result<tonic::Response<super::CreateCollectionResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/document_collection.DocumentCollection/create_collection",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "document_collection.DocumentCollection",
+                "create_collection",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn list_collections(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListCollectionsRequest>,
+        ) -> std::result::Result<tonic::Response<super::ListCollectionsResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/document_collection.DocumentCollection/list_collections",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "document_collection.DocumentCollection",
+                "list_collections",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn drop_collection(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DropCollectionRequest>,
+        ) -> std::result::Result<tonic::Response<super::DropCollectionResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/document_collection.DocumentCollection/drop_collection",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "document_collection.DocumentCollection",
+                "drop_collection",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn watch(
+            &mut self,
+            request: impl tonic::IntoRequest<super::WatchRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::DocumentChange>>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/document_collection.DocumentCollection/watch",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "document_collection.DocumentCollection",
+                "watch",
+            ));
+            self.inner.server_streaming(req, path, codec).await
+        }
+        pub async fn read_changes(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ReadChangesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::DocumentChange>>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/document_collection.DocumentCollection/read_changes",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "document_collection.DocumentCollection",
+                "read_changes",
+            ));
+            self.inner.server_streaming(req, path, codec).await
+        }
+        pub async fn create_webhook(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CreateWebhookRequest>,
+        ) -> std::result::Result<tonic::Response<super::CreateWebhookResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/document_collection.DocumentCollection/create_webhook",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "document_collection.DocumentCollection",
+                "create_webhook",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn list_webhooks(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListWebhooksRequest>,
+        ) -> std::result::Result<tonic::Response<super::ListWebhooksResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/document_collection.DocumentCollection/list_webhooks",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "document_collection.DocumentCollection",
+                "list_webhooks",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn delete_webhook(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DeleteWebhookRequest>,
+        ) -> std::result::Result<tonic::Response<super::DeleteWebhookResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/document_collection.DocumentCollection/delete_webhook",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "document_collection.DocumentCollection",
+                "delete_webhook",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn list_deliveries(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListDeliveriesRequest>,
+        ) -> std::result::Result<tonic::Response<super::ListDeliveriesResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/document_collection.DocumentCollection/list_deliveries",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "document_collection.DocumentCollection",
+                "list_deliveries",
             ));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn delete(
-            &mut self,
-            request: impl tonic::IntoRequest<super::DeleteRequest>,
-        ) -> std::result::Result<tonic::Response<super::DeleteResponse>, tonic::Status> {
-            self.inner.ready().await.map_err(|e| {
-                tonic::Status::new(
-                    tonic::Code::Unknown,
-                    format!("Service was not ready: {}", e.into()),
-                )
-            })?;
-            let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/document_collection.DocumentCollection/delete",
-            );
-            let mut req = request.into_request();
-            req.extensions_mut().insert(GrpcMethod::new(
-                "document_collection.DocumentCollection",
-                "delete",
-            ));
-            self.inner.unary(req, path, codec).await
+    }
+}
+/// Generated server implementations.
+pub mod document_collection_server {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with DocumentCollectionServer.
+    #[async_trait]
+    pub trait DocumentCollection: Send + Sync + 'static {
+        async fn get(
+            &self,
+            request: tonic::Request<super::GetRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetResponse>, tonic::Status>;
+        /// Server streaming response type for the query method.
+        type queryStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::Document, tonic::Status>,
+            > + Send
+            + 'static;
+        async fn query(
+            &self,
+            request: tonic::Request<super::QueryRequest>,
+        ) -> std::result::Result<tonic::Response<Self::queryStream>, tonic::Status>;
+        async fn list(
+            &self,
+            request: tonic::Request<super::QueryRequest>,
+        ) -> std::result::Result<tonic::Response<super::ListResponse>, tonic::Status>;
+        async fn aggregate(
+            &self,
+            request: tonic::Request<super::AggregateRequest>,
+        ) -> std::result::Result<tonic::Response<super::AggregateResponse>, tonic::Status>;
+        async fn create(
+            &self,
+            request: tonic::Request<super::CreateRequest>,
+        ) -> std::result::Result<tonic::Response<super::CreateResponse>, tonic::Status>;
+        async fn update(
+            &self,
+            request: tonic::Request<super::UpdateRequest>,
+        ) -> std::result::Result<tonic::Response<super::UpdateResponse>, tonic::Status>;
+        async fn patch(
+            &self,
+            request: tonic::Request<super::PatchRequest>,
+        ) -> std::result::Result<tonic::Response<super::PatchResponse>, tonic::Status>;
+        async fn delete(
+            &self,
+            request: tonic::Request<super::DeleteRequest>,
+        ) -> std::result::Result<tonic::Response<super::DeleteResponse>, tonic::Status>;
+        async fn batch_create(
+            &self,
+            request: tonic::Request<super::BatchCreateRequest>,
+        ) -> std::result::Result<tonic::Response<super::BatchResponse>, tonic::Status>;
+        async fn batch_update(
+            &self,
+            request: tonic::Request<super::BatchUpdateRequest>,
+        ) -> std::result::Result<tonic::Response<super::BatchResponse>, tonic::Status>;
+        async fn batch_delete(
+            &self,
+            request: tonic::Request<super::BatchDeleteRequest>,
+        ) -> std::result::Result<tonic::Response<super::BatchResponse>, tonic::Status>;
+        /// Server streaming response type for the batch_create_stream method.
+        type batch_create_streamStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::BatchResponse, tonic::Status>,
+            > + Send
+            + 'static;
+        /// for uploads too large for one message, every message is run as a batch of its own and answered
+        /// with its own response, a message failing as a whole ends the stream with its error
+        async fn batch_create_stream(
+            &self,
+            request: tonic::Request<tonic::Streaming<super::BatchCreateRequest>>,
+        ) -> std::result::Result<tonic::Response<Self::batch_create_streamStream>, tonic::Status>;
+        /// Server streaming response type for the batch_update_stream method.
+        type batch_update_streamStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::BatchResponse, tonic::Status>,
+            > + Send
+            + 'static;
+        async fn batch_update_stream(
+            &self,
+            request: tonic::Request<tonic::Streaming<super::BatchUpdateRequest>>,
+        ) -> std::result::Result<tonic::Response<Self::batch_update_streamStream>, tonic::Status>;
+        /// Server streaming response type for the batch_delete_stream method.
+        type batch_delete_streamStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::BatchResponse, tonic::Status>,
+            > + Send
+            + 'static;
+        async fn batch_delete_stream(
+            &self,
+            request: tonic::Request<tonic::Streaming<super::BatchDeleteRequest>>,
+        ) -> std::result::Result<tonic::Response<Self::batch_delete_streamStream>, tonic::Status>;
+        async fn undelete(
+            &self,
+            request: tonic::Request<super::UndeleteRequest>,
+        ) -> std::result::Result<tonic::Response<super::UndeleteResponse>, tonic::Status>;
+        async fn list_revisions(
+            &self,
+            request: tonic::Request<super::ListRevisionsRequest>,
+        ) -> std::result::Result<tonic::Response<super::ListRevisionsResponse>, tonic::Status>;
+        async fn restore(
+            &self,
+            request: tonic::Request<super::RestoreRequest>,
+        ) -> std::result::Result<tonic::Response<super::RestoreResponse>, tonic::Status>;
+        async fn register_schema(
+            &self,
+            request: tonic::Request<super::RegisterSchemaRequest>,
+        ) -> std::result::Result<tonic::Response<super::RegisterSchemaResponse>, tonic::Status>;
+        async fn get_schema(
+            &self,
+            request: tonic::Request<super::GetSchemaRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetSchemaResponse>, tonic::Status>;
+        async fn list_schemas(
+            &self,
+            request: tonic::Request<super::ListSchemasRequest>,
+        ) -> std::result::Result<tonic::Response<super::ListSchemasResponse>, tonic::Status>;
+        async fn create_collection(
+            &self,
+            request: tonic::Request<super::CreateCollectionRequest>,
+        ) -> std::result::Result<tonic::Response<super::CreateCollectionResponse>, tonic::Status>;
+        async fn list_collections(
+            &self,
+            request: tonic::Request<super::ListCollectionsRequest>,
+        ) -> std::result::Result<tonic::Response<super::ListCollectionsResponse>, tonic::Status>;
+        async fn drop_collection(
+            &self,
+            request: tonic::Request<super::DropCollectionRequest>,
+        ) -> std::result::Result<tonic::Response<super::DropCollectionResponse>, tonic::Status>;
+        /// Server streaming response type for the watch method.
+        type watchStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::DocumentChange, tonic::Status>,
+            > + Send
+            + 'static;
+        async fn watch(
+            &self,
+            request: tonic::Request<super::WatchRequest>,
+        ) -> std::result::Result<tonic::Response<Self::watchStream>, tonic::Status>;
+        /// Server streaming response type for the read_changes method.
+        type read_changesStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::DocumentChange, tonic::Status>,
+            > + Send
+            + 'static;
+        async fn read_changes(
+            &self,
+            request: tonic::Request<super::ReadChangesRequest>,
+        ) -> std::result::Result<tonic::Response<Self::read_changesStream>, tonic::Status>;
+        async fn create_webhook(
+            &self,
+            request: tonic::Request<super::CreateWebhookRequest>,
+        ) -> std::result::Result<tonic::Response<super::CreateWebhookResponse>, tonic::Status>;
+        async fn list_webhooks(
+            &self,
+            request: tonic::Request<super::ListWebhooksRequest>,
+        ) -> std::result::Result<tonic::Response<super::ListWebhooksResponse>, tonic::Status>;
+        async fn delete_webhook(
+            &self,
+            request: tonic::Request<super::DeleteWebhookRequest>,
+        ) -> std::result::Result<tonic::Response<super::DeleteWebhookResponse>, tonic::Status>;
+        async fn list_deliveries(
+            &self,
+            request: tonic::Request<super::ListDeliveriesRequest>,
+        ) -> std::result::Result<tonic::Response<super::ListDeliveriesResponse>, tonic::Status>;
+    }
+    #[derive(Debug)]
+    pub struct DocumentCollectionServer<T: DocumentCollection> {
+        inner: _Inner<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    struct _Inner<T>(Arc<T>);
+    impl<T: DocumentCollection> DocumentCollectionServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            let inner = _Inner(inner);
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(inner: T, interceptor: F) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for DocumentCollectionServer<T>
+    where
+        T: DocumentCollection,
+        B: Body + Send + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/document_collection.DocumentCollection/get" => {
+                    #[allow(non_camel_case_types)]
+                    struct getSvc<T: DocumentCollection>(pub Arc<T>);
+                    impl<T: DocumentCollection> tonic::server::UnaryService<super::GetRequest> for getSvc<T> {
+                        type Response = super::GetResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DocumentCollection>::get(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = getSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/document_collection.DocumentCollection/query" => {
+                    #[allow(non_camel_case_types)]
+                    struct querySvc<T: DocumentCollection>(pub Arc<T>);
+                    impl<T: DocumentCollection>
+                        tonic::server::ServerStreamingService<super::QueryRequest> for querySvc<T>
+                    {
+                        type Response = super::Document;
+                        type ResponseStream = T::queryStream;
+                        type Future =
+                            BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DocumentCollection>::query(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = querySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/document_collection.DocumentCollection/list" => {
+                    #[allow(non_camel_case_types)]
+                    struct listSvc<T: DocumentCollection>(pub Arc<T>);
+                    impl<T: DocumentCollection> tonic::server::UnaryService<super::QueryRequest> for listSvc<T> {
+                        type Response = super::ListResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DocumentCollection>::list(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = listSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/document_collection.DocumentCollection/aggregate" => {
+                    #[allow(non_camel_case_types)]
+                    struct aggregateSvc<T: DocumentCollection>(pub Arc<T>);
+                    impl<T: DocumentCollection> tonic::server::UnaryService<super::AggregateRequest>
+                        for aggregateSvc<T>
+                    {
+                        type Response = super::AggregateResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AggregateRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DocumentCollection>::aggregate(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = aggregateSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/document_collection.DocumentCollection/create" => {
+                    #[allow(non_camel_case_types)]
+                    struct createSvc<T: DocumentCollection>(pub Arc<T>);
+                    impl<T: DocumentCollection> tonic::server::UnaryService<super::CreateRequest> for createSvc<T> {
+                        type Response = super::CreateResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CreateRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DocumentCollection>::create(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = createSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/document_collection.DocumentCollection/update" => {
+                    #[allow(non_camel_case_types)]
+                    struct updateSvc<T: DocumentCollection>(pub Arc<T>);
+                    impl<T: DocumentCollection> tonic::server::UnaryService<super::UpdateRequest> for updateSvc<T> {
+                        type Response = super::UpdateResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::UpdateRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DocumentCollection>::update(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = updateSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/document_collection.DocumentCollection/patch" => {
+                    #[allow(non_camel_case_types)]
+                    struct patchSvc<T: DocumentCollection>(pub Arc<T>);
+                    impl<T: DocumentCollection> tonic::server::UnaryService<super::PatchRequest> for patchSvc<T> {
+                        type Response = super::PatchResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PatchRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DocumentCollection>::patch(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = patchSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/document_collection.DocumentCollection/delete" => {
+                    #[allow(non_camel_case_types)]
+                    struct deleteSvc<T: DocumentCollection>(pub Arc<T>);
+                    impl<T: DocumentCollection> tonic::server::UnaryService<super::DeleteRequest> for deleteSvc<T> {
+                        type Response = super::DeleteResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DeleteRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DocumentCollection>::delete(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = deleteSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/document_collection.DocumentCollection/batch_create" => {
+                    #[allow(non_camel_case_types)]
+                    struct batch_createSvc<T: DocumentCollection>(pub Arc<T>);
+                    impl<T: DocumentCollection>
+                        tonic::server::UnaryService<super::BatchCreateRequest>
+                        for batch_createSvc<T>
+                    {
+                        type Response = super::BatchResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BatchCreateRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DocumentCollection>::batch_create(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = batch_createSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/document_collection.DocumentCollection/batch_update" => {
+                    #[allow(non_camel_case_types)]
+                    struct batch_updateSvc<T: DocumentCollection>(pub Arc<T>);
+                    impl<T: DocumentCollection>
+                        tonic::server::UnaryService<super::BatchUpdateRequest>
+                        for batch_updateSvc<T>
+                    {
+                        type Response = super::BatchResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BatchUpdateRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DocumentCollection>::batch_update(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = batch_updateSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/document_collection.DocumentCollection/batch_delete" => {
+                    #[allow(non_camel_case_types)]
+                    struct batch_deleteSvc<T: DocumentCollection>(pub Arc<T>);
+                    impl<T: DocumentCollection>
+                        tonic::server::UnaryService<super::BatchDeleteRequest>
+                        for batch_deleteSvc<T>
+                    {
+                        type Response = super::BatchResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BatchDeleteRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DocumentCollection>::batch_delete(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = batch_deleteSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/document_collection.DocumentCollection/batch_create_stream" => {
+                    #[allow(non_camel_case_types)]
+                    struct batch_create_streamSvc<T: DocumentCollection>(pub Arc<T>);
+                    impl<T: DocumentCollection>
+                        tonic::server::StreamingService<super::BatchCreateRequest>
+                        for batch_create_streamSvc<T>
+                    {
+                        type Response = super::BatchResponse;
+                        type ResponseStream = T::batch_create_streamStream;
+                        type Future =
+                            BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<tonic::Streaming<super::BatchCreateRequest>>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DocumentCollection>::batch_create_stream(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = batch_create_streamSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/document_collection.DocumentCollection/batch_update_stream" => {
+                    #[allow(non_camel_case_types)]
+                    struct batch_update_streamSvc<T: DocumentCollection>(pub Arc<T>);
+                    impl<T: DocumentCollection>
+                        tonic::server::StreamingService<super::BatchUpdateRequest>
+                        for batch_update_streamSvc<T>
+                    {
+                        type Response = super::BatchResponse;
+                        type ResponseStream = T::batch_update_streamStream;
+                        type Future =
+                            BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<tonic::Streaming<super::BatchUpdateRequest>>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DocumentCollection>::batch_update_stream(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = batch_update_streamSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/document_collection.DocumentCollection/batch_delete_stream" => {
+                    #[allow(non_camel_case_types)]
+                    struct batch_delete_streamSvc<T: DocumentCollection>(pub Arc<T>);
+                    impl<T: DocumentCollection>
+                        tonic::server::StreamingService<super::BatchDeleteRequest>
+                        for batch_delete_streamSvc<T>
+                    {
+                        type Response = super::BatchResponse;
+                        type ResponseStream = T::batch_delete_streamStream;
+                        type Future =
+                            BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<tonic::Streaming<super::BatchDeleteRequest>>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DocumentCollection>::batch_delete_stream(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = batch_delete_streamSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/document_collection.DocumentCollection/undelete" => {
+                    #[allow(non_camel_case_types)]
+                    struct undeleteSvc<T: DocumentCollection>(pub Arc<T>);
+                    impl<T: DocumentCollection> tonic::server::UnaryService<super::UndeleteRequest> for undeleteSvc<T> {
+                        type Response = super::UndeleteResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::UndeleteRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DocumentCollection>::undelete(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = undeleteSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/document_collection.DocumentCollection/list_revisions" => {
+                    #[allow(non_camel_case_types)]
+                    struct list_revisionsSvc<T: DocumentCollection>(pub Arc<T>);
+                    impl<T: DocumentCollection>
+                        tonic::server::UnaryService<super::ListRevisionsRequest>
+                        for list_revisionsSvc<T>
+                    {
+                        type Response = super::ListRevisionsResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListRevisionsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DocumentCollection>::list_revisions(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = list_revisionsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/document_collection.DocumentCollection/restore" => {
+                    #[allow(non_camel_case_types)]
+                    struct restoreSvc<T: DocumentCollection>(pub Arc<T>);
+                    impl<T: DocumentCollection> tonic::server::UnaryService<super::RestoreRequest> for restoreSvc<T> {
+                        type Response = super::RestoreResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RestoreRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DocumentCollection>::restore(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = restoreSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/document_collection.DocumentCollection/register_schema" => {
+                    #[allow(non_camel_case_types)]
+                    struct register_schemaSvc<T: DocumentCollection>(pub Arc<T>);
+                    impl<T: DocumentCollection>
+                        tonic::server::UnaryService<super::RegisterSchemaRequest>
+                        for register_schemaSvc<T>
+                    {
+                        type Response = super::RegisterSchemaResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RegisterSchemaRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DocumentCollection>::register_schema(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = register_schemaSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/document_collection.DocumentCollection/get_schema" => {
+                    #[allow(non_camel_case_types)]
+                    struct get_schemaSvc<T: DocumentCollection>(pub Arc<T>);
+                    impl<T: DocumentCollection> tonic::server::UnaryService<super::GetSchemaRequest>
+                        for get_schemaSvc<T>
+                    {
+                        type Response = super::GetSchemaResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetSchemaRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DocumentCollection>::get_schema(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = get_schemaSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/document_collection.DocumentCollection/list_schemas" => {
+                    #[allow(non_camel_case_types)]
+                    struct list_schemasSvc<T: DocumentCollection>(pub Arc<T>);
+                    impl<T: DocumentCollection>
+                        tonic::server::UnaryService<super::ListSchemasRequest>
+                        for list_schemasSvc<T>
+                    {
+                        type Response = super::ListSchemasResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListSchemasRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DocumentCollection>::list_schemas(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = list_schemasSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/document_collection.DocumentCollection/create_collection" => {
+                    #[allow(non_camel_case_types)]
+                    struct create_collectionSvc<T: DocumentCollection>(pub Arc<T>);
+                    impl<T: DocumentCollection>
+                        tonic::server::UnaryService<super::CreateCollectionRequest>
+                        for create_collectionSvc<T>
+                    {
+                        type Response = super::CreateCollectionResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CreateCollectionRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DocumentCollection>::create_collection(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = create_collectionSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/document_collection.DocumentCollection/list_collections" => {
+                    #[allow(non_camel_case_types)]
+                    struct list_collectionsSvc<T: DocumentCollection>(pub Arc<T>);
+                    impl<T: DocumentCollection>
+                        tonic::server::UnaryService<super::ListCollectionsRequest>
+                        for list_collectionsSvc<T>
+                    {
+                        type Response = super::ListCollectionsResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListCollectionsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DocumentCollection>::list_collections(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = list_collectionsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/document_collection.DocumentCollection/drop_collection" => {
+                    #[allow(non_camel_case_types)]
+                    struct drop_collectionSvc<T: DocumentCollection>(pub Arc<T>);
+                    impl<T: DocumentCollection>
+                        tonic::server::UnaryService<super::DropCollectionRequest>
+                        for drop_collectionSvc<T>
+                    {
+                        type Response = super::DropCollectionResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DropCollectionRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DocumentCollection>::drop_collection(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = drop_collectionSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/document_collection.DocumentCollection/watch" => {
+                    #[allow(non_camel_case_types)]
+                    struct watchSvc<T: DocumentCollection>(pub Arc<T>);
+                    impl<T: DocumentCollection>
+                        tonic::server::ServerStreamingService<super::WatchRequest> for watchSvc<T>
+                    {
+                        type Response = super::DocumentChange;
+                        type ResponseStream = T::watchStream;
+                        type Future =
+                            BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::WatchRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DocumentCollection>::watch(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = watchSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/document_collection.DocumentCollection/read_changes" => {
+                    #[allow(non_camel_case_types)]
+                    struct read_changesSvc<T: DocumentCollection>(pub Arc<T>);
+                    impl<T: DocumentCollection>
+                        tonic::server::ServerStreamingService<super::ReadChangesRequest>
+                        for read_changesSvc<T>
+                    {
+                        type Response = super::DocumentChange;
+                        type ResponseStream = T::read_changesStream;
+                        type Future =
+                            BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ReadChangesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DocumentCollection>::read_changes(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = read_changesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/document_collection.DocumentCollection/create_webhook" => {
+                    #[allow(non_camel_case_types)]
+                    struct create_webhookSvc<T: DocumentCollection>(pub Arc<T>);
+                    impl<T: DocumentCollection>
+                        tonic::server::UnaryService<super::CreateWebhookRequest>
+                        for create_webhookSvc<T>
+                    {
+                        type Response = super::CreateWebhookResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CreateWebhookRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DocumentCollection>::create_webhook(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = create_webhookSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/document_collection.DocumentCollection/list_webhooks" => {
+                    #[allow(non_camel_case_types)]
+                    struct list_webhooksSvc<T: DocumentCollection>(pub Arc<T>);
+                    impl<T: DocumentCollection>
+                        tonic::server::UnaryService<super::ListWebhooksRequest>
+                        for list_webhooksSvc<T>
+                    {
+                        type Response = super::ListWebhooksResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListWebhooksRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DocumentCollection>::list_webhooks(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = list_webhooksSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/document_collection.DocumentCollection/delete_webhook" => {
+                    #[allow(non_camel_case_types)]
+                    struct delete_webhookSvc<T: DocumentCollection>(pub Arc<T>);
+                    impl<T: DocumentCollection>
+                        tonic::server::UnaryService<super::DeleteWebhookRequest>
+                        for delete_webhookSvc<T>
+                    {
+                        type Response = super::DeleteWebhookResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DeleteWebhookRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DocumentCollection>::delete_webhook(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = delete_webhookSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/document_collection.DocumentCollection/list_deliveries" => {
+                    #[allow(non_camel_case_types)]
+                    struct list_deliveriesSvc<T: DocumentCollection>(pub Arc<T>);
+                    impl<T: DocumentCollection>
+                        tonic::server::UnaryService<super::ListDeliveriesRequest>
+                        for list_deliveriesSvc<T>
+                    {
+                        type Response = super::ListDeliveriesResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListDeliveriesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DocumentCollection>::list_deliveries(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = list_deliveriesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => Box::pin(async move {
+                    Ok(http::Response::builder()
+                        .status(200)
+                        .header("grpc-status", "12")
+                        .header("content-type", "application/grpc")
+                        .body(empty_body())
+                        .unwrap())
+                }),
+            }
+        }
+    }
+    impl<T: DocumentCollection> Clone for DocumentCollectionServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    impl<T: DocumentCollection> Clone for _Inner<T> {
+        fn clone(&self) -> Self {
+            Self(Arc::clone(&self.0))
+        }
+    }
+    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+    impl<T: DocumentCollection> tonic::server::NamedService for DocumentCollectionServer<T> {
+        const NAME: &'static str = "document_collection.DocumentCollection";
+    }
+}
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_DOCUMENT: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.Document")]
+    impl ::prost_wkt::MessageSerde for Document {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "Document"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.Document"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
+        }
+    }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.Document" , decoder : | buf : & [u8] | { let msg : Document = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for Document {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "Document";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.Document".to_string()
+        }
+    }
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_DOCUMENT_ITEM: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.DocumentItem")]
+    impl ::prost_wkt::MessageSerde for DocumentItem {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "DocumentItem"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.DocumentItem"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
+        }
+    }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.DocumentItem" , decoder : | buf : & [u8] | { let msg : DocumentItem = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for DocumentItem {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "DocumentItem";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.DocumentItem".to_string()
+        }
+    }
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_ITEM1: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.Item1")]
+    impl ::prost_wkt::MessageSerde for Item1 {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "Item1"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.Item1"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
+        }
+    }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.Item1" , decoder : | buf : & [u8] | { let msg : Item1 = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for Item1 {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "Item1";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.Item1".to_string()
+        }
+    }
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_ITEM0: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.Item0")]
+    impl ::prost_wkt::MessageSerde for Item0 {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "Item0"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.Item0"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
+        }
+    }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.Item0" , decoder : | buf : & [u8] | { let msg : Item0 = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for Item0 {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "Item0";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.Item0".to_string()
+        }
+    }
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_GET_REQUEST: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.GetRequest")]
+    impl ::prost_wkt::MessageSerde for GetRequest {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "GetRequest"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.GetRequest"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
+        }
+    }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.GetRequest" , decoder : | buf : & [u8] | { let msg : GetRequest = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for GetRequest {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "GetRequest";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.GetRequest".to_string()
+        }
+    }
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_GET_RESPONSE: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.GetResponse")]
+    impl ::prost_wkt::MessageSerde for GetResponse {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "GetResponse"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.GetResponse"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
+        }
+    }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.GetResponse" , decoder : | buf : & [u8] | { let msg : GetResponse = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for GetResponse {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "GetResponse";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.GetResponse".to_string()
+        }
+    }
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_DOCUMENT_QUERY: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.DocumentQuery")]
+    impl ::prost_wkt::MessageSerde for DocumentQuery {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "DocumentQuery"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.DocumentQuery"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
+        }
+    }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.DocumentQuery" , decoder : | buf : & [u8] | { let msg : DocumentQuery = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for DocumentQuery {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "DocumentQuery";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.DocumentQuery".to_string()
+        }
+    }
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_FILTER: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.Filter")]
+    impl ::prost_wkt::MessageSerde for Filter {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "Filter"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.Filter"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
+        }
+    }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.Filter" , decoder : | buf : & [u8] | { let msg : Filter = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for Filter {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "Filter";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.Filter".to_string()
+        }
+    }
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_FILTER_LIST: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.FilterList")]
+    impl ::prost_wkt::MessageSerde for FilterList {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "FilterList"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.FilterList"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
+        }
+    }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.FilterList" , decoder : | buf : & [u8] | { let msg : FilterList = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for FilterList {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "FilterList";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.FilterList".to_string()
+        }
+    }
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_FIELD_FILTER: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.FieldFilter")]
+    impl ::prost_wkt::MessageSerde for FieldFilter {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "FieldFilter"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.FieldFilter"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
+        }
+    }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.FieldFilter" , decoder : | buf : & [u8] | { let msg : FieldFilter = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for FieldFilter {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "FieldFilter";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.FieldFilter".to_string()
+        }
+    }
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_QUERY_REQUEST: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.QueryRequest")]
+    impl ::prost_wkt::MessageSerde for QueryRequest {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "QueryRequest"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.QueryRequest"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
+        }
+    }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.QueryRequest" , decoder : | buf : & [u8] | { let msg : QueryRequest = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for QueryRequest {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "QueryRequest";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.QueryRequest".to_string()
+        }
+    }
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_ORDER_BY: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.OrderBy")]
+    impl ::prost_wkt::MessageSerde for OrderBy {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "OrderBy"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.OrderBy"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
+        }
+    }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.OrderBy" , decoder : | buf : & [u8] | { let msg : OrderBy = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for OrderBy {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "OrderBy";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.OrderBy".to_string()
+        }
+    }
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_LIST_RESPONSE: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.ListResponse")]
+    impl ::prost_wkt::MessageSerde for ListResponse {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "ListResponse"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.ListResponse"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
+        }
+    }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.ListResponse" , decoder : | buf : & [u8] | { let msg : ListResponse = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for ListResponse {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "ListResponse";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.ListResponse".to_string()
+        }
+    }
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_AGGREGATE_REQUEST: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.AggregateRequest")]
+    impl ::prost_wkt::MessageSerde for AggregateRequest {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "AggregateRequest"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.AggregateRequest"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
+        }
+    }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.AggregateRequest" , decoder : | buf : & [u8] | { let msg : AggregateRequest = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for AggregateRequest {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "AggregateRequest";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.AggregateRequest".to_string()
+        }
+    }
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_GROUP_BY: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.GroupBy")]
+    impl ::prost_wkt::MessageSerde for GroupBy {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "GroupBy"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.GroupBy"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
+        }
+    }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.GroupBy" , decoder : | buf : & [u8] | { let msg : GroupBy = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for GroupBy {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "GroupBy";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.GroupBy".to_string()
+        }
+    }
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_AGGREGATE_RESPONSE: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.AggregateResponse")]
+    impl ::prost_wkt::MessageSerde for AggregateResponse {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "AggregateResponse"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.AggregateResponse"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
+        }
+    }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.AggregateResponse" , decoder : | buf : & [u8] | { let msg : AggregateResponse = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for AggregateResponse {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "AggregateResponse";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.AggregateResponse".to_string()
+        }
+    }
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_AGGREGATE_GROUP: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.AggregateGroup")]
+    impl ::prost_wkt::MessageSerde for AggregateGroup {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "AggregateGroup"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.AggregateGroup"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
+        }
+    }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.AggregateGroup" , decoder : | buf : & [u8] | { let msg : AggregateGroup = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for AggregateGroup {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "AggregateGroup";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.AggregateGroup".to_string()
+        }
+    }
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_FIELD_AGGREGATE: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.FieldAggregate")]
+    impl ::prost_wkt::MessageSerde for FieldAggregate {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "FieldAggregate"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.FieldAggregate"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
+        }
+    }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.FieldAggregate" , decoder : | buf : & [u8] | { let msg : FieldAggregate = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for FieldAggregate {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "FieldAggregate";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.FieldAggregate".to_string()
+        }
+    }
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_CREATE_REQUEST: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.CreateRequest")]
+    impl ::prost_wkt::MessageSerde for CreateRequest {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "CreateRequest"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.CreateRequest"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
+        }
+    }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.CreateRequest" , decoder : | buf : & [u8] | { let msg : CreateRequest = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for CreateRequest {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "CreateRequest";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.CreateRequest".to_string()
+        }
+    }
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_CREATE_RESPONSE: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.CreateResponse")]
+    impl ::prost_wkt::MessageSerde for CreateResponse {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "CreateResponse"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.CreateResponse"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
+        }
+    }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.CreateResponse" , decoder : | buf : & [u8] | { let msg : CreateResponse = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for CreateResponse {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "CreateResponse";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.CreateResponse".to_string()
+        }
+    }
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_UPDATE_REQUEST: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.UpdateRequest")]
+    impl ::prost_wkt::MessageSerde for UpdateRequest {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "UpdateRequest"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.UpdateRequest"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
+        }
+    }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.UpdateRequest" , decoder : | buf : & [u8] | { let msg : UpdateRequest = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for UpdateRequest {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "UpdateRequest";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.UpdateRequest".to_string()
+        }
+    }
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_UPDATE_RESPONSE: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.UpdateResponse")]
+    impl ::prost_wkt::MessageSerde for UpdateResponse {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "UpdateResponse"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.UpdateResponse"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
+        }
+    }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.UpdateResponse" , decoder : | buf : & [u8] | { let msg : UpdateResponse = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for UpdateResponse {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "UpdateResponse";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.UpdateResponse".to_string()
+        }
+    }
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_PATCH_REQUEST: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.PatchRequest")]
+    impl ::prost_wkt::MessageSerde for PatchRequest {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "PatchRequest"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.PatchRequest"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
+        }
+    }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.PatchRequest" , decoder : | buf : & [u8] | { let msg : PatchRequest = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for PatchRequest {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "PatchRequest";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.PatchRequest".to_string()
+        }
+    }
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_JSON_PATCH: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.JsonPatch")]
+    impl ::prost_wkt::MessageSerde for JsonPatch {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "JsonPatch"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.JsonPatch"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
+        }
+    }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.JsonPatch" , decoder : | buf : & [u8] | { let msg : JsonPatch = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for JsonPatch {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "JsonPatch";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.JsonPatch".to_string()
+        }
+    }
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_JSON_PATCH_OPERATION: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.JsonPatchOperation")]
+    impl ::prost_wkt::MessageSerde for JsonPatchOperation {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "JsonPatchOperation"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.JsonPatchOperation"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
+        }
+    }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.JsonPatchOperation" , decoder : | buf : & [u8] | { let msg : JsonPatchOperation = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for JsonPatchOperation {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "JsonPatchOperation";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.JsonPatchOperation".to_string()
+        }
+    }
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_PATCH_RESPONSE: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.PatchResponse")]
+    impl ::prost_wkt::MessageSerde for PatchResponse {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "PatchResponse"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.PatchResponse"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
+        }
+    }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.PatchResponse" , decoder : | buf : & [u8] | { let msg : PatchResponse = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for PatchResponse {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "PatchResponse";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.PatchResponse".to_string()
+        }
+    }
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_DELETE_REQUEST: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.DeleteRequest")]
+    impl ::prost_wkt::MessageSerde for DeleteRequest {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "DeleteRequest"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.DeleteRequest"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
+        }
+    }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.DeleteRequest" , decoder : | buf : & [u8] | { let msg : DeleteRequest = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for DeleteRequest {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "DeleteRequest";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.DeleteRequest".to_string()
+        }
+    }
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_DELETE_RESPONSE: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.DeleteResponse")]
+    impl ::prost_wkt::MessageSerde for DeleteResponse {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "DeleteResponse"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.DeleteResponse"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
+        }
+    }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.DeleteResponse" , decoder : | buf : & [u8] | { let msg : DeleteResponse = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for DeleteResponse {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "DeleteResponse";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.DeleteResponse".to_string()
+        }
+    }
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_UNDELETE_REQUEST: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.UndeleteRequest")]
+    impl ::prost_wkt::MessageSerde for UndeleteRequest {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "UndeleteRequest"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.UndeleteRequest"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
+        }
+    }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.UndeleteRequest" , decoder : | buf : & [u8] | { let msg : UndeleteRequest = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for UndeleteRequest {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "UndeleteRequest";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.UndeleteRequest".to_string()
+        }
+    }
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_UNDELETE_RESPONSE: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.UndeleteResponse")]
+    impl ::prost_wkt::MessageSerde for UndeleteResponse {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "UndeleteResponse"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.UndeleteResponse"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
+        }
+    }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.UndeleteResponse" , decoder : | buf : & [u8] | { let msg : UndeleteResponse = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for UndeleteResponse {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "UndeleteResponse";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.UndeleteResponse".to_string()
+        }
+    }
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_BATCH_CREATE_REQUEST: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.BatchCreateRequest")]
+    impl ::prost_wkt::MessageSerde for BatchCreateRequest {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "BatchCreateRequest"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.BatchCreateRequest"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
+        }
+    }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.BatchCreateRequest" , decoder : | buf : & [u8] | { let msg : BatchCreateRequest = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for BatchCreateRequest {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "BatchCreateRequest";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.BatchCreateRequest".to_string()
+        }
+    }
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_BATCH_UPDATE_REQUEST: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.BatchUpdateRequest")]
+    impl ::prost_wkt::MessageSerde for BatchUpdateRequest {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "BatchUpdateRequest"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.BatchUpdateRequest"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
+        }
+    }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.BatchUpdateRequest" , decoder : | buf : & [u8] | { let msg : BatchUpdateRequest = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for BatchUpdateRequest {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "BatchUpdateRequest";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.BatchUpdateRequest".to_string()
+        }
+    }
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_BATCH_DELETE_REQUEST: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.BatchDeleteRequest")]
+    impl ::prost_wkt::MessageSerde for BatchDeleteRequest {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "BatchDeleteRequest"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.BatchDeleteRequest"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
+        }
+    }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.BatchDeleteRequest" , decoder : | buf : & [u8] | { let msg : BatchDeleteRequest = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for BatchDeleteRequest {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "BatchDeleteRequest";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.BatchDeleteRequest".to_string()
+        }
+    }
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_BATCH_RESULT: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.BatchResult")]
+    impl ::prost_wkt::MessageSerde for BatchResult {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "BatchResult"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.BatchResult"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
+        }
+    }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.BatchResult" , decoder : | buf : & [u8] | { let msg : BatchResult = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for BatchResult {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "BatchResult";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.BatchResult".to_string()
+        }
+    }
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_BATCH_RESPONSE: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.BatchResponse")]
+    impl ::prost_wkt::MessageSerde for BatchResponse {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "BatchResponse"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.BatchResponse"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
+        }
+    }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.BatchResponse" , decoder : | buf : & [u8] | { let msg : BatchResponse = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for BatchResponse {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "BatchResponse";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.BatchResponse".to_string()
+        }
+    }
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_DOCUMENT_REVISION: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.DocumentRevision")]
+    impl ::prost_wkt::MessageSerde for DocumentRevision {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "DocumentRevision"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.DocumentRevision"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
+        }
+    }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.DocumentRevision" , decoder : | buf : & [u8] | { let msg : DocumentRevision = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for DocumentRevision {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "DocumentRevision";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.DocumentRevision".to_string()
+        }
+    }
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_LIST_REVISIONS_REQUEST: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.ListRevisionsRequest")]
+    impl ::prost_wkt::MessageSerde for ListRevisionsRequest {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "ListRevisionsRequest"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.ListRevisionsRequest"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
+        }
+    }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.ListRevisionsRequest" , decoder : | buf : & [u8] | { let msg : ListRevisionsRequest = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for ListRevisionsRequest {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "ListRevisionsRequest";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.ListRevisionsRequest".to_string()
+        }
+    }
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_LIST_REVISIONS_RESPONSE: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.ListRevisionsResponse")]
+    impl ::prost_wkt::MessageSerde for ListRevisionsResponse {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "ListRevisionsResponse"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.ListRevisionsResponse"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
+        }
+    }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.ListRevisionsResponse" , decoder : | buf : & [u8] | { let msg : ListRevisionsResponse = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for ListRevisionsResponse {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "ListRevisionsResponse";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.ListRevisionsResponse".to_string()
+        }
+    }
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_RESTORE_REQUEST: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.RestoreRequest")]
+    impl ::prost_wkt::MessageSerde for RestoreRequest {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "RestoreRequest"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.RestoreRequest"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
+        }
+    }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.RestoreRequest" , decoder : | buf : & [u8] | { let msg : RestoreRequest = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for RestoreRequest {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "RestoreRequest";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.RestoreRequest".to_string()
+        }
+    }
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_RESTORE_RESPONSE: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.RestoreResponse")]
+    impl ::prost_wkt::MessageSerde for RestoreResponse {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "RestoreResponse"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.RestoreResponse"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
+        }
+    }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.RestoreResponse" , decoder : | buf : & [u8] | { let msg : RestoreResponse = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for RestoreResponse {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "RestoreResponse";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.RestoreResponse".to_string()
+        }
+    }
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_DOCUMENT_SCHEMA: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.DocumentSchema")]
+    impl ::prost_wkt::MessageSerde for DocumentSchema {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "DocumentSchema"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.DocumentSchema"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
+        }
+    }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.DocumentSchema" , decoder : | buf : & [u8] | { let msg : DocumentSchema = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for DocumentSchema {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "DocumentSchema";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.DocumentSchema".to_string()
+        }
+    }
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_REGISTER_SCHEMA_REQUEST: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.RegisterSchemaRequest")]
+    impl ::prost_wkt::MessageSerde for RegisterSchemaRequest {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "RegisterSchemaRequest"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.RegisterSchemaRequest"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
+        }
+    }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.RegisterSchemaRequest" , decoder : | buf : & [u8] | { let msg : RegisterSchemaRequest = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for RegisterSchemaRequest {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "RegisterSchemaRequest";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.RegisterSchemaRequest".to_string()
+        }
+    }
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_REGISTER_SCHEMA_RESPONSE: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.RegisterSchemaResponse")]
+    impl ::prost_wkt::MessageSerde for RegisterSchemaResponse {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "RegisterSchemaResponse"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.RegisterSchemaResponse"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
+        }
+    }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.RegisterSchemaResponse" , decoder : | buf : & [u8] | { let msg : RegisterSchemaResponse = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for RegisterSchemaResponse {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "RegisterSchemaResponse";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.RegisterSchemaResponse".to_string()
+        }
+    }
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_GET_SCHEMA_REQUEST: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.GetSchemaRequest")]
+    impl ::prost_wkt::MessageSerde for GetSchemaRequest {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "GetSchemaRequest"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.GetSchemaRequest"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
+        }
+    }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.GetSchemaRequest" , decoder : | buf : & [u8] | { let msg : GetSchemaRequest = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for GetSchemaRequest {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "GetSchemaRequest";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.GetSchemaRequest".to_string()
+        }
+    }
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_GET_SCHEMA_RESPONSE: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.GetSchemaResponse")]
+    impl ::prost_wkt::MessageSerde for GetSchemaResponse {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "GetSchemaResponse"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.GetSchemaResponse"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
         }
     }
-}
-/// Generated server implementations.
-pub mod document_collection_server {
-    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
-    use tonic::codegen::*;
-    /// Generated trait containing gRPC methods that should be implemented for use with DocumentCollectionServer.
-    #[async_trait]
-    pub trait DocumentCollection: Send + Sync + 'static {
-        async fn get(
-            &self,
-            request: tonic::Request<super::GetRequest>,
-        ) -> std::result::Result<tonic::Response<super::GetResponse>, tonic::Status>;
-        /// Server streaming response type for the query method.
-        type queryStream: tonic::codegen::tokio_stream::Stream<
-                Item = std::result::Result<super::Document, tonic::Status>,
-            > + Send
-            + 'static;
-        async fn query(
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.GetSchemaResponse" , decoder : | buf : & [u8] | { let msg : GetSchemaResponse = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for GetSchemaResponse {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "GetSchemaResponse";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.GetSchemaResponse".to_string()
+        }
+    }
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_LIST_SCHEMAS_REQUEST: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.ListSchemasRequest")]
+    impl ::prost_wkt::MessageSerde for ListSchemasRequest {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "ListSchemasRequest"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.ListSchemasRequest"
+        }
+        fn new_instance(
             &self,
-            request: tonic::Request<super::QueryRequest>,
-        ) -> std::result::Result<tonic::Response<Self::queryStream>, tonic::Status>;
-        async fn create(
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
+        }
+    }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.ListSchemasRequest" , decoder : | buf : & [u8] | { let msg : ListSchemasRequest = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for ListSchemasRequest {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "ListSchemasRequest";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.ListSchemasRequest".to_string()
+        }
+    }
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_LIST_SCHEMAS_RESPONSE: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.ListSchemasResponse")]
+    impl ::prost_wkt::MessageSerde for ListSchemasResponse {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "ListSchemasResponse"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.ListSchemasResponse"
+        }
+        fn new_instance(
             &self,
-            request: tonic::Request<super::CreateRequest>,
-        ) -> std::result::Result<tonic::Response<super::CreateResponse>, tonic::Status>;
-        async fn update(
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
+        }
+    }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.ListSchemasResponse" , decoder : | buf : & [u8] | { let msg : ListSchemasResponse = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for ListSchemasResponse {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "ListSchemasResponse";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.ListSchemasResponse".to_string()
+        }
+    }
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_COLLECTION: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.Collection")]
+    impl ::prost_wkt::MessageSerde for Collection {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "Collection"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.Collection"
+        }
+        fn new_instance(
             &self,
-            request: tonic::Request<super::UpdateRequest>,
-        ) -> std::result::Result<tonic::Response<super::UpdateResponse>, tonic::Status>;
-        async fn delete(
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
+        }
+    }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.Collection" , decoder : | buf : & [u8] | { let msg : Collection = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for Collection {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "Collection";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.Collection".to_string()
+        }
+    }
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_CREATE_COLLECTION_REQUEST: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.CreateCollectionRequest")]
+    impl ::prost_wkt::MessageSerde for CreateCollectionRequest {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "CreateCollectionRequest"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.CreateCollectionRequest"
+        }
+        fn new_instance(
             &self,
-            request: tonic::Request<super::DeleteRequest>,
-        ) -> std::result::Result<tonic::Response<super::DeleteResponse>, tonic::Status>;
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
+        }
     }
-    #[derive(Debug)]
-    pub struct DocumentCollectionServer<T: DocumentCollection> {
-        inner: _Inner<T>,
-        accept_compression_encodings: EnabledCompressionEncodings,
-        send_compression_encodings: EnabledCompressionEncodings,
-        max_decoding_message_size: Option<usize>,
-        max_encoding_message_size: Option<usize>,
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.CreateCollectionRequest" , decoder : | buf : & [u8] | { let msg : CreateCollectionRequest = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for CreateCollectionRequest {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "CreateCollectionRequest";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.CreateCollectionRequest".to_string()
+        }
     }
-    struct _Inner<T>(Arc<T>);
-    impl<T: DocumentCollection> DocumentCollectionServer<T> {
-        pub fn new(inner: T) -> Self {
-            Self::from_arc(Arc::new(inner))
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_CREATE_COLLECTION_RESPONSE: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.CreateCollectionResponse")]
+    impl ::prost_wkt::MessageSerde for CreateCollectionResponse {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
         }
-        pub fn from_arc(inner: Arc<T>) -> Self {
-            let inner = _Inner(inner);
-            Self {
-                inner,
-                accept_compression_encodings: Default::default(),
-                send_compression_encodings: Default::default(),
-                max_decoding_message_size: None,
-                max_encoding_message_size: None,
-            }
+        fn message_name(&self) -> &'static str {
+            "CreateCollectionResponse"
         }
-        pub fn with_interceptor<F>(inner: T, interceptor: F) -> InterceptedService<Self, F>
-        where
-            F: tonic::service::Interceptor,
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.CreateCollectionResponse"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
         {
-            InterceptedService::new(Self::new(inner), interceptor)
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
         }
-        /// Enable decompressing requests with the given encoding.
-        #[must_use]
-        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
-            self.accept_compression_encodings.enable(encoding);
-            self
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
         }
-        /// Compress responses with the given encoding, if the client supports it.
-        #[must_use]
-        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
-            self.send_compression_encodings.enable(encoding);
-            self
+    }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.CreateCollectionResponse" , decoder : | buf : & [u8] | { let msg : CreateCollectionResponse = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for CreateCollectionResponse {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "CreateCollectionResponse";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.CreateCollectionResponse".to_string()
         }
-        /// Limits the maximum size of a decoded message.
-        ///
-        /// Default: `4MB`
-        #[must_use]
-        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
-            self.max_decoding_message_size = Some(limit);
-            self
+    }
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_LIST_COLLECTIONS_REQUEST: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.ListCollectionsRequest")]
+    impl ::prost_wkt::MessageSerde for ListCollectionsRequest {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "ListCollectionsRequest"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.ListCollectionsRequest"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
         }
-        /// Limits the maximum size of an encoded message.
-        ///
-        /// Default: `usize::MAX`
-        #[must_use]
-        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
-            self.max_encoding_message_size = Some(limit);
-            self
+    }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.ListCollectionsRequest" , decoder : | buf : & [u8] | { let msg : ListCollectionsRequest = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for ListCollectionsRequest {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "ListCollectionsRequest";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.ListCollectionsRequest".to_string()
         }
     }
-    impl<T, B> tonic::codegen::Service<http::Request<B>> for DocumentCollectionServer<T>
-    where
-        T: DocumentCollection,
-        B: Body + Send + 'static,
-        B::Error: Into<StdError> + Send + 'static,
-    {
-        type Response = http::Response<tonic::body::BoxBody>;
-        type Error = std::convert::Infallible;
-        type Future = BoxFuture<Self::Response, Self::Error>;
-        fn poll_ready(
-            &mut self,
-            _cx: &mut Context<'_>,
-        ) -> Poll<std::result::Result<(), Self::Error>> {
-            Poll::Ready(Ok(()))
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_LIST_COLLECTIONS_RESPONSE: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.ListCollectionsResponse")]
+    impl ::prost_wkt::MessageSerde for ListCollectionsResponse {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
         }
-        fn call(&mut self, req: http::Request<B>) -> Self::Future {
-            let inner = self.inner.clone();
-            match req.uri().path() {
-                "/document_collection.DocumentCollection/get" => {
-                    #[allow(non_camel_case_types)]
-                    struct getSvc<T: DocumentCollection>(pub Arc<T>);
-                    impl<T: DocumentCollection> tonic::server::UnaryService<super::GetRequest> for getSvc<T> {
-                        type Response = super::GetResponse;
-                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
-                        fn call(
-                            &mut self,
-                            request: tonic::Request<super::GetRequest>,
-                        ) -> Self::Future {
-                            let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as DocumentCollection>::get(&inner, request).await
-                            };
-                            Box::pin(fut)
-                        }
-                    }
-                    let accept_compression_encodings = self.accept_compression_encodings;
-                    let send_compression_encodings = self.send_compression_encodings;
-                    let max_decoding_message_size = self.max_decoding_message_size;
-                    let max_encoding_message_size = self.max_encoding_message_size;
-                    let inner = self.inner.clone();
-                    let fut = async move {
-                        let inner = inner.0;
-                        let method = getSvc(inner);
-                        let codec = tonic::codec::ProstCodec::default();
-                        let mut grpc = tonic::server::Grpc::new(codec)
-                            .apply_compression_config(
-                                accept_compression_encodings,
-                                send_compression_encodings,
-                            )
-                            .apply_max_message_size_config(
-                                max_decoding_message_size,
-                                max_encoding_message_size,
-                            );
-                        let res = grpc.unary(method, req).await;
-                        Ok(res)
-                    };
-                    Box::pin(fut)
-                }
-                "/document_collection.DocumentCollection/query" => {
-                    #[allow(non_camel_case_types)]
-                    struct querySvc<T: DocumentCollection>(pub Arc<T>);
-                    impl<T: DocumentCollection>
-                        tonic::server::ServerStreamingService<super::QueryRequest> for querySvc<T>
-                    {
-                        type Response = super::Document;
-                        type ResponseStream = T::queryStream;
-                        type Future =
-                            BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
-                        fn call(
-                            &mut self,
-                            request: tonic::Request<super::QueryRequest>,
-                        ) -> Self::Future {
-                            let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as DocumentCollection>::query(&inner, request).await
-                            };
-                            Box::pin(fut)
-                        }
-                    }
-                    let accept_compression_encodings = self.accept_compression_encodings;
-                    let send_compression_encodings = self.send_compression_encodings;
-                    let max_decoding_message_size = self.max_decoding_message_size;
-                    let max_encoding_message_size = self.max_encoding_message_size;
-                    let inner = self.inner.clone();
-                    let fut = async move {
-                        let inner = inner.0;
-                        let method = querySvc(inner);
-                        let codec = tonic::codec::ProstCodec::default();
-                        let mut grpc = tonic::server::Grpc::new(codec)
-                            .apply_compression_config(
-                                accept_compression_encodings,
-                                send_compression_encodings,
-                            )
-                            .apply_max_message_size_config(
-                                max_decoding_message_size,
-                                max_encoding_message_size,
-                            );
-                        let res = grpc.server_streaming(method, req).await;
-                        Ok(res)
-                    };
-                    Box::pin(fut)
-                }
-                "/document_collection.DocumentCollection/create" => {
-                    #[allow(non_camel_case_types)]
-                    struct createSvc<T: DocumentCollection>(pub Arc<T>);
-                    impl<T: DocumentCollection> tonic::server::UnaryService<super::CreateRequest> for createSvc<T> {
-                        type Response = super::CreateResponse;
-                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
-                        fn call(
-                            &mut self,
-                            request: tonic::Request<super::CreateRequest>,
-                        ) -> Self::Future {
-                            let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as DocumentCollection>::create(&inner, request).await
-                            };
-                            Box::pin(fut)
-                        }
-                    }
-                    let accept_compression_encodings = self.accept_compression_encodings;
-                    let send_compression_encodings = self.send_compression_encodings;
-                    let max_decoding_message_size = self.max_decoding_message_size;
-                    let max_encoding_message_size = self.max_encoding_message_size;
-                    let inner = self.inner.clone();
-                    let fut = async move {
-                        let inner = inner.0;
-                        let method = createSvc(inner);
-                        let codec = tonic::codec::ProstCodec::default();
-                        let mut grpc = tonic::server::Grpc::new(codec)
-                            .apply_compression_config(
-                                accept_compression_encodings,
-                                send_compression_encodings,
-                            )
-                            .apply_max_message_size_config(
-                                max_decoding_message_size,
-                                max_encoding_message_size,
-                            );
-                        let res = grpc.unary(method, req).await;
-                        Ok(res)
-                    };
-                    Box::pin(fut)
-                }
-                "/document_collection.DocumentCollection/update" => {
-                    #[allow(non_camel_case_types)]
-                    struct updateSvc<T: DocumentCollection>(pub Arc<T>);
-                    impl<T: DocumentCollection> tonic::server::UnaryService<super::UpdateRequest> for updateSvc<T> {
-                        type Response = super::UpdateResponse;
-                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
-                        fn call(
-                            &mut self,
-                            request: tonic::Request<super::UpdateRequest>,
-                        ) -> Self::Future {
-                            let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as DocumentCollection>::update(&inner, request).await
-                            };
-                            Box::pin(fut)
-                        }
-                    }
-                    let accept_compression_encodings = self.accept_compression_encodings;
-                    let send_compression_encodings = self.send_compression_encodings;
-                    let max_decoding_message_size = self.max_decoding_message_size;
-                    let max_encoding_message_size = self.max_encoding_message_size;
-                    let inner = self.inner.clone();
-                    let fut = async move {
-                        let inner = inner.0;
-                        let method = updateSvc(inner);
-                        let codec = tonic::codec::ProstCodec::default();
-                        let mut grpc = tonic::server::Grpc::new(codec)
-                            .apply_compression_config(
-                                accept_compression_encodings,
-                                send_compression_encodings,
-                            )
-                            .apply_max_message_size_config(
-                                max_decoding_message_size,
-                                max_encoding_message_size,
-                            );
-                        let res = grpc.unary(method, req).await;
-                        Ok(res)
-                    };
-                    Box::pin(fut)
-                }
-                "/document_collection.DocumentCollection/delete" => {
-                    #[allow(non_camel_case_types)]
-                    struct deleteSvc<T: DocumentCollection>(pub Arc<T>);
-                    impl<T: DocumentCollection> tonic::server::UnaryService<super::DeleteRequest> for deleteSvc<T> {
-                        type Response = super::DeleteResponse;
-                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
-                        fn call(
-                            &mut self,
-                            request: tonic::Request<super::DeleteRequest>,
-                        ) -> Self::Future {
-                            let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as DocumentCollection>::delete(&inner, request).await
-                            };
-                            Box::pin(fut)
-                        }
-                    }
-                    let accept_compression_encodings = self.accept_compression_encodings;
-                    let send_compression_encodings = self.send_compression_encodings;
-                    let max_decoding_message_size = self.max_decoding_message_size;
-                    let max_encoding_message_size = self.max_encoding_message_size;
-                    let inner = self.inner.clone();
-                    let fut = async move {
-                        let inner = inner.0;
-                        let method = deleteSvc(inner);
-                        let codec = tonic::codec::ProstCodec::default();
-                        let mut grpc = tonic::server::Grpc::new(codec)
-                            .apply_compression_config(
-                                accept_compression_encodings,
-                                send_compression_encodings,
-                            )
-                            .apply_max_message_size_config(
-                                max_decoding_message_size,
-                                max_encoding_message_size,
-                            );
-                        let res = grpc.unary(method, req).await;
-                        Ok(res)
-                    };
-                    Box::pin(fut)
-                }
-                _ => Box::pin(async move {
-                    Ok(http::Response::builder()
-                        .status(200)
-                        .header("grpc-status", "12")
-                        .header("content-type", "application/grpc")
-                        .body(empty_body())
-                        .unwrap())
-                }),
-            }
+        fn message_name(&self) -> &'static str {
+            "ListCollectionsResponse"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.ListCollectionsResponse"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
         }
     }
-    impl<T: DocumentCollection> Clone for DocumentCollectionServer<T> {
-        fn clone(&self) -> Self {
-            let inner = self.inner.clone();
-            Self {
-                inner,
-                accept_compression_encodings: self.accept_compression_encodings,
-                send_compression_encodings: self.send_compression_encodings,
-                max_decoding_message_size: self.max_decoding_message_size,
-                max_encoding_message_size: self.max_encoding_message_size,
-            }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.ListCollectionsResponse" , decoder : | buf : & [u8] | { let msg : ListCollectionsResponse = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for ListCollectionsResponse {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "ListCollectionsResponse";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.ListCollectionsResponse".to_string()
         }
     }
-    impl<T: DocumentCollection> Clone for _Inner<T> {
-        fn clone(&self) -> Self {
-            Self(Arc::clone(&self.0))
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_DROP_COLLECTION_REQUEST: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.DropCollectionRequest")]
+    impl ::prost_wkt::MessageSerde for DropCollectionRequest {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "DropCollectionRequest"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.DropCollectionRequest"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
         }
     }
-    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
-        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            write!(f, "{:?}", self.0)
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.DropCollectionRequest" , decoder : | buf : & [u8] | { let msg : DropCollectionRequest = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for DropCollectionRequest {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "DropCollectionRequest";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.DropCollectionRequest".to_string()
         }
     }
-    impl<T: DocumentCollection> tonic::server::NamedService for DocumentCollectionServer<T> {
-        const NAME: &'static str = "document_collection.DocumentCollection";
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_DROP_COLLECTION_RESPONSE: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.DropCollectionResponse")]
+    impl ::prost_wkt::MessageSerde for DropCollectionResponse {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "DropCollectionResponse"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.DropCollectionResponse"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
+        }
     }
-}
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.DropCollectionResponse" , decoder : | buf : & [u8] | { let msg : DropCollectionResponse = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for DropCollectionResponse {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "DropCollectionResponse";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.DropCollectionResponse".to_string()
+        }
+    }
+};
 
 #[allow(dead_code)]
-const IMPL_MESSAGE_SERDE_FOR_DOCUMENT: () = {
+const IMPL_MESSAGE_SERDE_FOR_DOCUMENT_CHANGE: () = {
     use ::prost_wkt::typetag;
-    #[typetag::serde(name = "type.googleapis.com/document_collection.Document")]
-    impl ::prost_wkt::MessageSerde for Document {
+    #[typetag::serde(name = "type.googleapis.com/document_collection.DocumentChange")]
+    impl ::prost_wkt::MessageSerde for DocumentChange {
         fn package_name(&self) -> &'static str {
             "document_collection"
         }
         fn message_name(&self) -> &'static str {
-            "Document"
+            "DocumentChange"
         }
         fn type_url(&self) -> &'static str {
-            "type.googleapis.com/document_collection.Document"
+            "type.googleapis.com/document_collection.DocumentChange"
         }
         fn new_instance(
             &self,
@@ -666,29 +5664,29 @@ const IMPL_MESSAGE_SERDE_FOR_DOCUMENT: () = {
             Ok(buf)
         }
     }
-    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.Document" , decoder : | buf : & [u8] | { let msg : Document = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
-    impl ::prost::Name for Document {
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.DocumentChange" , decoder : | buf : & [u8] | { let msg : DocumentChange = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for DocumentChange {
         const PACKAGE: &'static str = "document_collection";
-        const NAME: &'static str = "Document";
+        const NAME: &'static str = "DocumentChange";
         fn type_url() -> String {
-            "type.googleapis.com/document_collection.Document".to_string()
+            "type.googleapis.com/document_collection.DocumentChange".to_string()
         }
     }
 };
 
 #[allow(dead_code)]
-const IMPL_MESSAGE_SERDE_FOR_GET_REQUEST: () = {
+const IMPL_MESSAGE_SERDE_FOR_WATCH_REQUEST: () = {
     use ::prost_wkt::typetag;
-    #[typetag::serde(name = "type.googleapis.com/document_collection.GetRequest")]
-    impl ::prost_wkt::MessageSerde for GetRequest {
+    #[typetag::serde(name = "type.googleapis.com/document_collection.WatchRequest")]
+    impl ::prost_wkt::MessageSerde for WatchRequest {
         fn package_name(&self) -> &'static str {
             "document_collection"
         }
         fn message_name(&self) -> &'static str {
-            "GetRequest"
+            "WatchRequest"
         }
         fn type_url(&self) -> &'static str {
-            "type.googleapis.com/document_collection.GetRequest"
+            "type.googleapis.com/document_collection.WatchRequest"
         }
         fn new_instance(
             &self,
@@ -707,29 +5705,29 @@ const IMPL_MESSAGE_SERDE_FOR_GET_REQUEST: () = {
             Ok(buf)
         }
     }
-    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.GetRequest" , decoder : | buf : & [u8] | { let msg : GetRequest = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
-    impl ::prost::Name for GetRequest {
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.WatchRequest" , decoder : | buf : & [u8] | { let msg : WatchRequest = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for WatchRequest {
         const PACKAGE: &'static str = "document_collection";
-        const NAME: &'static str = "GetRequest";
+        const NAME: &'static str = "WatchRequest";
         fn type_url() -> String {
-            "type.googleapis.com/document_collection.GetRequest".to_string()
+            "type.googleapis.com/document_collection.WatchRequest".to_string()
         }
     }
 };
 
 #[allow(dead_code)]
-const IMPL_MESSAGE_SERDE_FOR_GET_RESPONSE: () = {
+const IMPL_MESSAGE_SERDE_FOR_READ_CHANGES_REQUEST: () = {
     use ::prost_wkt::typetag;
-    #[typetag::serde(name = "type.googleapis.com/document_collection.GetResponse")]
-    impl ::prost_wkt::MessageSerde for GetResponse {
+    #[typetag::serde(name = "type.googleapis.com/document_collection.ReadChangesRequest")]
+    impl ::prost_wkt::MessageSerde for ReadChangesRequest {
         fn package_name(&self) -> &'static str {
             "document_collection"
         }
         fn message_name(&self) -> &'static str {
-            "GetResponse"
+            "ReadChangesRequest"
         }
         fn type_url(&self) -> &'static str {
-            "type.googleapis.com/document_collection.GetResponse"
+            "type.googleapis.com/document_collection.ReadChangesRequest"
         }
         fn new_instance(
             &self,
@@ -748,29 +5746,29 @@ const IMPL_MESSAGE_SERDE_FOR_GET_RESPONSE: () = {
             Ok(buf)
         }
     }
-    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.GetResponse" , decoder : | buf : & [u8] | { let msg : GetResponse = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
-    impl ::prost::Name for GetResponse {
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.ReadChangesRequest" , decoder : | buf : & [u8] | { let msg : ReadChangesRequest = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for ReadChangesRequest {
         const PACKAGE: &'static str = "document_collection";
-        const NAME: &'static str = "GetResponse";
+        const NAME: &'static str = "ReadChangesRequest";
         fn type_url() -> String {
-            "type.googleapis.com/document_collection.GetResponse".to_string()
+            "type.googleapis.com/document_collection.ReadChangesRequest".to_string()
         }
     }
 };
 
 #[allow(dead_code)]
-const IMPL_MESSAGE_SERDE_FOR_DOCUMENT_QUERY: () = {
+const IMPL_MESSAGE_SERDE_FOR_WEBHOOK: () = {
     use ::prost_wkt::typetag;
-    #[typetag::serde(name = "type.googleapis.com/document_collection.DocumentQuery")]
-    impl ::prost_wkt::MessageSerde for DocumentQuery {
+    #[typetag::serde(name = "type.googleapis.com/document_collection.Webhook")]
+    impl ::prost_wkt::MessageSerde for Webhook {
         fn package_name(&self) -> &'static str {
             "document_collection"
         }
         fn message_name(&self) -> &'static str {
-            "DocumentQuery"
+            "Webhook"
         }
         fn type_url(&self) -> &'static str {
-            "type.googleapis.com/document_collection.DocumentQuery"
+            "type.googleapis.com/document_collection.Webhook"
         }
         fn new_instance(
             &self,
@@ -789,29 +5787,29 @@ const IMPL_MESSAGE_SERDE_FOR_DOCUMENT_QUERY: () = {
             Ok(buf)
         }
     }
-    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.DocumentQuery" , decoder : | buf : & [u8] | { let msg : DocumentQuery = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
-    impl ::prost::Name for DocumentQuery {
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.Webhook" , decoder : | buf : & [u8] | { let msg : Webhook = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for Webhook {
         const PACKAGE: &'static str = "document_collection";
-        const NAME: &'static str = "DocumentQuery";
+        const NAME: &'static str = "Webhook";
         fn type_url() -> String {
-            "type.googleapis.com/document_collection.DocumentQuery".to_string()
+            "type.googleapis.com/document_collection.Webhook".to_string()
         }
     }
 };
 
 #[allow(dead_code)]
-const IMPL_MESSAGE_SERDE_FOR_QUERY_REQUEST: () = {
+const IMPL_MESSAGE_SERDE_FOR_CREATE_WEBHOOK_REQUEST: () = {
     use ::prost_wkt::typetag;
-    #[typetag::serde(name = "type.googleapis.com/document_collection.QueryRequest")]
-    impl ::prost_wkt::MessageSerde for QueryRequest {
+    #[typetag::serde(name = "type.googleapis.com/document_collection.CreateWebhookRequest")]
+    impl ::prost_wkt::MessageSerde for CreateWebhookRequest {
         fn package_name(&self) -> &'static str {
             "document_collection"
         }
         fn message_name(&self) -> &'static str {
-            "QueryRequest"
+            "CreateWebhookRequest"
         }
         fn type_url(&self) -> &'static str {
-            "type.googleapis.com/document_collection.QueryRequest"
+            "type.googleapis.com/document_collection.CreateWebhookRequest"
         }
         fn new_instance(
             &self,
@@ -830,29 +5828,29 @@ const IMPL_MESSAGE_SERDE_FOR_QUERY_REQUEST: () = {
             Ok(buf)
         }
     }
-    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.QueryRequest" , decoder : | buf : & [u8] | { let msg : QueryRequest = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
-    impl ::prost::Name for QueryRequest {
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.CreateWebhookRequest" , decoder : | buf : & [u8] | { let msg : CreateWebhookRequest = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for CreateWebhookRequest {
         const PACKAGE: &'static str = "document_collection";
-        const NAME: &'static str = "QueryRequest";
+        const NAME: &'static str = "CreateWebhookRequest";
         fn type_url() -> String {
-            "type.googleapis.com/document_collection.QueryRequest".to_string()
+            "type.googleapis.com/document_collection.CreateWebhookRequest".to_string()
         }
     }
 };
 
 #[allow(dead_code)]
-const IMPL_MESSAGE_SERDE_FOR_CREATE_REQUEST: () = {
+const IMPL_MESSAGE_SERDE_FOR_CREATE_WEBHOOK_RESPONSE: () = {
     use ::prost_wkt::typetag;
-    #[typetag::serde(name = "type.googleapis.com/document_collection.CreateRequest")]
-    impl ::prost_wkt::MessageSerde for CreateRequest {
+    #[typetag::serde(name = "type.googleapis.com/document_collection.CreateWebhookResponse")]
+    impl ::prost_wkt::MessageSerde for CreateWebhookResponse {
         fn package_name(&self) -> &'static str {
             "document_collection"
         }
         fn message_name(&self) -> &'static str {
-            "CreateRequest"
+            "CreateWebhookResponse"
         }
         fn type_url(&self) -> &'static str {
-            "type.googleapis.com/document_collection.CreateRequest"
+            "type.googleapis.com/document_collection.CreateWebhookResponse"
         }
         fn new_instance(
             &self,
@@ -871,29 +5869,29 @@ const IMPL_MESSAGE_SERDE_FOR_CREATE_REQUEST: () = {
             Ok(buf)
         }
     }
-    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.CreateRequest" , decoder : | buf : & [u8] | { let msg : CreateRequest = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
-    impl ::prost::Name for CreateRequest {
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.CreateWebhookResponse" , decoder : | buf : & [u8] | { let msg : CreateWebhookResponse = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for CreateWebhookResponse {
         const PACKAGE: &'static str = "document_collection";
-        const NAME: &'static str = "CreateRequest";
+        const NAME: &'static str = "CreateWebhookResponse";
         fn type_url() -> String {
-            "type.googleapis.com/document_collection.CreateRequest".to_string()
+            "type.googleapis.com/document_collection.CreateWebhookResponse".to_string()
         }
     }
 };
 
 #[allow(dead_code)]
-const IMPL_MESSAGE_SERDE_FOR_CREATE_RESPONSE: () = {
+const IMPL_MESSAGE_SERDE_FOR_LIST_WEBHOOKS_REQUEST: () = {
     use ::prost_wkt::typetag;
-    #[typetag::serde(name = "type.googleapis.com/document_collection.CreateResponse")]
-    impl ::prost_wkt::MessageSerde for CreateResponse {
+    #[typetag::serde(name = "type.googleapis.com/document_collection.ListWebhooksRequest")]
+    impl ::prost_wkt::MessageSerde for ListWebhooksRequest {
         fn package_name(&self) -> &'static str {
             "document_collection"
         }
         fn message_name(&self) -> &'static str {
-            "CreateResponse"
+            "ListWebhooksRequest"
         }
         fn type_url(&self) -> &'static str {
-            "type.googleapis.com/document_collection.CreateResponse"
+            "type.googleapis.com/document_collection.ListWebhooksRequest"
         }
         fn new_instance(
             &self,
@@ -912,29 +5910,29 @@ const IMPL_MESSAGE_SERDE_FOR_CREATE_RESPONSE: () = {
             Ok(buf)
         }
     }
-    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.CreateResponse" , decoder : | buf : & [u8] | { let msg : CreateResponse = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
-    impl ::prost::Name for CreateResponse {
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.ListWebhooksRequest" , decoder : | buf : & [u8] | { let msg : ListWebhooksRequest = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for ListWebhooksRequest {
         const PACKAGE: &'static str = "document_collection";
-        const NAME: &'static str = "CreateResponse";
+        const NAME: &'static str = "ListWebhooksRequest";
         fn type_url() -> String {
-            "type.googleapis.com/document_collection.CreateResponse".to_string()
+            "type.googleapis.com/document_collection.ListWebhooksRequest".to_string()
         }
     }
 };
 
 #[allow(dead_code)]
-const IMPL_MESSAGE_SERDE_FOR_UPDATE_REQUEST: () = {
+const IMPL_MESSAGE_SERDE_FOR_LIST_WEBHOOKS_RESPONSE: () = {
     use ::prost_wkt::typetag;
-    #[typetag::serde(name = "type.googleapis.com/document_collection.UpdateRequest")]
-    impl ::prost_wkt::MessageSerde for UpdateRequest {
+    #[typetag::serde(name = "type.googleapis.com/document_collection.ListWebhooksResponse")]
+    impl ::prost_wkt::MessageSerde for ListWebhooksResponse {
         fn package_name(&self) -> &'static str {
             "document_collection"
         }
         fn message_name(&self) -> &'static str {
-            "UpdateRequest"
+            "ListWebhooksResponse"
         }
         fn type_url(&self) -> &'static str {
-            "type.googleapis.com/document_collection.UpdateRequest"
+            "type.googleapis.com/document_collection.ListWebhooksResponse"
         }
         fn new_instance(
             &self,
@@ -953,29 +5951,29 @@ const IMPL_MESSAGE_SERDE_FOR_UPDATE_REQUEST: () = {
             Ok(buf)
         }
     }
-    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.UpdateRequest" , decoder : | buf : & [u8] | { let msg : UpdateRequest = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
-    impl ::prost::Name for UpdateRequest {
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.ListWebhooksResponse" , decoder : | buf : & [u8] | { let msg : ListWebhooksResponse = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for ListWebhooksResponse {
         const PACKAGE: &'static str = "document_collection";
-        const NAME: &'static str = "UpdateRequest";
+        const NAME: &'static str = "ListWebhooksResponse";
         fn type_url() -> String {
-            "type.googleapis.com/document_collection.UpdateRequest".to_string()
+            "type.googleapis.com/document_collection.ListWebhooksResponse".to_string()
         }
     }
 };
 
 #[allow(dead_code)]
-const IMPL_MESSAGE_SERDE_FOR_UPDATE_RESPONSE: () = {
+const IMPL_MESSAGE_SERDE_FOR_DELETE_WEBHOOK_REQUEST: () = {
     use ::prost_wkt::typetag;
-    #[typetag::serde(name = "type.googleapis.com/document_collection.UpdateResponse")]
-    impl ::prost_wkt::MessageSerde for UpdateResponse {
+    #[typetag::serde(name = "type.googleapis.com/document_collection.DeleteWebhookRequest")]
+    impl ::prost_wkt::MessageSerde for DeleteWebhookRequest {
         fn package_name(&self) -> &'static str {
             "document_collection"
         }
         fn message_name(&self) -> &'static str {
-            "UpdateResponse"
+            "DeleteWebhookRequest"
         }
         fn type_url(&self) -> &'static str {
-            "type.googleapis.com/document_collection.UpdateResponse"
+            "type.googleapis.com/document_collection.DeleteWebhookRequest"
         }
         fn new_instance(
             &self,
@@ -994,29 +5992,29 @@ const IMPL_MESSAGE_SERDE_FOR_UPDATE_RESPONSE: () = {
             Ok(buf)
         }
     }
-    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.UpdateResponse" , decoder : | buf : & [u8] | { let msg : UpdateResponse = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
-    impl ::prost::Name for UpdateResponse {
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.DeleteWebhookRequest" , decoder : | buf : & [u8] | { let msg : DeleteWebhookRequest = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for DeleteWebhookRequest {
         const PACKAGE: &'static str = "document_collection";
-        const NAME: &'static str = "UpdateResponse";
+        const NAME: &'static str = "DeleteWebhookRequest";
         fn type_url() -> String {
-            "type.googleapis.com/document_collection.UpdateResponse".to_string()
+            "type.googleapis.com/document_collection.DeleteWebhookRequest".to_string()
         }
     }
 };
 
 #[allow(dead_code)]
-const IMPL_MESSAGE_SERDE_FOR_DELETE_REQUEST: () = {
+const IMPL_MESSAGE_SERDE_FOR_DELETE_WEBHOOK_RESPONSE: () = {
     use ::prost_wkt::typetag;
-    #[typetag::serde(name = "type.googleapis.com/document_collection.DeleteRequest")]
-    impl ::prost_wkt::MessageSerde for DeleteRequest {
+    #[typetag::serde(name = "type.googleapis.com/document_collection.DeleteWebhookResponse")]
+    impl ::prost_wkt::MessageSerde for DeleteWebhookResponse {
         fn package_name(&self) -> &'static str {
             "document_collection"
         }
         fn message_name(&self) -> &'static str {
-            "DeleteRequest"
+            "DeleteWebhookResponse"
         }
         fn type_url(&self) -> &'static str {
-            "type.googleapis.com/document_collection.DeleteRequest"
+            "type.googleapis.com/document_collection.DeleteWebhookResponse"
         }
         fn new_instance(
             &self,
@@ -1035,29 +6033,29 @@ const IMPL_MESSAGE_SERDE_FOR_DELETE_REQUEST: () = {
             Ok(buf)
         }
     }
-    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.DeleteRequest" , decoder : | buf : & [u8] | { let msg : DeleteRequest = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
-    impl ::prost::Name for DeleteRequest {
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.DeleteWebhookResponse" , decoder : | buf : & [u8] | { let msg : DeleteWebhookResponse = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for DeleteWebhookResponse {
         const PACKAGE: &'static str = "document_collection";
-        const NAME: &'static str = "DeleteRequest";
+        const NAME: &'static str = "DeleteWebhookResponse";
         fn type_url() -> String {
-            "type.googleapis.com/document_collection.DeleteRequest".to_string()
+            "type.googleapis.com/document_collection.DeleteWebhookResponse".to_string()
         }
     }
 };
 
 #[allow(dead_code)]
-const IMPL_MESSAGE_SERDE_FOR_DELETE_RESPONSE: () = {
+const IMPL_MESSAGE_SERDE_FOR_WEBHOOK_DELIVERY: () = {
     use ::prost_wkt::typetag;
-    #[typetag::serde(name = "type.googleapis.com/document_collection.DeleteResponse")]
-    impl ::prost_wkt::MessageSerde for DeleteResponse {
+    #[typetag::serde(name = "type.googleapis.com/document_collection.WebhookDelivery")]
+    impl ::prost_wkt::MessageSerde for WebhookDelivery {
         fn package_name(&self) -> &'static str {
             "document_collection"
         }
         fn message_name(&self) -> &'static str {
-            "DeleteResponse"
+            "WebhookDelivery"
         }
         fn type_url(&self) -> &'static str {
-            "type.googleapis.com/document_collection.DeleteResponse"
+            "type.googleapis.com/document_collection.WebhookDelivery"
         }
         fn new_instance(
             &self,
@@ -1076,12 +6074,94 @@ const IMPL_MESSAGE_SERDE_FOR_DELETE_RESPONSE: () = {
             Ok(buf)
         }
     }
-    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.DeleteResponse" , decoder : | buf : & [u8] | { let msg : DeleteResponse = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
-    impl ::prost::Name for DeleteResponse {
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.WebhookDelivery" , decoder : | buf : & [u8] | { let msg : WebhookDelivery = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for WebhookDelivery {
         const PACKAGE: &'static str = "document_collection";
-        const NAME: &'static str = "DeleteResponse";
+        const NAME: &'static str = "WebhookDelivery";
         fn type_url() -> String {
-            "type.googleapis.com/document_collection.DeleteResponse".to_string()
+            "type.googleapis.com/document_collection.WebhookDelivery".to_string()
+        }
+    }
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_LIST_DELIVERIES_REQUEST: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.ListDeliveriesRequest")]
+    impl ::prost_wkt::MessageSerde for ListDeliveriesRequest {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "ListDeliveriesRequest"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.ListDeliveriesRequest"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
+        }
+    }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.ListDeliveriesRequest" , decoder : | buf : & [u8] | { let msg : ListDeliveriesRequest = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for ListDeliveriesRequest {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "ListDeliveriesRequest";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.ListDeliveriesRequest".to_string()
+        }
+    }
+};
+
+#[allow(dead_code)]
+const IMPL_MESSAGE_SERDE_FOR_LIST_DELIVERIES_RESPONSE: () = {
+    use ::prost_wkt::typetag;
+    #[typetag::serde(name = "type.googleapis.com/document_collection.ListDeliveriesResponse")]
+    impl ::prost_wkt::MessageSerde for ListDeliveriesResponse {
+        fn package_name(&self) -> &'static str {
+            "document_collection"
+        }
+        fn message_name(&self) -> &'static str {
+            "ListDeliveriesResponse"
+        }
+        fn type_url(&self) -> &'static str {
+            "type.googleapis.com/document_collection.ListDeliveriesResponse"
+        }
+        fn new_instance(
+            &self,
+            data: Vec<u8>,
+        ) -> ::std::result::Result<Box<dyn ::prost_wkt::MessageSerde>, ::prost::DecodeError>
+        {
+            let mut target = Self::default();
+            ::prost::Message::merge(&mut target, data.as_slice())?;
+            let erased: ::std::boxed::Box<dyn ::prost_wkt::MessageSerde> =
+                ::std::boxed::Box::new(target);
+            Ok(erased)
+        }
+        fn try_encoded(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::prost::EncodeError> {
+            let mut buf = ::std::vec::Vec::with_capacity(::prost::Message::encoded_len(self));
+            ::prost::Message::encode(self, &mut buf)?;
+            Ok(buf)
+        }
+    }
+    ::prost_wkt::inventory::submit! { :: prost_wkt :: MessageSerdeDecoderEntry { type_url : "type.googleapis.com/document_collection.ListDeliveriesResponse" , decoder : | buf : & [u8] | { let msg : ListDeliveriesResponse = :: prost :: Message :: decode (buf) ? ; Ok (:: std :: boxed :: Box :: new (msg)) } } }
+    impl ::prost::Name for ListDeliveriesResponse {
+        const PACKAGE: &'static str = "document_collection";
+        const NAME: &'static str = "ListDeliveriesResponse";
+        fn type_url() -> String {
+            "type.googleapis.com/document_collection.ListDeliveriesResponse".to_string()
         }
     }
 };
@@ -1,4 +1,7 @@
-#[allow(clippy::all, non_camel_case_types)]
+#[allow(clippy::all, non_camel_case_types, non_local_definitions)]
 mod document_collection;
+#[allow(clippy::all, non_camel_case_types, non_local_definitions)]
+mod user;
 
 pub use document_collection::*;
+pub use user::*;
@@ -0,0 +1,56 @@
+use chrono::{DateTime, Utc};
+use prost_wkt_types::Timestamp;
+use sqlx::{postgres::PgRow, types::Uuid, FromRow, Row};
+
+use crate::{ApiKey, Permission};
+
+impl Permission {
+    /// the value stored in the permissions column
+    pub fn to_db(self) -> &'static str {
+        match self {
+            Permission::Unspecified => "unspecified",
+            Permission::Read => "read",
+            Permission::Write => "write",
+            Permission::ManageUsers => "manage_users",
+        }
+    }
+
+    pub fn from_db(permission: &str) -> Self {
+        match permission {
+            "read" => Permission::Read,
+            "write" => Permission::Write,
+            "manage_users" => Permission::ManageUsers,
+            _ => Permission::Unspecified,
+        }
+    }
+
+    /// every permission, what a caller authenticated with a bearer token has
+    pub fn all() -> Vec<Self> {
+        vec![Permission::Read, Permission::Write, Permission::ManageUsers]
+    }
+}
+
+impl FromRow<'_, PgRow> for ApiKey {
+    fn from_row(row: &'_ PgRow) -> Result<Self, sqlx::Error> {
+        let id: Uuid = row.get("id");
+        let user_id: Uuid = row.get("user_id");
+        let permissions: Vec<String> = row.get("permissions");
+        let created_at: DateTime<Utc> = row.get("created_at");
+        let last_used_at: Option<DateTime<Utc>> = row.get("last_used_at");
+        let revoked_at: Option<DateTime<Utc>> = row.get("revoked_at");
+
+        Ok(Self {
+            id: id.to_string(),
+            user_id: user_id.to_string(),
+            name: row.get("name"),
+            permissions: permissions
+                .iter()
+                .map(|p| Permission::from_db(p) as i32)
+                .collect(),
+            prefix: row.get("prefix"),
+            created_at: Some(Timestamp::from(created_at)),
+            last_used_at: last_used_at.map(Timestamp::from),
+            revoked_at: revoked_at.map(Timestamp::from),
+        })
+    }
+}
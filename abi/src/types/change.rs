@@ -0,0 +1,24 @@
+use sqlx::{postgres::PgRow, FromRow, Row};
+
+use crate::{ChangeType, Document, DocumentChange};
+
+/// a revision selected together with the document it recorded, see `Document::from_row`
+impl FromRow<'_, PgRow> for DocumentChange {
+    fn from_row(row: &'_ PgRow) -> Result<Self, sqlx::Error> {
+        let deleted: bool = row.get("deleted");
+        let document = Document::from_row(row)?;
+        let change_type = if deleted {
+            ChangeType::Deleted
+        } else if document.version == 1 {
+            ChangeType::Created
+        } else {
+            ChangeType::Updated
+        };
+
+        Ok(Self {
+            sequence: row.get("seq"),
+            change_type: change_type as i32,
+            document: Some(document),
+        })
+    }
+}
@@ -0,0 +1,19 @@
+use chrono::{DateTime, Utc};
+use prost_wkt_types::Timestamp;
+use sqlx::{postgres::PgRow, FromRow, Row};
+
+use crate::Collection;
+
+impl FromRow<'_, PgRow> for Collection {
+    fn from_row(row: &'_ PgRow) -> Result<Self, sqlx::Error> {
+        let created_at: DateTime<Utc> = row.get("created_at");
+
+        Ok(Self {
+            name: row.get("name"),
+            document_type: row.get("document_type"),
+            retention_days: row.get("retention_days"),
+            indexes: row.get("indexes"),
+            created_at: Some(Timestamp::from(created_at)),
+        })
+    }
+}
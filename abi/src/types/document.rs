@@ -1,10 +1,9 @@
 use chrono::{DateTime, Utc};
-use prost_wkt::MessageSerde;
 use prost_wkt_types::{Struct, Timestamp};
 use serde_json::Value;
 use sqlx::{postgres::PgRow, types::Uuid, FromRow, Row};
 
-use crate::{Document, Error, Validator};
+use crate::{Document, DocumentItem, Error, Validator};
 
 use super::validate_range;
 
@@ -14,7 +13,11 @@ impl Validator for Document {
             return Err(Error::InvalidUserId(self.user_id.clone()));
         }
 
-        validate_range(self.created_at.as_ref(), self.created_at.as_ref())?;
+        validate_range(self.created_at.as_ref(), self.updated_at.as_ref())?;
+
+        for item in &self.items {
+            item.validate()?;
+        }
         Ok(())
     }
 }
@@ -24,10 +27,19 @@ impl FromRow<'_, PgRow> for Document {
         let id: Uuid = row.get("id");
         let user_id: Uuid = row.get("user_id");
         let data: Value = row.get("data");
+        let items: Value = row.get("items");
         let created_at: DateTime<Utc> = row.get("created_at");
         let updated_at: DateTime<Utc> = row.get("updated_at");
+        let version: i64 = row.get("version");
+        let deleted_at: Option<DateTime<Utc>> = row.get("deleted_at");
+        let org_id: Option<Uuid> = row.get("org_id");
+        let store_id: Option<Uuid> = row.get("store_id");
+        let schema_version: Option<i32> = row.get("schema_version");
 
-        let data: Struct = serde_json::from_value(data).unwrap();
+        let data: Struct =
+            serde_json::from_value(data).map_err(|e| sqlx::Error::Decode(Box::new(e)))?;
+        let items: Vec<DocumentItem> =
+            serde_json::from_value(items).map_err(|e| sqlx::Error::Decode(Box::new(e)))?;
 
         Ok(Self {
             id: id.to_string(),
@@ -35,6 +47,15 @@ impl FromRow<'_, PgRow> for Document {
             data: Some(data),
             created_at: Some(Timestamp::from(created_at)),
             updated_at: Some(Timestamp::from(updated_at)),
+            items,
+            total: String::new(),
+            version,
+            deleted_at: deleted_at.map(Timestamp::from),
+            org_id: org_id.map(|id| id.to_string()).unwrap_or_default(),
+            store_id: store_id.map(|id| id.to_string()).unwrap_or_default(),
+            document_type: row.get("document_type"),
+            schema_version: schema_version.unwrap_or_default(),
+            collection: row.get("collection"),
         })
     }
 }
@@ -0,0 +1,67 @@
+use std::str::FromStr;
+
+use rust_decimal::Decimal;
+
+use crate::{DocumentItem, Error, Item0, Item1, Validator};
+
+impl Validator for DocumentItem {
+    fn validate(&self) -> Result<(), Error> {
+        if self.name.is_empty() {
+            return Err(Error::InvalidItem("item name is empty".to_string()));
+        }
+
+        for item in &self.items1 {
+            item.validate()?;
+        }
+        for item in &self.items0 {
+            item.validate()?;
+        }
+        Ok(())
+    }
+}
+
+impl Validator for Item1 {
+    fn validate(&self) -> Result<(), Error> {
+        if self.name.is_empty() {
+            return Err(Error::InvalidItem("item name is empty".to_string()));
+        }
+
+        for item in &self.items {
+            item.validate()?;
+        }
+        Ok(())
+    }
+}
+
+impl Validator for Item0 {
+    fn validate(&self) -> Result<(), Error> {
+        if self.name.is_empty() {
+            return Err(Error::InvalidItem("item name is empty".to_string()));
+        }
+
+        if self.quantity < 0 {
+            return Err(Error::InvalidItem(format!(
+                "negative quantity for {}",
+                self.name
+            )));
+        }
+
+        self.parse_price()?;
+        Ok(())
+    }
+}
+
+impl Item0 {
+    /// parse the decimal price, raise an error if it's malformed or negative
+    pub fn parse_price(&self) -> Result<Decimal, Error> {
+        let price = Decimal::from_str(&self.price)
+            .map_err(|_| Error::InvalidItem(format!("invalid price for {}", self.name)))?;
+        if price.is_sign_negative() {
+            return Err(Error::InvalidItem(format!(
+                "negative price for {}",
+                self.name
+            )));
+        }
+        Ok(price)
+    }
+}
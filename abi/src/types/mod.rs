@@ -1,19 +1,40 @@
+use chrono::{DateTime, Utc};
 use prost_wkt_types::Timestamp;
 
 use crate::Error;
 
+mod api_key;
+mod change;
+mod collection;
 mod document;
+mod item;
+mod organization;
+mod request;
+mod revision;
+mod schema;
+mod user;
+mod webhook;
 
+/// check both bounds lie in the supported range and start is not after end, a missing bound is
+/// unbounded
 pub fn validate_range(start: Option<&Timestamp>, end: Option<&Timestamp>) -> Result<(), Error> {
-    if start.is_none() || end.is_none() {
+    if start
+        .into_iter()
+        .chain(end)
+        .any(|t| timestamp_to_utc(t).is_none())
+    {
         return Err(Error::InvalidTime);
     }
-
-    let start = start.unwrap();
-    let end = end.unwrap();
-
-    if start.seconds > end.seconds {
-        return Err(Error::InvalidTime);
+    match (start, end) {
+        (Some(start), Some(end)) if (start.seconds, start.nanos) > (end.seconds, end.nanos) => {
+            Err(Error::InvalidTime)
+        }
+        _ => Ok(()),
     }
-    Ok(())
+}
+
+/// the instant of a timestamp, None when it lies outside the range chrono supports
+pub fn timestamp_to_utc(timestamp: &Timestamp) -> Option<DateTime<Utc>> {
+    let nanos = u32::try_from(timestamp.nanos).ok()?;
+    DateTime::from_timestamp(timestamp.seconds, nanos)
 }
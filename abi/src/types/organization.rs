@@ -0,0 +1,33 @@
+use chrono::{DateTime, Utc};
+use prost_wkt_types::Timestamp;
+use sqlx::{postgres::PgRow, types::Uuid, FromRow, Row};
+
+use crate::{Organization, Store};
+
+impl FromRow<'_, PgRow> for Organization {
+    fn from_row(row: &'_ PgRow) -> Result<Self, sqlx::Error> {
+        let id: Uuid = row.get("id");
+        let created_at: DateTime<Utc> = row.get("created_at");
+
+        Ok(Self {
+            id: id.to_string(),
+            name: row.get("name"),
+            created_at: Some(Timestamp::from(created_at)),
+        })
+    }
+}
+
+impl FromRow<'_, PgRow> for Store {
+    fn from_row(row: &'_ PgRow) -> Result<Self, sqlx::Error> {
+        let id: Uuid = row.get("id");
+        let org_id: Uuid = row.get("org_id");
+        let created_at: DateTime<Utc> = row.get("created_at");
+
+        Ok(Self {
+            id: id.to_string(),
+            org_id: org_id.to_string(),
+            name: row.get("name"),
+            created_at: Some(Timestamp::from(created_at)),
+        })
+    }
+}
@@ -0,0 +1,767 @@
+use prost_wkt_types::Struct;
+use serde_json::Value;
+use sqlx::types::Uuid;
+
+use crate::{
+    patch_request::Patch, AggregateRequest, BatchCreateRequest, BatchDeleteRequest,
+    BatchUpdateRequest, ChangeType, CreateCollectionRequest, CreateRequest, CreateUserRequest,
+    CreateWebhookRequest, DeleteRequest, DeleteWebhookRequest, DocumentItem, DocumentQuery,
+    DropCollectionRequest, Error, FieldViolation, GetRequest, GetSchemaRequest, GetUserRequest,
+    ListDeliveriesRequest, ListRevisionsRequest, ListSchemasRequest, ListWebhooksRequest,
+    PatchRequest, QueryRequest, ReadChangesRequest, RegisterSchemaRequest, RestoreRequest,
+    UndeleteRequest, UpdateRequest, UpdateUserRequest, Validator, WatchRequest,
+};
+
+use super::{timestamp_to_utc, validate_range};
+
+/// largest accepted size of the json encoded data and items of a document
+pub const MAX_DOCUMENT_SIZE: usize = 1 << 20;
+/// deepest accepted nesting of objects and arrays in document data
+pub const MAX_NESTING_DEPTH: usize = 32;
+/// longest accepted document type name
+pub const MAX_DOCUMENT_TYPE_LEN: usize = 64;
+/// longest accepted collection name
+pub const MAX_COLLECTION_NAME_LEN: usize = 48;
+/// most paths a collection may index
+pub const MAX_COLLECTION_INDEXES: usize = 8;
+/// most requests one batch may hold
+pub const MAX_BATCH_SIZE: usize = 1000;
+/// longest accepted idempotency key
+pub const MAX_IDEMPOTENCY_KEY_LEN: usize = 255;
+/// longest accepted webhook secret
+pub const MAX_WEBHOOK_SECRET_LEN: usize = 256;
+
+/// collects every problem of a request, so they are reported at once
+#[derive(Debug, Default)]
+struct Violations(Vec<FieldViolation>);
+
+impl Violations {
+    fn add(&mut self, field: &str, description: impl Into<String>) {
+        self.0.push(FieldViolation {
+            field: field.to_string(),
+            description: description.into(),
+        });
+    }
+
+    fn uuid(&mut self, field: &str, id: &str) {
+        if Uuid::parse_str(id).is_err() {
+            self.add(field, format!("not a valid uuid: {:?}", id));
+        }
+    }
+
+    fn optional_uuid(&mut self, field: &str, id: &str) {
+        if !id.is_empty() {
+            self.uuid(field, id);
+        }
+    }
+
+    /// document types are short names of letters, digits, `_`, `-` and `.`
+    fn document_type(&mut self, field: &str, document_type: &str) {
+        if document_type.len() > MAX_DOCUMENT_TYPE_LEN {
+            self.add(
+                field,
+                format!("longer than {} characters", MAX_DOCUMENT_TYPE_LEN),
+            );
+        }
+        if !document_type
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+        {
+            self.add(field, "may only contain letters, digits, '_', '-' and '.'");
+        }
+    }
+
+    fn required_document_type(&mut self, field: &str, document_type: &str) {
+        if document_type.is_empty() {
+            self.add(field, "must be set");
+        } else {
+            self.document_type(field, document_type);
+        }
+    }
+
+    /// collection names are short names of lower case letters, digits and `_`, empty means the default
+    fn collection(&mut self, field: &str, name: &str) {
+        if name.len() > MAX_COLLECTION_NAME_LEN {
+            self.add(
+                field,
+                format!("longer than {} characters", MAX_COLLECTION_NAME_LEN),
+            );
+        }
+        if !name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+        {
+            self.add(field, "may only contain lower case letters, digits and '_'");
+        }
+    }
+
+    fn required_collection(&mut self, field: &str, name: &str) {
+        if name.is_empty() {
+            self.add(field, "must be set");
+        } else {
+            self.collection(field, name);
+        }
+    }
+
+    /// indexed paths are restricted to plain names, as they end up in the index definition
+    fn index_path(&mut self, field: &str, path: &str) {
+        let mut segments = path.split('.');
+        if !matches!(segments.next(), Some("data" | "items")) {
+            self.add(field, "must start with data or items");
+        }
+        let mut segments = segments.peekable();
+        if segments.peek().is_none() {
+            self.add(field, "must name a field");
+        }
+        if !segments
+            .all(|s| !s.is_empty() && s.chars().all(|c| c.is_ascii_alphanumeric() || c == '_'))
+        {
+            self.add(field, "segments may only contain letters, digits and '_'");
+        }
+    }
+
+    /// keys are opaque to the server, but printable and short as they are stored
+    fn idempotency_key(&mut self, key: &str) {
+        if key.len() > MAX_IDEMPOTENCY_KEY_LEN {
+            self.add(
+                "idempotency_key",
+                format!("longer than {} characters", MAX_IDEMPOTENCY_KEY_LEN),
+            );
+        }
+        if !key.chars().all(|c| c.is_ascii_graphic()) {
+            self.add(
+                "idempotency_key",
+                "may only contain printable ascii characters",
+            );
+        }
+    }
+
+    /// larger uploads are streamed as several batches
+    fn batch_size(&mut self, len: usize) {
+        if len > MAX_BATCH_SIZE {
+            self.add(
+                "requests",
+                format!("at most {} requests fit in a batch", MAX_BATCH_SIZE),
+            );
+        }
+    }
+
+    /// a retried batch can't be told apart from the first attempt by the keys of its requests
+    fn batch_idempotency_keys<'a>(&mut self, keys: impl Iterator<Item = &'a str>) {
+        for (i, key) in keys.enumerate() {
+            if !key.is_empty() {
+                self.add(
+                    &format!("requests[{}].idempotency_key", i),
+                    "not supported in batches",
+                );
+            }
+        }
+    }
+
+    fn expected_version(&mut self, expected_version: i64) {
+        if expected_version < 0 {
+            self.add("expected_version", "must not be negative");
+        }
+    }
+
+    /// limit the size and nesting of document content and validate its items
+    fn content(&mut self, field: &str, data: Option<&Struct>, items: &[DocumentItem]) {
+        let (Ok(data), Ok(items_json)) = (
+            data.map(serde_json::to_vec).transpose(),
+            serde_json::to_vec(items),
+        ) else {
+            self.add(field, "can't be encoded as json");
+            return;
+        };
+
+        let size = data.as_ref().map(Vec::len).unwrap_or(0) + items_json.len();
+        if size > MAX_DOCUMENT_SIZE {
+            self.add(
+                field,
+                format!("document is larger than {} bytes", MAX_DOCUMENT_SIZE),
+            );
+        }
+        let depth = data
+            .and_then(|d| serde_json::from_slice::<Value>(&d).ok())
+            .map(|d| depth(&d))
+            .unwrap_or(0);
+        if depth > MAX_NESTING_DEPTH {
+            self.add(
+                field,
+                format!("nested deeper than {} levels", MAX_NESTING_DEPTH),
+            );
+        }
+
+        for (i, item) in items.iter().enumerate() {
+            if let Err(e) = item.validate() {
+                self.add(&format!("items[{}]", i), e.to_string());
+            }
+        }
+    }
+
+    fn query(&mut self, query: &DocumentQuery) {
+        self.query_at("query", query);
+    }
+
+    /// the query of a request, at the given field
+    fn query_at(&mut self, field: &str, query: &DocumentQuery) {
+        let field = |name: &str| format!("{}.{}", field, name);
+        self.optional_uuid(&field("user_id"), &query.user_id);
+        self.optional_uuid(&field("org_id"), &query.org_id);
+        self.optional_uuid(&field("store_id"), &query.store_id);
+        self.document_type(&field("document_type"), &query.document_type);
+        self.collection(&field("collection"), &query.collection);
+        let mut in_range = true;
+        for (name, bound) in [("start", &query.start), ("end", &query.end)] {
+            if bound
+                .as_ref()
+                .is_some_and(|t| timestamp_to_utc(t).is_none())
+            {
+                self.add(&field(name), "out of range");
+                in_range = false;
+            }
+        }
+        if in_range && validate_range(query.start.as_ref(), query.end.as_ref()).is_err() {
+            self.add(
+                &field("start"),
+                format!("must not be after {}", field("end")),
+            );
+        }
+    }
+
+    fn into_result(self) -> Result<(), Error> {
+        if self.0.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::InvalidRequest(self.0))
+        }
+    }
+}
+
+/// how deeply objects and arrays are nested in a json value
+fn depth(value: &Value) -> usize {
+    match value {
+        Value::Object(map) => 1 + map.values().map(depth).max().unwrap_or(0),
+        Value::Array(values) => 1 + values.iter().map(depth).max().unwrap_or(0),
+        _ => 0,
+    }
+}
+
+impl Validator for CreateRequest {
+    fn validate(&self) -> Result<(), Error> {
+        let mut v = Violations::default();
+        v.optional_uuid("user_id", &self.user_id);
+        if self.data.is_none() && self.items.is_empty() {
+            v.add("data", "data or items must be set");
+        }
+        v.content("data", self.data.as_ref(), &self.items);
+        v.document_type("document_type", &self.document_type);
+        v.collection("collection", &self.collection);
+        v.idempotency_key(&self.idempotency_key);
+        v.into_result()
+    }
+}
+
+impl Validator for UpdateRequest {
+    fn validate(&self) -> Result<(), Error> {
+        let mut v = Violations::default();
+        v.uuid("id", &self.id);
+        if self.data.is_none() && self.items.is_empty() {
+            v.add("data", "data or items must be set");
+        }
+        v.content("data", self.data.as_ref(), &self.items);
+        v.expected_version(self.expected_version);
+        v.collection("collection", &self.collection);
+        v.idempotency_key(&self.idempotency_key);
+        v.into_result()
+    }
+}
+
+impl Validator for PatchRequest {
+    fn validate(&self) -> Result<(), Error> {
+        let mut v = Violations::default();
+        v.uuid("id", &self.id);
+        match &self.patch {
+            None => v.add("patch", "merge_patch or json_patch must be set"),
+            Some(Patch::MergePatch(patch)) => v.content("patch.merge_patch", Some(patch), &[]),
+            Some(Patch::JsonPatch(patch)) if patch.operations.is_empty() => {
+                v.add("patch.json_patch", "has no operations")
+            }
+            Some(Patch::JsonPatch(_)) => {}
+        }
+        v.expected_version(self.expected_version);
+        v.collection("collection", &self.collection);
+        v.into_result()
+    }
+}
+
+impl Validator for DeleteRequest {
+    fn validate(&self) -> Result<(), Error> {
+        let mut v = Violations::default();
+        v.uuid("id", &self.id);
+        v.expected_version(self.expected_version);
+        v.collection("collection", &self.collection);
+        v.into_result()
+    }
+}
+
+impl Validator for UndeleteRequest {
+    fn validate(&self) -> Result<(), Error> {
+        let mut v = Violations::default();
+        v.uuid("id", &self.id);
+        v.collection("collection", &self.collection);
+        v.into_result()
+    }
+}
+
+impl Validator for GetRequest {
+    fn validate(&self) -> Result<(), Error> {
+        let mut v = Violations::default();
+        v.uuid("id", &self.id);
+        if self.revision < 0 {
+            v.add("revision", "must not be negative");
+        }
+        v.collection("collection", &self.collection);
+        v.into_result()
+    }
+}
+
+impl Validator for ListRevisionsRequest {
+    fn validate(&self) -> Result<(), Error> {
+        let mut v = Violations::default();
+        v.uuid("id", &self.id);
+        v.collection("collection", &self.collection);
+        v.into_result()
+    }
+}
+
+impl Validator for RestoreRequest {
+    fn validate(&self) -> Result<(), Error> {
+        let mut v = Violations::default();
+        v.uuid("id", &self.id);
+        if self.revision <= 0 {
+            v.add("revision", "must be positive");
+        }
+        v.expected_version(self.expected_version);
+        v.collection("collection", &self.collection);
+        v.into_result()
+    }
+}
+
+impl Validator for QueryRequest {
+    fn validate(&self) -> Result<(), Error> {
+        let mut v = Violations::default();
+        match &self.query {
+            Some(query) => v.query(query),
+            None => v.add("query", "must be set"),
+        }
+        if self.page_size < 0 {
+            v.add("page_size", "must not be negative");
+        }
+        v.into_result()
+    }
+}
+
+impl Validator for AggregateRequest {
+    fn validate(&self) -> Result<(), Error> {
+        let mut v = Violations::default();
+        if let Some(query) = &self.query {
+            v.query(query);
+        }
+        if self.fields.iter().any(|f| f.is_empty()) {
+            v.add("fields", "must not contain empty paths");
+        }
+        v.into_result()
+    }
+}
+
+impl Validator for BatchCreateRequest {
+    fn validate(&self) -> Result<(), Error> {
+        let mut v = Violations::default();
+        v.batch_size(self.requests.len());
+        v.batch_idempotency_keys(self.requests.iter().map(|r| r.idempotency_key.as_str()));
+        v.into_result()
+    }
+}
+
+impl Validator for BatchUpdateRequest {
+    fn validate(&self) -> Result<(), Error> {
+        let mut v = Violations::default();
+        v.batch_size(self.requests.len());
+        v.batch_idempotency_keys(self.requests.iter().map(|r| r.idempotency_key.as_str()));
+        v.into_result()
+    }
+}
+
+impl Validator for BatchDeleteRequest {
+    fn validate(&self) -> Result<(), Error> {
+        let mut v = Violations::default();
+        v.batch_size(self.requests.len());
+        v.into_result()
+    }
+}
+
+impl Validator for RegisterSchemaRequest {
+    fn validate(&self) -> Result<(), Error> {
+        let mut v = Violations::default();
+        v.optional_uuid("org_id", &self.org_id);
+        v.required_document_type("document_type", &self.document_type);
+        match &self.schema {
+            Some(schema) => v.content("schema", Some(schema), &[]),
+            None => v.add("schema", "must be set"),
+        }
+        v.into_result()
+    }
+}
+
+impl Validator for GetSchemaRequest {
+    fn validate(&self) -> Result<(), Error> {
+        let mut v = Violations::default();
+        v.optional_uuid("org_id", &self.org_id);
+        v.required_document_type("document_type", &self.document_type);
+        if self.version < 0 {
+            v.add("version", "must not be negative");
+        }
+        v.into_result()
+    }
+}
+
+impl Validator for ListSchemasRequest {
+    fn validate(&self) -> Result<(), Error> {
+        let mut v = Violations::default();
+        v.optional_uuid("org_id", &self.org_id);
+        v.document_type("document_type", &self.document_type);
+        v.into_result()
+    }
+}
+
+impl Validator for CreateCollectionRequest {
+    fn validate(&self) -> Result<(), Error> {
+        let mut v = Violations::default();
+        let Some(collection) = &self.collection else {
+            v.add("collection", "must be set");
+            return v.into_result();
+        };
+        v.required_collection("collection.name", &collection.name);
+        v.document_type("collection.document_type", &collection.document_type);
+        if collection.retention_days < 0 {
+            v.add("collection.retention_days", "must not be negative");
+        }
+        if collection.indexes.len() > MAX_COLLECTION_INDEXES {
+            v.add(
+                "collection.indexes",
+                format!("at most {} paths may be indexed", MAX_COLLECTION_INDEXES),
+            );
+        }
+        for (i, path) in collection.indexes.iter().enumerate() {
+            v.index_path(&format!("collection.indexes[{}]", i), path);
+        }
+        v.into_result()
+    }
+}
+
+impl Validator for DropCollectionRequest {
+    fn validate(&self) -> Result<(), Error> {
+        let mut v = Violations::default();
+        v.required_collection("name", &self.name);
+        v.into_result()
+    }
+}
+
+impl Validator for WatchRequest {
+    fn validate(&self) -> Result<(), Error> {
+        let mut v = Violations::default();
+        if let Some(query) = &self.query {
+            v.query(query);
+        }
+        if self.after_sequence < 0 {
+            v.add("after_sequence", "must not be negative");
+        }
+        v.into_result()
+    }
+}
+
+impl Validator for ReadChangesRequest {
+    fn validate(&self) -> Result<(), Error> {
+        let mut v = Violations::default();
+        if self.since_seq < 0 {
+            v.add("since_seq", "must not be negative");
+        }
+        v.optional_uuid("org_id", &self.org_id);
+        v.collection("collection", &self.collection);
+        if self.limit < 0 {
+            v.add("limit", "must not be negative");
+        }
+        v.into_result()
+    }
+}
+
+impl Validator for CreateWebhookRequest {
+    fn validate(&self) -> Result<(), Error> {
+        let mut v = Violations::default();
+        let Some(webhook) = &self.webhook else {
+            v.add("webhook", "must be set");
+            return v.into_result();
+        };
+        v.optional_uuid("webhook.org_id", &webhook.org_id);
+        match url::Url::parse(&webhook.url) {
+            Ok(url) if matches!(url.scheme(), "http" | "https") && url.host().is_some() => {}
+            _ => v.add("webhook.url", "must be an http or https url"),
+        }
+        for (i, event_type) in webhook.event_types.iter().enumerate() {
+            if !matches!(
+                ChangeType::try_from(*event_type),
+                Ok(ChangeType::Created | ChangeType::Updated | ChangeType::Deleted)
+            ) {
+                v.add(
+                    &format!("webhook.event_types[{}]", i),
+                    format!("unknown change type: {}", event_type),
+                );
+            }
+        }
+        if let Some(query) = &webhook.query {
+            v.query_at("webhook.query", query);
+        }
+        if webhook.secret.len() > MAX_WEBHOOK_SECRET_LEN {
+            v.add(
+                "webhook.secret",
+                format!("longer than {} characters", MAX_WEBHOOK_SECRET_LEN),
+            );
+        }
+        v.into_result()
+    }
+}
+
+impl Validator for ListWebhooksRequest {
+    fn validate(&self) -> Result<(), Error> {
+        let mut v = Violations::default();
+        v.optional_uuid("org_id", &self.org_id);
+        v.into_result()
+    }
+}
+
+impl Validator for DeleteWebhookRequest {
+    fn validate(&self) -> Result<(), Error> {
+        let mut v = Violations::default();
+        v.uuid("id", &self.id);
+        v.into_result()
+    }
+}
+
+impl Validator for ListDeliveriesRequest {
+    fn validate(&self) -> Result<(), Error> {
+        let mut v = Violations::default();
+        v.uuid("webhook_id", &self.webhook_id);
+        if self.limit < 0 {
+            v.add("limit", "must not be negative");
+        }
+        v.into_result()
+    }
+}
+
+impl Validator for CreateUserRequest {
+    fn validate(&self) -> Result<(), Error> {
+        let mut v = Violations::default();
+        if self.user.is_none() {
+            v.add("user", "must be set");
+        }
+        v.into_result()
+    }
+}
+
+impl Validator for GetUserRequest {
+    fn validate(&self) -> Result<(), Error> {
+        let mut v = Violations::default();
+        v.uuid("id", &self.id);
+        v.into_result()
+    }
+}
+
+impl Validator for UpdateUserRequest {
+    fn validate(&self) -> Result<(), Error> {
+        let mut v = Violations::default();
+        match &self.user {
+            Some(user) => v.uuid("user.id", &user.id),
+            None => v.add("user", "must be set"),
+        }
+        v.into_result()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prost_wkt_types::Timestamp;
+    use serde_json::json;
+
+    use super::*;
+
+    fn violations(result: Result<(), Error>) -> Vec<String> {
+        match result {
+            Err(Error::InvalidRequest(violations)) => {
+                violations.into_iter().map(|v| v.field).collect()
+            }
+            other => panic!("expected violations, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn update_request_should_report_every_violation() {
+        let request = UpdateRequest {
+            id: "not-a-uuid".to_string(),
+            expected_version: -1,
+            items: vec![DocumentItem::default()],
+            ..Default::default()
+        };
+        assert_eq!(
+            violations(request.validate()),
+            ["id", "items[0]", "expected_version"]
+        );
+    }
+
+    #[test]
+    fn create_request_without_content_should_fail() {
+        let request = CreateRequest::default();
+        assert_eq!(violations(request.validate()), ["data"]);
+    }
+
+    #[test]
+    fn create_request_should_check_idempotency_key() {
+        let request = CreateRequest {
+            data: Some(Struct::default()),
+            idempotency_key: "retry me".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(violations(request.validate()), ["idempotency_key"]);
+    }
+
+    #[test]
+    fn register_schema_request_should_check_document_type() {
+        let request = RegisterSchemaRequest {
+            document_type: "receipt v2".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(violations(request.validate()), ["document_type", "schema"]);
+    }
+
+    #[test]
+    fn create_collection_request_should_check_name_and_indexes() {
+        let request = CreateCollectionRequest {
+            collection: Some(crate::Collection {
+                name: "Stock Counts".to_string(),
+                retention_days: -1,
+                indexes: vec![
+                    "data.store".to_string(),
+                    "total".to_string(),
+                    "data.a-b".to_string(),
+                ],
+                ..Default::default()
+            }),
+        };
+        assert_eq!(
+            violations(request.validate()),
+            [
+                "collection.name",
+                "collection.retention_days",
+                "collection.indexes[1]",
+                "collection.indexes[1]",
+                "collection.indexes[2]"
+            ]
+        );
+    }
+
+    #[test]
+    fn deeply_nested_data_should_fail() {
+        let mut value = json!(1);
+        for _ in 0..=MAX_NESTING_DEPTH {
+            value = json!({ "a": value });
+        }
+        let request = CreateRequest {
+            data: Some(serde_json::from_value(value).unwrap()),
+            ..Default::default()
+        };
+        assert_eq!(violations(request.validate()), ["data"]);
+    }
+
+    #[test]
+    fn oversized_data_should_fail() {
+        let data = json!({ "blob": "x".repeat(MAX_DOCUMENT_SIZE) });
+        let request = CreateRequest {
+            data: Some(serde_json::from_value(data).unwrap()),
+            ..Default::default()
+        };
+        assert_eq!(violations(request.validate()), ["data"]);
+    }
+
+    #[test]
+    fn query_with_start_after_end_should_fail() {
+        let request = QueryRequest {
+            query: Some(DocumentQuery {
+                user_id: "nope".to_string(),
+                start: Some(Timestamp {
+                    seconds: 10,
+                    nanos: 0,
+                }),
+                end: Some(Timestamp {
+                    seconds: 5,
+                    nanos: 0,
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert_eq!(
+            violations(request.validate()),
+            ["query.user_id", "query.start"]
+        );
+        assert!(QueryRequest {
+            query: Some(DocumentQuery::default()),
+            ..Default::default()
+        }
+        .validate()
+        .is_ok());
+    }
+
+    #[test]
+    fn query_with_bounds_out_of_range_should_fail() {
+        let request = QueryRequest {
+            query: Some(DocumentQuery {
+                start: Some(Timestamp {
+                    seconds: i64::MIN,
+                    nanos: 0,
+                }),
+                end: Some(Timestamp {
+                    seconds: 0,
+                    nanos: -1,
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert_eq!(violations(request.validate()), ["query.start", "query.end"]);
+        assert!(validate_range(
+            None,
+            Some(&Timestamp {
+                seconds: i64::MAX,
+                nanos: 0
+            })
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn user_requests_should_need_a_user() {
+        assert_eq!(
+            violations(CreateUserRequest::default().validate()),
+            ["user"]
+        );
+        assert_eq!(
+            violations(UpdateUserRequest::default().validate()),
+            ["user"]
+        );
+        let request = UpdateUserRequest {
+            user: Some(Default::default()),
+        };
+        assert_eq!(violations(request.validate()), ["user.id"]);
+        assert_eq!(violations(GetUserRequest::default().validate()), ["id"]);
+    }
+}
@@ -0,0 +1,33 @@
+use chrono::{DateTime, Utc};
+use prost_wkt_types::{Struct, Timestamp};
+use serde_json::Value;
+use sqlx::{postgres::PgRow, types::Uuid, FromRow, Row};
+
+use crate::{DocumentItem, DocumentRevision};
+
+impl FromRow<'_, PgRow> for DocumentRevision {
+    fn from_row(row: &'_ PgRow) -> Result<Self, sqlx::Error> {
+        let document_id: Uuid = row.get("document_id");
+        let revision: i64 = row.get("revision");
+        let user_id: Uuid = row.get("user_id");
+        let data: Value = row.get("data");
+        let items: Value = row.get("items");
+        let deleted: bool = row.get("deleted");
+        let created_at: DateTime<Utc> = row.get("created_at");
+
+        let data: Struct =
+            serde_json::from_value(data).map_err(|e| sqlx::Error::Decode(Box::new(e)))?;
+        let items: Vec<DocumentItem> =
+            serde_json::from_value(items).map_err(|e| sqlx::Error::Decode(Box::new(e)))?;
+
+        Ok(Self {
+            document_id: document_id.to_string(),
+            revision,
+            user_id: user_id.to_string(),
+            data: Some(data),
+            items,
+            deleted,
+            created_at: Some(Timestamp::from(created_at)),
+        })
+    }
+}
@@ -0,0 +1,25 @@
+use chrono::{DateTime, Utc};
+use prost_wkt_types::{Struct, Timestamp};
+use serde_json::Value;
+use sqlx::{postgres::PgRow, types::Uuid, FromRow, Row};
+
+use crate::DocumentSchema;
+
+impl FromRow<'_, PgRow> for DocumentSchema {
+    fn from_row(row: &'_ PgRow) -> Result<Self, sqlx::Error> {
+        let org_id: Uuid = row.get("org_id");
+        let schema: Value = row.get("schema");
+        let created_at: DateTime<Utc> = row.get("created_at");
+
+        let schema: Struct =
+            serde_json::from_value(schema).map_err(|e| sqlx::Error::Decode(Box::new(e)))?;
+
+        Ok(Self {
+            org_id: org_id.to_string(),
+            document_type: row.get("document_type"),
+            version: row.get("version"),
+            schema: Some(schema),
+            created_at: Some(Timestamp::from(created_at)),
+        })
+    }
+}
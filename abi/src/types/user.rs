@@ -0,0 +1,88 @@
+use chrono::{DateTime, Utc};
+use prost_wkt_types::Timestamp;
+use sqlx::{postgres::PgRow, types::Uuid, FromRow, Row};
+
+use crate::{Error, Role, User, Validator};
+
+impl Validator for User {
+    fn validate(&self) -> Result<(), Error> {
+        if self.display_name.trim().is_empty() {
+            return Err(Error::InvalidUser("display name is empty".to_string()));
+        }
+
+        if !self.email.contains('@') {
+            return Err(Error::InvalidUser(format!("invalid email: {}", self.email)));
+        }
+
+        match Role::try_from(self.role) {
+            Ok(Role::Unspecified) | Err(_) => {
+                return Err(Error::InvalidUser(format!("invalid role: {}", self.role)))
+            }
+            Ok(Role::Store) if self.store_id.is_empty() => {
+                return Err(Error::InvalidUser(
+                    "store users must belong to a store".to_string(),
+                ))
+            }
+            Ok(_) => {}
+        }
+
+        if !self.store_id.is_empty() && Uuid::parse_str(&self.store_id).is_err() {
+            return Err(Error::InvalidUser(format!(
+                "invalid store id: {}",
+                self.store_id
+            )));
+        }
+
+        if !self.org_id.is_empty() && Uuid::parse_str(&self.org_id).is_err() {
+            return Err(Error::InvalidUser(format!(
+                "invalid organization id: {}",
+                self.org_id
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl Role {
+    /// the value stored in the role column
+    pub fn to_db(self) -> &'static str {
+        match self {
+            Role::Unspecified => "unspecified",
+            Role::Store => "store",
+            Role::Admin => "admin",
+            Role::Auditor => "auditor",
+        }
+    }
+
+    pub fn from_db(role: &str) -> Self {
+        match role {
+            "store" => Role::Store,
+            "admin" => Role::Admin,
+            "auditor" => Role::Auditor,
+            _ => Role::Unspecified,
+        }
+    }
+}
+
+impl FromRow<'_, PgRow> for User {
+    fn from_row(row: &'_ PgRow) -> Result<Self, sqlx::Error> {
+        let id: Uuid = row.get("id");
+        let role: String = row.get("role");
+        let store_id: Option<Uuid> = row.get("store_id");
+        let org_id: Option<Uuid> = row.get("org_id");
+        let created_at: DateTime<Utc> = row.get("created_at");
+        let updated_at: DateTime<Utc> = row.get("updated_at");
+
+        Ok(Self {
+            id: id.to_string(),
+            display_name: row.get("display_name"),
+            email: row.get("email"),
+            role: Role::from_db(&role) as i32,
+            store_id: store_id.map(|id| id.to_string()).unwrap_or_default(),
+            active: row.get("active"),
+            created_at: Some(Timestamp::from(created_at)),
+            updated_at: Some(Timestamp::from(updated_at)),
+            org_id: org_id.map(|id| id.to_string()).unwrap_or_default(),
+        })
+    }
+}
@@ -0,0 +1,62 @@
+use chrono::{DateTime, Utc};
+use prost_wkt_types::Timestamp;
+use serde_json::Value;
+use sqlx::{postgres::PgRow, types::Uuid, FromRow, Row};
+
+use crate::{ChangeType, DeliveryStatus, DocumentQuery, Webhook, WebhookDelivery};
+
+impl FromRow<'_, PgRow> for Webhook {
+    fn from_row(row: &'_ PgRow) -> Result<Self, sqlx::Error> {
+        let id: Uuid = row.get("id");
+        let org_id: Option<Uuid> = row.get("org_id");
+        let event_types: Vec<String> = row.get("event_types");
+        let query: Value = row.get("query");
+        let created_at: DateTime<Utc> = row.get("created_at");
+
+        let query: DocumentQuery =
+            serde_json::from_value(query).map_err(|e| sqlx::Error::Decode(Box::new(e)))?;
+
+        Ok(Self {
+            id: id.to_string(),
+            org_id: org_id.map(|id| id.to_string()).unwrap_or_default(),
+            url: row.get("url"),
+            event_types: event_types
+                .iter()
+                .filter_map(|name| ChangeType::from_str_name(name))
+                .map(|t| t as i32)
+                .collect(),
+            query: Some(query),
+            secret: row.get("secret"),
+            created_at: Some(Timestamp::from(created_at)),
+        })
+    }
+}
+
+impl FromRow<'_, PgRow> for WebhookDelivery {
+    fn from_row(row: &'_ PgRow) -> Result<Self, sqlx::Error> {
+        let id: Uuid = row.get("id");
+        let webhook_id: Uuid = row.get("webhook_id");
+        let document_id: Uuid = row.get("document_id");
+        let change_type: String = row.get("change_type");
+        let status: String = row.get("status");
+        let response_status: Option<i32> = row.get("response_status");
+        let next_attempt_at: Option<DateTime<Utc>> = row.get("next_attempt_at");
+        let created_at: DateTime<Utc> = row.get("created_at");
+        let finished_at: Option<DateTime<Utc>> = row.get("finished_at");
+
+        Ok(Self {
+            id: id.to_string(),
+            webhook_id: webhook_id.to_string(),
+            sequence: row.get("seq"),
+            change_type: ChangeType::from_str_name(&change_type).unwrap_or_default() as i32,
+            document_id: document_id.to_string(),
+            status: DeliveryStatus::from_str_name(&status).unwrap_or_default() as i32,
+            attempts: row.get("attempts"),
+            response_status: response_status.unwrap_or_default(),
+            last_error: row.get("last_error"),
+            next_attempt_at: next_attempt_at.map(Timestamp::from),
+            created_at: Some(Timestamp::from(created_at)),
+            finished_at: finished_at.map(Timestamp::from),
+        })
+    }
+}
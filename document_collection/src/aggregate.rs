@@ -0,0 +1,150 @@
+use abi::{AggregateRequest, GroupKind};
+use sqlx::{Postgres, QueryBuilder};
+
+use crate::{
+    filter::parse_path,
+    manager::push_conditions,
+    time_range::{parse_time_zone, TimeRange},
+};
+
+/// most numeric fields a single aggregate request may ask for
+const MAX_AGGREGATE_FIELDS: usize = 16;
+
+/// decimal places averages are rounded to
+const AVG_SCALE: i32 = 2;
+
+/// compose the aggregate query, its columns are `key`, `count` and `sum_{i}` / `avg_{i}` for every field
+pub(crate) fn build_aggregate(
+    request: &AggregateRequest,
+) -> Result<QueryBuilder<'static, Postgres>, abi::Error> {
+    if request.fields.len() > MAX_AGGREGATE_FIELDS {
+        return Err(invalid_query(format!(
+            "at most {} fields can be aggregated",
+            MAX_AGGREGATE_FIELDS
+        )));
+    }
+    let query = request.query.clone().unwrap_or_default();
+
+    let mut builder = QueryBuilder::new("SELECT ");
+    push_group_key(&mut builder, request, &query)?;
+    builder.push(" AS key, COUNT(*) AS count");
+    for (i, field) in request.fields.iter().enumerate() {
+        builder.push(", SUM(");
+        push_number(&mut builder, field)?;
+        builder.push(format!(")::TEXT AS sum_{}, ROUND(AVG(", i));
+        push_number(&mut builder, field)?;
+        builder.push(format!("), {})::TEXT AS avg_{}", AVG_SCALE, i));
+    }
+    builder.push(" FROM dc.documents");
+    push_conditions(&mut builder, &query)?;
+    // group and order by position, bound parameters make the expressions differ textually
+    builder.push(" GROUP BY 1 ORDER BY 1");
+    Ok(builder)
+}
+
+fn push_group_key(
+    builder: &mut QueryBuilder<'_, Postgres>,
+    request: &AggregateRequest,
+    query: &abi::DocumentQuery,
+) -> Result<(), abi::Error> {
+    let group_by = request.group_by.clone().unwrap_or_default();
+    let kind = GroupKind::try_from(group_by.kind)
+        .map_err(|_| invalid_query(format!("unknown group kind: {}", group_by.kind)))?;
+
+    let period = match kind {
+        GroupKind::None => {
+            builder.push("''");
+            return Ok(());
+        }
+        GroupKind::User => {
+            builder.push("user_id::TEXT");
+            return Ok(());
+        }
+        GroupKind::Path => {
+            let (column, path) = parse_path(&group_by.path).map_err(invalid_query)?;
+            builder.push(format!("COALESCE({} #>> ", column));
+            builder.push_bind(path);
+            builder.push("::TEXT[], '')");
+            return Ok(());
+        }
+        GroupKind::Day => "day",
+        GroupKind::Week => "week",
+        GroupKind::Month => "month",
+    };
+
+    let field = TimeRange::new(query)?.field();
+    let tz = parse_time_zone(&query.time_zone)?;
+    builder.push(format!(
+        "to_char(date_trunc('{}', {} AT TIME ZONE ",
+        period, field
+    ));
+    builder.push_bind(tz.name().to_string());
+    builder.push("), 'YYYY-MM-DD')");
+    Ok(())
+}
+
+/// push the numeric value of a field, null where the document has no number there
+fn push_number(builder: &mut QueryBuilder<'_, Postgres>, field: &str) -> Result<(), abi::Error> {
+    if field == "total" {
+        // item totals are stored with the items, the document total is their sum
+        builder.push(
+            "(SELECT COALESCE(SUM((i ->> 'total')::NUMERIC), 0) FROM jsonb_array_elements(items) i)",
+        );
+        return Ok(());
+    }
+
+    let (column, path) = parse_path(field).map_err(invalid_query)?;
+    if column != "data" || path.is_empty() {
+        return Err(invalid_query(format!(
+            "aggregated fields must be total or a path inside data: {}",
+            field
+        )));
+    }
+    builder.push("CASE WHEN jsonb_typeof(data #> ");
+    builder.push_bind(path.clone());
+    builder.push("::TEXT[]) = 'number' THEN (data #>> ");
+    builder.push_bind(path);
+    builder.push("::TEXT[])::NUMERIC END");
+    Ok(())
+}
+
+fn invalid_query(e: impl ToString) -> abi::Error {
+    abi::Error::InvalidQuery(e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use abi::GroupBy;
+
+    use super::*;
+
+    #[test]
+    fn aggregate_by_month_should_compile() {
+        let request = AggregateRequest {
+            query: None,
+            group_by: Some(GroupBy {
+                kind: GroupKind::Month as i32,
+                path: String::new(),
+            }),
+            fields: vec!["total".to_string()],
+        };
+        let sql = build_aggregate(&request).unwrap().into_sql();
+
+        assert!(sql.starts_with(
+            "SELECT to_char(date_trunc('month', created_at AT TIME ZONE $1), 'YYYY-MM-DD') AS key, COUNT(*) AS count, SUM((SELECT"
+        ));
+        assert!(sql.ends_with(" GROUP BY 1 ORDER BY 1"));
+    }
+
+    #[test]
+    fn aggregate_of_non_data_field_should_fail() {
+        let request = AggregateRequest {
+            fields: vec!["items.total".to_string()],
+            ..Default::default()
+        };
+        assert!(matches!(
+            build_aggregate(&request),
+            Err(abi::Error::InvalidQuery(_))
+        ));
+    }
+}
@@ -0,0 +1,828 @@
+use std::collections::{HashMap, HashSet};
+
+use abi::{CreateRequest, DeleteRequest, Document, FieldViolation, UpdateRequest};
+use async_trait::async_trait;
+use serde_json::Value;
+use sqlx::{types::Uuid, Acquire, PgConnection, Postgres, Transaction};
+
+use crate::{
+    manager::{
+        collection_name, data_to_value, items_to_value, parse_id, parse_user_id, resolve_type,
+        user_org,
+    },
+    schema::LatestSchemas,
+    totals::fill_totals,
+    Batches, DcManager, Owner,
+};
+
+/// a document ready to be inserted
+struct NewDocument {
+    id: Uuid,
+    user_id: Uuid,
+    /// the organization of the user, the document is checked against its schemas
+    org_id: Option<Uuid>,
+    data: Value,
+    items: Value,
+    document_type: String,
+    schema_version: Option<i32>,
+    collection: String,
+}
+
+/// an update ready to be applied
+struct DocumentUpdate {
+    id: Uuid,
+    collection: String,
+    /// None keeps the stored data
+    data: Option<Value>,
+    /// None keeps the stored items
+    items: Option<Value>,
+    schema_version: Option<i32>,
+    expected_version: Option<i64>,
+}
+
+/// what a write needs to know of a document written before
+struct FoundDocument {
+    user_id: Uuid,
+    org_id: Option<Uuid>,
+    document_type: String,
+}
+
+/// a delete ready to be applied
+struct DocumentDelete {
+    id: Uuid,
+    collection: String,
+    expected_version: Option<i64>,
+}
+
+/// The outcome of every request of a batch, in request order.
+type BatchResults = Vec<Result<Document, abi::Error>>;
+
+/// The outcome of every request of a batch while it runs, None for those not run yet.
+type Outcomes = Vec<Option<Result<Document, abi::Error>>>;
+
+#[async_trait]
+impl Batches for DcManager {
+    async fn batch_create(
+        &self,
+        requests: Vec<CreateRequest>,
+        atomic: bool,
+    ) -> Result<BatchResults, abi::Error> {
+        let prepared = requests.into_iter().map(parse_create).collect();
+        let prepared = self.prepare_creates(prepared).await?;
+        let (mut results, ready) = split(prepared, atomic);
+        if ready.is_empty() {
+            return Ok(finish(results));
+        }
+
+        let mut tx = self.pool.begin().await?;
+        let (ready, documents) = write_rows(&mut tx, &mut results, ready, atomic).await?;
+
+        // a user removed since it was checked leaves its documents out
+        let ready = ready.into_iter().map(|(i, d)| (i, d.id));
+        let missing = |_| async { abi::Error::UserNotFound };
+        self.apply(tx, &mut results, ready, documents, atomic, missing)
+            .await?;
+        Ok(finish(results))
+    }
+
+    async fn batch_update(
+        &self,
+        requests: Vec<UpdateRequest>,
+        atomic: bool,
+    ) -> Result<BatchResults, abi::Error> {
+        let mut prepared: Vec<_> = requests.into_iter().map(parse_update).collect();
+        reject_duplicates(&mut prepared, |u| u.id);
+        let prepared = self.prepare_updates(prepared).await?;
+        let (mut results, ready) = split(prepared, atomic);
+        if ready.is_empty() {
+            return Ok(finish(results));
+        }
+
+        let mut tx = self.pool.begin().await?;
+        let (ready, documents) = write_rows(&mut tx, &mut results, ready, atomic).await?;
+
+        let missing: HashMap<Uuid, (String, Option<i64>)> = ready
+            .iter()
+            .map(|(_, u)| (u.id, (u.collection.clone(), u.expected_version)))
+            .collect();
+        let ready = ready.into_iter().map(|(i, u)| (i, u.id));
+        let missing = |id| {
+            let (collection, expected_version) = missing[&id].clone();
+            async move {
+                self.precondition_error(&self.pool, &collection, id, expected_version)
+                    .await
+            }
+        };
+        self.apply(tx, &mut results, ready, documents, atomic, missing)
+            .await?;
+        Ok(finish(results))
+    }
+
+    async fn batch_delete(
+        &self,
+        requests: Vec<DeleteRequest>,
+        atomic: bool,
+    ) -> Result<BatchResults, abi::Error> {
+        let mut prepared: Vec<_> = requests
+            .into_iter()
+            .map(|request| {
+                Ok(DocumentDelete {
+                    id: parse_id(&request.id)?,
+                    collection: collection_name(request.collection),
+                    expected_version: (request.expected_version > 0)
+                        .then_some(request.expected_version),
+                })
+            })
+            .collect();
+        reject_duplicates(&mut prepared, |d| d.id);
+        let (mut results, ready) = split(prepared, atomic);
+        if ready.is_empty() {
+            return Ok(finish(results));
+        }
+
+        let mut tx = self.pool.begin().await?;
+        let (ready, documents) = write_rows(&mut tx, &mut results, ready, atomic).await?;
+
+        let missing: HashMap<Uuid, (String, Option<i64>)> = ready
+            .iter()
+            .map(|(_, d)| (d.id, (d.collection.clone(), d.expected_version)))
+            .collect();
+        let ready = ready.into_iter().map(|(i, d)| (i, d.id));
+        let missing = |id| {
+            let (collection, expected_version) = missing[&id].clone();
+            async move {
+                self.precondition_error(&self.pool, &collection, id, expected_version)
+                    .await
+            }
+        };
+        self.apply(tx, &mut results, ready, documents, atomic, missing)
+            .await?;
+        Ok(finish(results))
+    }
+
+    async fn batch_owners(
+        &self,
+        documents: Vec<(String, abi::DocumentId)>,
+    ) -> Result<Vec<Result<Owner, abi::Error>>, abi::Error> {
+        let keys: Vec<Result<_, abi::Error>> = documents
+            .into_iter()
+            .map(|(collection, id)| Ok((parse_id(&id)?, collection_name(collection))))
+            .collect();
+        let found = self
+            .find_documents(keys.iter().flatten().cloned().collect())
+            .await?;
+        Ok(keys
+            .into_iter()
+            .map(|key| {
+                let found = found.get(&key?).ok_or(abi::Error::NotFound)?;
+                Ok(Owner {
+                    user_id: found.user_id.to_string(),
+                    org_id: found.org_id.map(|id| id.to_string()).unwrap_or_default(),
+                })
+            })
+            .collect())
+    }
+}
+
+impl DcManager {
+    /// check new documents like create does, looking up their users, collections and schemas
+    /// once for all of them
+    async fn prepare_creates(
+        &self,
+        prepared: Vec<Result<NewDocument, abi::Error>>,
+    ) -> Result<Vec<Result<NewDocument, abi::Error>>, abi::Error> {
+        let user_ids: Vec<Uuid> = prepared.iter().flatten().map(|d| d.user_id).collect();
+        let users: HashMap<Uuid, (bool, Option<Uuid>)> =
+            sqlx::query_as("SELECT id, active, org_id FROM dc.users WHERE id = ANY($1)")
+                .bind(user_ids)
+                .fetch_all(&self.pool)
+                .await?
+                .into_iter()
+                .map(|(id, active, org_id)| (id, (active, org_id)))
+                .collect();
+        let names: Vec<&str> = prepared
+            .iter()
+            .flatten()
+            .map(|d| d.collection.as_str())
+            .collect();
+        let collections: HashMap<String, String> =
+            sqlx::query_as("SELECT name, document_type FROM dc.collections WHERE name = ANY($1) AND state <> 'dropping'")
+                .bind(names)
+                .fetch_all(&self.pool)
+                .await?
+                .into_iter()
+                .collect();
+
+        let prepared: Vec<Result<NewDocument, abi::Error>> = prepared
+            .into_iter()
+            .map(|document| {
+                let document = document?;
+                let org_id = user_org(document.user_id, users.get(&document.user_id).copied())?;
+                let collection_type = collections.get(&document.collection).cloned();
+                let (collection, document_type) =
+                    resolve_type(document.collection, collection_type, document.document_type)?;
+                Ok(NewDocument {
+                    org_id,
+                    collection,
+                    document_type,
+                    ..document
+                })
+            })
+            .collect();
+
+        let types = prepared
+            .iter()
+            .flatten()
+            .map(|d| (d.org_id, d.document_type.as_str()));
+        let schemas = LatestSchemas::load(&self.pool, &self.schemas, types).await?;
+        Ok(prepared
+            .into_iter()
+            .map(|document| {
+                let mut document = document?;
+                document.schema_version =
+                    schemas.check(document.org_id, &document.document_type, &document.data)?;
+                Ok(document)
+            })
+            .collect())
+    }
+
+    /// check updates like update does, looking up their documents and schemas once for all of them
+    async fn prepare_updates(
+        &self,
+        prepared: Vec<Result<DocumentUpdate, abi::Error>>,
+    ) -> Result<Vec<Result<DocumentUpdate, abi::Error>>, abi::Error> {
+        let keys = prepared
+            .iter()
+            .flatten()
+            .map(|u| (u.id, u.collection.clone()));
+        let found = self.find_documents(keys.collect()).await?;
+        let prepared: Vec<Result<_, abi::Error>> = prepared
+            .into_iter()
+            .map(|update| {
+                let update = update?;
+                let found = found
+                    .get(&(update.id, update.collection.clone()))
+                    .ok_or(abi::Error::NotFound)?;
+                Ok((update, found))
+            })
+            .collect();
+
+        let types = prepared
+            .iter()
+            .flatten()
+            .filter(|(u, _)| u.data.is_some())
+            .map(|(_, found)| (found.org_id, found.document_type.as_str()));
+        let schemas = LatestSchemas::load(&self.pool, &self.schemas, types).await?;
+        Ok(prepared
+            .into_iter()
+            .map(|prepared| {
+                let (mut update, found) = prepared?;
+                if let Some(data) = &update.data {
+                    update.schema_version =
+                        schemas.check(found.org_id, &found.document_type, data)?;
+                }
+                Ok(update)
+            })
+            .collect())
+    }
+
+    /// look up documents by id and collection, also those in the trash or purged
+    async fn find_documents(
+        &self,
+        keys: Vec<(Uuid, String)>,
+    ) -> Result<HashMap<(Uuid, String), FoundDocument>, abi::Error> {
+        let (ids, collections): (Vec<_>, Vec<_>) = keys.into_iter().unzip();
+        let found: Vec<(Uuid, String, Uuid, Option<Uuid>, String)> = sqlx::query_as(
+            "SELECT b.id, b.collection, d.user_id, d.org_id, d.document_type
+            FROM UNNEST($1::UUID[], $2::TEXT[]) AS b(id, collection)
+            JOIN LATERAL (
+                SELECT user_id, org_id, document_type FROM dc.documents
+                WHERE id = b.id AND collection = b.collection
+                UNION ALL SELECT user_id, org_id, document_type FROM dc.document_revisions
+                WHERE document_id = b.id AND collection = b.collection LIMIT 1
+            ) d ON TRUE",
+        )
+        .bind(ids)
+        .bind(collections)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(found
+            .into_iter()
+            .map(|(id, collection, user_id, org_id, document_type)| {
+                let found = FoundDocument {
+                    user_id,
+                    org_id,
+                    document_type,
+                };
+                ((id, collection), found)
+            })
+            .collect())
+    }
+
+    /// match the written documents back to their requests, explaining with `missing` why a request
+    /// wrote nothing, and commit unless that fails an atomic batch
+    async fn apply<F, Fut>(
+        &self,
+        tx: Transaction<'_, Postgres>,
+        results: &mut [Option<Result<Document, abi::Error>>],
+        ready: impl Iterator<Item = (usize, Uuid)>,
+        documents: Vec<Document>,
+        atomic: bool,
+        missing: F,
+    ) -> Result<(), abi::Error>
+    where
+        F: Fn(Uuid) -> Fut,
+        Fut: std::future::Future<Output = abi::Error>,
+    {
+        let mut documents: HashMap<String, Document> =
+            documents.into_iter().map(|d| (d.id.clone(), d)).collect();
+        let mut failed = vec![];
+        for (i, id) in ready {
+            match documents.remove(&id.to_string()) {
+                Some(mut document) => {
+                    fill_totals(&mut document)?;
+                    results[i] = Some(Ok(document));
+                }
+                None => failed.push((i, id)),
+            }
+        }
+
+        if atomic && !failed.is_empty() {
+            tx.rollback().await?;
+            for result in results.iter_mut() {
+                *result = Some(Err(abi::Error::BatchAborted));
+            }
+        } else {
+            tx.commit().await?;
+        }
+        // explain after the transaction ended, so it looks at what other requests see
+        for (i, id) in failed {
+            results[i] = Some(Err(missing(id).await));
+        }
+        Ok(())
+    }
+}
+
+/// A request of a batch, written by one statement for all the requests of its kind.
+#[async_trait]
+trait Row: Sized + Send + Sync {
+    /// write the rows, returning the documents written
+    async fn write(
+        conn: &mut PgConnection,
+        rows: &[(usize, Self)],
+    ) -> Result<Vec<Document>, sqlx::Error>;
+}
+
+#[async_trait]
+impl Row for NewDocument {
+    async fn write(
+        conn: &mut PgConnection,
+        rows: &[(usize, Self)],
+    ) -> Result<Vec<Document>, sqlx::Error> {
+        let ids: Vec<_> = rows.iter().map(|(_, d)| d.id).collect();
+        let user_ids: Vec<_> = rows.iter().map(|(_, d)| d.user_id).collect();
+        let data: Vec<_> = rows.iter().map(|(_, d)| d.data.clone()).collect();
+        let items: Vec<_> = rows.iter().map(|(_, d)| d.items.clone()).collect();
+        let document_types: Vec<_> = rows.iter().map(|(_, d)| d.document_type.clone()).collect();
+        let schema_versions: Vec<_> = rows.iter().map(|(_, d)| d.schema_version).collect();
+        let collections: Vec<_> = rows.iter().map(|(_, d)| d.collection.clone()).collect();
+        sqlx::query_as(
+            "INSERT INTO dc.documents (id, user_id, org_id, store_id, data, items, document_type, schema_version, collection)
+            SELECT b.id, u.id, u.org_id, u.store_id, b.data, b.items, b.document_type, b.schema_version, b.collection
+            FROM UNNEST($1::UUID[], $2::UUID[], $3::JSONB[], $4::JSONB[], $5::TEXT[], $6::INT[], $7::TEXT[])
+                WITH ORDINALITY AS b(id, user_id, data, items, document_type, schema_version, collection, n)
+            JOIN dc.users u ON u.id = b.user_id
+            ORDER BY b.n
+            RETURNING *",
+        )
+        .bind(ids)
+        .bind(user_ids)
+        .bind(data)
+        .bind(items)
+        .bind(document_types)
+        .bind(schema_versions)
+        .bind(collections)
+        .fetch_all(conn)
+        .await
+    }
+}
+
+#[async_trait]
+impl Row for DocumentUpdate {
+    async fn write(
+        conn: &mut PgConnection,
+        rows: &[(usize, Self)],
+    ) -> Result<Vec<Document>, sqlx::Error> {
+        let ids: Vec<_> = rows.iter().map(|(_, u)| u.id).collect();
+        let collections: Vec<_> = rows.iter().map(|(_, u)| u.collection.clone()).collect();
+        let data: Vec<_> = rows.iter().map(|(_, u)| u.data.clone()).collect();
+        let items: Vec<_> = rows.iter().map(|(_, u)| u.items.clone()).collect();
+        let schema_versions: Vec<_> = rows.iter().map(|(_, u)| u.schema_version).collect();
+        let expected_versions: Vec<_> = rows.iter().map(|(_, u)| u.expected_version).collect();
+        sqlx::query_as(
+            "UPDATE dc.documents d SET data = COALESCE(b.data, d.data), items = COALESCE(b.items, d.items),
+                schema_version = CASE WHEN b.data IS NULL THEN d.schema_version ELSE b.schema_version END
+            FROM UNNEST($1::UUID[], $2::TEXT[], $3::JSONB[], $4::JSONB[], $5::INT[], $6::BIGINT[])
+                AS b(id, collection, data, items, schema_version, expected_version)
+            WHERE d.id = b.id AND d.collection = b.collection AND d.deleted_at IS NULL
+                AND (b.expected_version IS NULL OR d.version = b.expected_version)
+            RETURNING d.*",
+        )
+        .bind(ids)
+        .bind(collections)
+        .bind(data)
+        .bind(items)
+        .bind(schema_versions)
+        .bind(expected_versions)
+        .fetch_all(conn)
+        .await
+    }
+}
+
+#[async_trait]
+impl Row for DocumentDelete {
+    async fn write(
+        conn: &mut PgConnection,
+        rows: &[(usize, Self)],
+    ) -> Result<Vec<Document>, sqlx::Error> {
+        let ids: Vec<_> = rows.iter().map(|(_, d)| d.id).collect();
+        let collections: Vec<_> = rows.iter().map(|(_, d)| d.collection.clone()).collect();
+        let expected_versions: Vec<_> = rows.iter().map(|(_, d)| d.expected_version).collect();
+        sqlx::query_as(
+            "UPDATE dc.documents d SET deleted_at = NOW()
+            FROM UNNEST($1::UUID[], $2::TEXT[], $3::BIGINT[]) AS b(id, collection, expected_version)
+            WHERE d.id = b.id AND d.collection = b.collection AND d.deleted_at IS NULL
+                AND (b.expected_version IS NULL OR d.version = b.expected_version)
+            RETURNING d.*",
+        )
+        .bind(ids)
+        .bind(collections)
+        .bind(expected_versions)
+        .fetch_all(conn)
+        .await
+    }
+}
+
+/// Write the rows ready to run in the transaction, returning the rows written and their documents.
+/// An atomic batch fails as a whole when the database rejects a row. Otherwise the rows are written
+/// again one by one, each in a savepoint, so a rejected row only fails itself.
+async fn write_rows<T: Row>(
+    tx: &mut Transaction<'_, Postgres>,
+    results: &mut [Option<Result<Document, abi::Error>>],
+    ready: Vec<(usize, T)>,
+    atomic: bool,
+) -> Result<(Vec<(usize, T)>, Vec<Document>), abi::Error> {
+    if atomic {
+        let documents = T::write(tx, &ready).await?;
+        return Ok((ready, documents));
+    }
+
+    let mut savepoint = tx.begin().await?;
+    match T::write(&mut savepoint, &ready).await {
+        Ok(documents) => {
+            savepoint.commit().await?;
+            return Ok((ready, documents));
+        }
+        Err(_) => savepoint.rollback().await?,
+    }
+    let mut written = Vec::with_capacity(ready.len());
+    let mut documents = vec![];
+    for row in ready {
+        let mut savepoint = tx.begin().await?;
+        match T::write(&mut savepoint, std::slice::from_ref(&row)).await {
+            Ok(mut document) => {
+                savepoint.commit().await?;
+                documents.append(&mut document);
+                written.push(row);
+            }
+            Err(e) => {
+                savepoint.rollback().await?;
+                results[row.0] = Some(Err(e.into()));
+            }
+        }
+    }
+    Ok((written, documents))
+}
+
+/// check what a create request holds by itself
+fn parse_create(request: CreateRequest) -> Result<NewDocument, abi::Error> {
+    Ok(NewDocument {
+        id: Uuid::new_v4(),
+        items: items_to_value(request.items)?,
+        data: data_to_value(request.data.unwrap_or_default())?,
+        user_id: parse_user_id(&request.user_id)?,
+        org_id: None,
+        document_type: request.document_type,
+        schema_version: None,
+        collection: collection_name(request.collection),
+    })
+}
+
+/// check what an update request holds by itself
+fn parse_update(request: UpdateRequest) -> Result<DocumentUpdate, abi::Error> {
+    let items = (!request.items.is_empty())
+        .then(|| items_to_value(request.items))
+        .transpose()?;
+    Ok(DocumentUpdate {
+        data: request.data.map(data_to_value).transpose()?,
+        items,
+        id: parse_id(&request.id)?,
+        collection: collection_name(request.collection),
+        schema_version: None,
+        expected_version: (request.expected_version > 0).then_some(request.expected_version),
+    })
+}
+
+/// Set aside the requests that failed to prepare, and return the ones ready to run with their
+/// position. An atomic batch with a failed request runs none, the others are aborted.
+fn split<T>(prepared: Vec<Result<T, abi::Error>>, atomic: bool) -> (Outcomes, Vec<(usize, T)>) {
+    let abort = atomic && prepared.iter().any(|p| p.is_err());
+    let mut results = Vec::with_capacity(prepared.len());
+    let mut ready = vec![];
+    for (i, p) in prepared.into_iter().enumerate() {
+        match p {
+            Err(e) => results.push(Some(Err(e))),
+            Ok(_) if abort => results.push(Some(Err(abi::Error::BatchAborted))),
+            Ok(value) => {
+                results.push(None);
+                ready.push((i, value));
+            }
+        }
+    }
+    (results, ready)
+}
+
+/// every request has a result once the batch ran
+fn finish(results: Outcomes) -> BatchResults {
+    results
+        .into_iter()
+        .map(|result| result.unwrap_or(Err(abi::Error::BatchAborted)))
+        .collect()
+}
+
+/// a batch may write each document once, later requests for it fail
+fn reject_duplicates<T, K>(prepared: &mut [Result<T, abi::Error>], key: impl Fn(&T) -> K)
+where
+    K: std::hash::Hash + Eq,
+{
+    let mut seen = HashSet::new();
+    for (i, result) in prepared.iter_mut().enumerate() {
+        if matches!(result, Ok(value) if !seen.insert(key(value))) {
+            *result = Err(abi::Error::InvalidRequest(vec![FieldViolation {
+                field: format!("requests[{}].id", i),
+                description: "the batch already holds a request for this document".to_string(),
+            }]));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use abi::DocumentId;
+    use sqlx::PgPool;
+
+    use super::*;
+    use crate::{
+        testing::{new_manager, USER_ID},
+        Dc, DEFAULT_COLLECTION,
+    };
+
+    fn create_request(user_id: &str) -> CreateRequest {
+        CreateRequest {
+            user_id: user_id.to_string(),
+            ..Default::default()
+        }
+    }
+
+    async fn create_documents(manager: &DcManager, n: usize) -> Vec<DocumentId> {
+        let requests = (0..n).map(|_| create_request(USER_ID)).collect();
+        manager
+            .batch_create(requests, true)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|result| result.unwrap().id)
+            .collect()
+    }
+
+    async fn document_count(manager: &DcManager) -> i64 {
+        sqlx::query_scalar("SELECT COUNT(*) FROM dc.documents WHERE deleted_at IS NULL")
+            .fetch_one(&manager.pool)
+            .await
+            .unwrap()
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn batch_create_should_return_documents_in_request_order(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let requests = (1..=3)
+            .map(|i| CreateRequest {
+                data: Some(serde_json::from_value(serde_json::json!({ "n": i })).unwrap()),
+                ..create_request(USER_ID)
+            })
+            .collect();
+
+        let results = manager.batch_create(requests, false).await.unwrap();
+        let numbers: Vec<_> = results
+            .into_iter()
+            .map(|result| {
+                let document = result.unwrap();
+                assert_eq!(document.version, 1);
+                document.data.unwrap().fields["n"].clone()
+            })
+            .collect();
+        assert_eq!(
+            numbers,
+            vec![
+                prost_wkt_types::Value::from(1.0),
+                prost_wkt_types::Value::from(2.0),
+                prost_wkt_types::Value::from(3.0)
+            ]
+        );
+        // every document is recorded like a single create
+        let revisions: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM dc.document_revisions")
+            .fetch_one(&manager.pool)
+            .await
+            .unwrap();
+        assert_eq!(revisions, 3);
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn batch_create_should_fail_requests_on_their_own(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let requests = vec![
+            create_request(USER_ID),
+            create_request("not-a-uuid"),
+            create_request(USER_ID),
+        ];
+
+        let results = manager.batch_create(requests, false).await.unwrap();
+        assert!(results[0].is_ok());
+        assert!(matches!(results[1], Err(abi::Error::InvalidUserId(_))));
+        assert!(results[2].is_ok());
+        assert_eq!(document_count(&manager).await, 2);
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn batch_create_should_fail_only_the_rows_the_database_rejects(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        sqlx::query(
+            "ALTER TABLE dc.documents ADD CONSTRAINT no_items CHECK (jsonb_array_length(items) = 0)",
+        )
+        .execute(&manager.pool)
+        .await
+        .unwrap();
+        let requests = || {
+            vec![
+                create_request(USER_ID),
+                CreateRequest {
+                    items: vec![abi::DocumentItem {
+                        name: "tea".to_string(),
+                        ..Default::default()
+                    }],
+                    ..create_request(USER_ID)
+                },
+                create_request(USER_ID),
+            ]
+        };
+
+        let results = manager.batch_create(requests(), false).await.unwrap();
+        assert!(results[0].is_ok());
+        assert!(matches!(results[1], Err(abi::Error::DbError(_))));
+        assert!(results[2].is_ok());
+        assert_eq!(document_count(&manager).await, 2);
+
+        let err = manager.batch_create(requests(), true).await.unwrap_err();
+        assert!(matches!(err, abi::Error::DbError(_)));
+        assert_eq!(document_count(&manager).await, 2);
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn atomic_batch_create_should_write_nothing_if_a_request_fails(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let requests = vec![
+            create_request(USER_ID),
+            CreateRequest {
+                collection: "missing".to_string(),
+                ..create_request(USER_ID)
+            },
+        ];
+
+        let results = manager.batch_create(requests, true).await.unwrap();
+        assert!(matches!(results[0], Err(abi::Error::BatchAborted)));
+        assert!(matches!(results[1], Err(abi::Error::CollectionNotFound(_))));
+        assert_eq!(document_count(&manager).await, 0);
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn batch_update_should_report_conflicts_per_request(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let ids = create_documents(&manager, 2).await;
+        let requests = vec![
+            UpdateRequest {
+                id: ids[0].clone(),
+                expected_version: 1,
+                ..Default::default()
+            },
+            UpdateRequest {
+                id: ids[1].clone(),
+                expected_version: 3,
+                ..Default::default()
+            },
+        ];
+
+        let results = manager.batch_update(requests, false).await.unwrap();
+        assert_eq!(results[0].as_ref().unwrap().version, 2);
+        assert!(matches!(
+            results[1],
+            Err(abi::Error::Conflict {
+                expected: 3,
+                actual: 1
+            })
+        ));
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn atomic_batch_update_should_roll_back_on_conflict(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let ids = create_documents(&manager, 2).await;
+        let requests = vec![
+            UpdateRequest {
+                id: ids[0].clone(),
+                ..Default::default()
+            },
+            UpdateRequest {
+                id: ids[1].clone(),
+                expected_version: 3,
+                ..Default::default()
+            },
+        ];
+
+        let results = manager.batch_update(requests, true).await.unwrap();
+        assert!(matches!(results[0], Err(abi::Error::BatchAborted)));
+        assert!(matches!(results[1], Err(abi::Error::Conflict { .. })));
+        let document = manager
+            .get(String::new(), ids[0].clone(), false)
+            .await
+            .unwrap();
+        assert_eq!(document.version, 1);
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn batch_update_should_reject_a_document_twice(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let ids = create_documents(&manager, 1).await;
+        let request = UpdateRequest {
+            id: ids[0].clone(),
+            ..Default::default()
+        };
+
+        let results = manager
+            .batch_update(vec![request.clone(), request], false)
+            .await
+            .unwrap();
+        assert_eq!(results[0].as_ref().unwrap().version, 2);
+        assert!(matches!(results[1], Err(abi::Error::InvalidRequest(_))));
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn batch_delete_should_move_documents_to_trash(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let ids = create_documents(&manager, 3).await;
+        let mut requests: Vec<_> = ids[..2]
+            .iter()
+            .map(|id| DeleteRequest {
+                id: id.clone(),
+                ..Default::default()
+            })
+            .collect();
+        requests.push(DeleteRequest {
+            id: Uuid::new_v4().to_string(),
+            ..Default::default()
+        });
+
+        let results = manager.batch_delete(requests, false).await.unwrap();
+        assert!(results[0].as_ref().unwrap().deleted_at.is_some());
+        assert!(results[1].as_ref().unwrap().deleted_at.is_some());
+        assert!(matches!(results[2], Err(abi::Error::NotFound)));
+        assert_eq!(document_count(&manager).await, 1);
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn batch_owners_should_keep_the_order_asked_for(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let ids = create_documents(&manager, 2).await;
+        let documents = vec![
+            (String::new(), ids[1].clone()),
+            (String::new(), "not-a-uuid".to_string()),
+            ("other".to_string(), ids[0].clone()),
+            (DEFAULT_COLLECTION.to_string(), ids[0].clone()),
+        ];
+
+        let owners = manager.batch_owners(documents).await.unwrap();
+        assert_eq!(owners[0].as_ref().unwrap().user_id, USER_ID);
+        assert!(owners[1].is_err());
+        assert!(matches!(owners[2], Err(abi::Error::NotFound)));
+        assert_eq!(owners[3].as_ref().unwrap().user_id, USER_ID);
+    }
+}
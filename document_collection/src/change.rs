@@ -0,0 +1,693 @@
+use abi::{Document, DocumentChange, DocumentQuery, FieldViolation, Filter, ReadChangesRequest};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures::StreamExt;
+use serde_json::{json, Value};
+use sqlx::{postgres::PgListener, types::Uuid};
+use tokio::sync::mpsc;
+use tracing::warn;
+
+use crate::{
+    filter::eval_filter, manager::collection_name, totals::fill_totals, Changes, DcManager,
+};
+
+/// channel dc.record_revision() notifies the sequence number of every change on
+const CHANGES_CHANNEL: &str = "dc_document_changes";
+
+/// select a revision as the change it recorded, shaped like a row of dc.documents
+const REVISION_AS_CHANGE: &str = "SELECT r.seq, r.deleted, r.document_id AS id, r.user_id, r.data, r.items, r.revision AS version,
+    r.document_created_at AS created_at, r.created_at AS updated_at, CASE WHEN r.deleted THEN r.created_at END AS deleted_at,
+    r.org_id, r.store_id, r.document_type, r.schema_version, r.collection
+    FROM dc.document_revisions r";
+
+/// select the committed changes no running transaction can add an earlier one to, in transaction
+/// order, after the change with the sequence $1 if it is not 0, of the organization $2 if set and in
+/// the collection $3 if not empty, at most $4
+pub(crate) fn read_changes_query() -> String {
+    format!(
+        "{} WHERE r.txid < pg_snapshot_xmin(pg_current_snapshot())
+        AND ($1 = 0 OR (r.txid, r.seq) > (SELECT s.txid, s.seq FROM dc.document_revisions s WHERE s.seq = $1))
+        AND ($2::UUID IS NULL OR r.org_id = $2) AND ($3 = '' OR r.collection = $3)
+        ORDER BY r.txid, r.seq LIMIT $4",
+        REVISION_AS_CHANGE
+    )
+}
+
+/// Committed changes `list_changes` holds back until an older transaction ends.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeldBack {
+    /// how many committed changes wait
+    pub changes: i64,
+    /// when the oldest of them was recorded
+    pub since: DateTime<Utc>,
+    /// the transaction they wait for, None if it is not run by a session, like a prepared one
+    pub blocking: Option<BlockingTransaction>,
+}
+
+/// The oldest running transaction of the server, in any database.
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow)]
+pub struct BlockingTransaction {
+    pub pid: i32,
+    pub database: Option<String>,
+    pub state: Option<String>,
+    pub started_at: Option<DateTime<Utc>>,
+    /// the statement it runs, or ran last if it is idle
+    pub query: Option<String>,
+}
+
+/// Receives the sequence numbers of changes as the transactions making them commit.
+pub struct ChangeListener {
+    listener: PgListener,
+}
+
+impl ChangeListener {
+    /// Wait for the next change, None if the connection was lost and changes may have been missed,
+    /// the listener reconnects on the next call.
+    pub async fn recv(&mut self) -> Result<Option<i64>, abi::Error> {
+        loop {
+            let Some(notification) = self.listener.try_recv().await? else {
+                return Ok(None);
+            };
+            match notification.payload().parse() {
+                Ok(sequence) => return Ok(Some(sequence)),
+                Err(_) => warn!("Ignoring change notification: {:?}", notification.payload()),
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Changes for DcManager {
+    async fn list_changes(
+        &self,
+        after_sequence: i64,
+        limit: i64,
+    ) -> Result<Vec<DocumentChange>, abi::Error> {
+        let changes: Vec<DocumentChange> = sqlx::query_as(&read_changes_query())
+            .bind(after_sequence)
+            .bind(None::<Uuid>)
+            .bind("")
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await?;
+        if changes.is_empty() {
+            check_after(&self.pool, "after_sequence", after_sequence).await?;
+        }
+        with_totals(changes)
+    }
+
+    async fn get_changes(&self, sequences: Vec<i64>) -> Result<Vec<DocumentChange>, abi::Error> {
+        let changes = sqlx::query_as(&format!(
+            "{} WHERE r.seq = ANY($1) ORDER BY array_position($1, r.seq)",
+            REVISION_AS_CHANGE
+        ))
+        .bind(sequences)
+        .fetch_all(&self.pool)
+        .await?;
+        with_totals(changes)
+    }
+
+    async fn latest_sequence(&self) -> Result<i64, abi::Error> {
+        let sequence: Option<i64> = sqlx::query_scalar(
+            "SELECT seq FROM dc.document_revisions WHERE txid < pg_snapshot_xmin(pg_current_snapshot())
+            ORDER BY txid DESC, seq DESC LIMIT 1",
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(sequence.unwrap_or_default())
+    }
+
+    async fn held_back(&self) -> Result<Option<HeldBack>, abi::Error> {
+        let mut tx = self.pool.begin().await?;
+        let (changes, since): (i64, Option<DateTime<Utc>>) = sqlx::query_as(
+            "SELECT COUNT(*), MIN(created_at) FROM dc.document_revisions
+            WHERE txid >= pg_snapshot_xmin(pg_current_snapshot())",
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+        let Some(since) = since else {
+            return Ok(None);
+        };
+        // the oldest transaction given an id, which is what the changes wait for
+        let blocking = sqlx::query_as(
+            "SELECT pid, datname AS database, state, xact_start AS started_at, query
+            FROM pg_stat_activity
+            WHERE backend_xid::TEXT::BIGINT = pg_snapshot_xmin(pg_current_snapshot())::TEXT::BIGINT % 4294967296",
+        )
+        .fetch_optional(&mut *tx)
+        .await?;
+        tx.commit().await?;
+
+        Ok(Some(HeldBack {
+            changes,
+            since,
+            blocking,
+        }))
+    }
+
+    async fn listen_changes(&self) -> Result<ChangeListener, abi::Error> {
+        let mut listener = PgListener::connect_with(&self.pool).await?;
+        listener.listen(CHANGES_CHANNEL).await?;
+        Ok(ChangeListener { listener })
+    }
+
+    async fn purge_changes(&self, recorded_before: DateTime<Utc>) -> Result<u64, abi::Error> {
+        // webhook deliveries are still to be created for the change the cursor points at and those
+        // after it, nothing is removed before they were looked at once
+        let result = sqlx::query(
+            "DELETE FROM dc.document_revisions r
+            WHERE r.created_at < $1
+            AND (r.txid, r.seq) < (
+                SELECT s.txid, s.seq FROM dc.webhook_cursor c JOIN dc.document_revisions s ON s.seq = c.since_seq
+            )
+            AND NOT EXISTS (SELECT 1 FROM dc.documents d WHERE d.id = r.document_id AND d.version = r.revision)",
+        )
+        .bind(recorded_before)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    async fn read_changes(
+        &self,
+        request: ReadChangesRequest,
+    ) -> mpsc::Receiver<Result<DocumentChange, abi::Error>> {
+        let pool = self.pool.clone();
+        let (tx, rx) = mpsc::channel(128);
+
+        let org_id = match parse_org_id(&request.org_id) {
+            Ok(org_id) => org_id,
+            Err(e) => {
+                // the channel is empty, so this can't fail
+                let _ = tx.try_send(Err(e));
+                return rx;
+            }
+        };
+        let limit = (request.limit > 0).then_some(i64::from(request.limit));
+
+        tokio::spawn(async move {
+            if let Err(e) = check_after(&pool, "since_seq", request.since_seq).await {
+                let _ = tx.send(Err(e)).await;
+                return;
+            }
+            let query = read_changes_query();
+            let mut changes = sqlx::query_as(&query)
+                .bind(request.since_seq)
+                .bind(org_id)
+                .bind(request.collection)
+                .bind(limit)
+                .fetch(&pool);
+            while let Some(ret) = changes.next().await {
+                let ret = ret
+                    .map_err(abi::Error::from)
+                    .and_then(|mut change: DocumentChange| {
+                        if let Some(document) = change.document.as_mut() {
+                            fill_totals(document)?;
+                        }
+                        Ok(change)
+                    });
+                if ret.is_err() {
+                    warn!("Read changes error: {:?}", ret);
+                }
+                if tx.send(ret).await.is_err() {
+                    // rx is dropped, so client disconnected
+                    break;
+                }
+            }
+        });
+        rx
+    }
+}
+
+/// a watcher or consumer can only resume after a change that exists, and was not removed for
+/// being older than the retention of changes
+async fn check_after(pool: &sqlx::PgPool, field: &str, sequence: i64) -> Result<(), abi::Error> {
+    if sequence == 0 {
+        return Ok(());
+    }
+    let exists: bool =
+        sqlx::query_scalar("SELECT EXISTS (SELECT 1 FROM dc.document_revisions WHERE seq = $1)")
+            .bind(sequence)
+            .fetch_one(pool)
+            .await?;
+    if exists {
+        Ok(())
+    } else {
+        Err(abi::Error::InvalidRequest(vec![FieldViolation {
+            field: field.to_string(),
+            description: format!("no change with sequence {}", sequence),
+        }]))
+    }
+}
+
+fn parse_org_id(org_id: &str) -> Result<Option<Uuid>, abi::Error> {
+    if org_id.is_empty() {
+        return Ok(None);
+    }
+    Uuid::parse_str(org_id)
+        .map(Some)
+        .map_err(|_| abi::Error::OrganizationNotFound)
+}
+
+fn with_totals(mut changes: Vec<DocumentChange>) -> Result<Vec<DocumentChange>, abi::Error> {
+    for document in changes.iter_mut().filter_map(|c| c.document.as_mut()) {
+        fill_totals(document)?;
+    }
+    Ok(changes)
+}
+
+/// Check a document matches the owner, collection, type and filter of a query,
+/// its time range and `include_deleted` are ignored.
+pub fn matches(query: &DocumentQuery, document: &Document) -> Result<bool, abi::Error> {
+    let same = |wanted: &str, actual: &str| wanted.is_empty() || wanted == actual;
+    if !same(&query.user_id, &document.user_id)
+        || !same(&query.org_id, &document.org_id)
+        || !same(&query.store_id, &document.store_id)
+        || !same(&query.document_type, &document.document_type)
+        || collection_name(query.collection.clone()) != document.collection
+    {
+        return Ok(false);
+    }
+
+    match &query.filter {
+        Some(filter) => {
+            let content = json!({ "data": document.data, "items": document.items });
+            eval_filter(filter, &content)
+        }
+        None => Ok(true),
+    }
+}
+
+/// Check a filter is valid without evaluating it against a document.
+pub fn check_filter(filter: &Filter) -> Result<(), abi::Error> {
+    eval_filter(filter, &Value::Null).map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use abi::ChangeType;
+    use chrono::Days;
+    use prost_wkt_types::Struct;
+    use sqlx::PgPool;
+
+    use super::*;
+    use crate::{
+        testing::{new_manager, USER_ID},
+        Collections, Dc, Webhooks, DEFAULT_COLLECTION,
+    };
+
+    async fn create_document(manager: &DcManager, collection: &str) -> Document {
+        manager
+            .create(
+                collection.to_string(),
+                USER_ID.to_string(),
+                Struct::default(),
+                vec![],
+                String::new(),
+            )
+            .await
+            .unwrap()
+    }
+
+    fn change_types(changes: &[DocumentChange]) -> Vec<ChangeType> {
+        changes.iter().map(|c| c.change_type()).collect()
+    }
+
+    async fn read_changes(
+        manager: &DcManager,
+        request: ReadChangesRequest,
+    ) -> Result<Vec<DocumentChange>, abi::Error> {
+        let mut rx = manager.read_changes(request).await;
+        let mut changes = vec![];
+        while let Some(change) = rx.recv().await {
+            changes.push(change?);
+        }
+        Ok(changes)
+    }
+
+    /// transaction ids are shared by every database of the server, so a transaction of a test
+    /// running alongside may hold changes back for a moment
+    async fn read_at_least(
+        manager: &DcManager,
+        request: ReadChangesRequest,
+        n: usize,
+    ) -> Vec<DocumentChange> {
+        for _ in 0..50 {
+            let changes = read_changes(manager, request.clone()).await.unwrap();
+            if changes.len() >= n {
+                return changes;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+        panic!("expected at least {} changes", n);
+    }
+
+    /// like `read_at_least`, changes are listed once every older transaction has ended
+    async fn list_at_least(manager: &DcManager, after: i64, n: usize) -> Vec<DocumentChange> {
+        for _ in 0..50 {
+            let changes = manager.list_changes(after, 10).await.unwrap();
+            if changes.len() >= n {
+                return changes;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+        panic!("expected at least {} changes", n);
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn every_mutation_should_be_listed_as_a_change(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let before = manager.latest_sequence().await.unwrap();
+        let document = create_document(&manager, DEFAULT_COLLECTION).await;
+        let collection = DEFAULT_COLLECTION.to_string();
+        manager
+            .update(
+                collection.clone(),
+                document.id.clone(),
+                Some(Struct::default()),
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        manager
+            .delete(collection.clone(), document.id.clone(), None)
+            .await
+            .unwrap();
+
+        let changes = list_at_least(&manager, before, 3).await;
+        assert_eq!(
+            change_types(&changes),
+            [
+                ChangeType::Created,
+                ChangeType::Updated,
+                ChangeType::Deleted
+            ]
+        );
+        assert!(changes.windows(2).all(|w| w[0].sequence < w[1].sequence));
+        let deleted = changes[2].document.as_ref().unwrap();
+        assert_eq!(deleted.id, document.id);
+        assert_eq!(deleted.version, 3);
+        assert!(deleted.deleted_at.is_some());
+        assert_eq!(
+            manager.latest_sequence().await.unwrap(),
+            changes[2].sequence
+        );
+
+        let resumed = list_at_least(&manager, changes[0].sequence, 2).await;
+        assert_eq!(
+            change_types(&resumed),
+            [ChangeType::Updated, ChangeType::Deleted]
+        );
+
+        let sequences = vec![changes[2].sequence, changes[0].sequence];
+        let picked = manager.get_changes(sequences.clone()).await.unwrap();
+        let picked: Vec<i64> = picked.iter().map(|c| c.sequence).collect();
+        assert_eq!(picked, sequences);
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn listener_should_receive_committed_changes(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let mut listener = manager.listen_changes().await.unwrap();
+        create_document(&manager, DEFAULT_COLLECTION).await;
+
+        let sequence = listener.recv().await.unwrap().unwrap();
+        assert_eq!(sequence, list_at_least(&manager, 0, 1).await[0].sequence);
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn changes_should_wait_for_older_transactions_to_end(pool: PgPool) {
+        let manager = new_manager(pool.clone()).await;
+        let mut tx = pool.begin().await.unwrap();
+        let first = manager
+            .create_in(
+                &mut tx,
+                DEFAULT_COLLECTION.to_string(),
+                USER_ID.to_string(),
+                Struct::default(),
+                vec![],
+                String::new(),
+            )
+            .await
+            .unwrap();
+        // committed first, though its sequence comes after the one of the open transaction
+        let second = create_document(&manager, DEFAULT_COLLECTION).await;
+        assert!(manager.list_changes(0, 10).await.unwrap().is_empty());
+        assert_eq!(manager.latest_sequence().await.unwrap(), 0);
+
+        tx.commit().await.unwrap();
+        let changes = list_at_least(&manager, 0, 2).await;
+        let ids: Vec<&str> = changes
+            .iter()
+            .map(|c| c.document.as_ref().unwrap().id.as_str())
+            .collect();
+        assert_eq!(ids, [first.id.as_str(), second.id.as_str()]);
+        let resumed = manager.list_changes(changes[0].sequence, 10).await.unwrap();
+        assert_eq!(resumed, changes[1..]);
+
+        let err = manager.list_changes(i64::MAX, 10).await.unwrap_err();
+        assert!(matches!(err, abi::Error::InvalidRequest(_)));
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn changes_should_match_the_collection_and_filter_of_a_query(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        manager
+            .create_collection(abi::Collection {
+                name: "invoices".to_string(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        let invoice = create_document(&manager, "invoices").await;
+        let document = create_document(&manager, DEFAULT_COLLECTION).await;
+
+        let query = DocumentQuery {
+            user_id: USER_ID.to_string(),
+            ..Default::default()
+        };
+        assert!(matches(&query, &document).unwrap());
+        assert!(!matches(&query, &invoice).unwrap());
+
+        let query = DocumentQuery {
+            collection: "invoices".to_string(),
+            store_id: Uuid::nil().to_string(),
+            ..Default::default()
+        };
+        assert!(!matches(&query, &invoice).unwrap());
+
+        let query = DocumentQuery {
+            collection: "invoices".to_string(),
+            filter: Some(Filter {
+                filter: Some(abi::filter::Filter::Field(abi::FieldFilter {
+                    path: "data.missing".to_string(),
+                    op: abi::FilterOperator::Exists as i32,
+                    value: None,
+                })),
+            }),
+            ..Default::default()
+        };
+        assert!(!matches(&query, &invoice).unwrap());
+        assert!(check_filter(query.filter.as_ref().unwrap()).is_ok());
+        assert!(check_filter(&Filter::default()).is_err());
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn read_changes_should_resume_after_a_change(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let document = create_document(&manager, DEFAULT_COLLECTION).await;
+        let collection = DEFAULT_COLLECTION.to_string();
+        manager
+            .update(
+                collection.clone(),
+                document.id.clone(),
+                Some(Struct::default()),
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        manager
+            .delete(collection, document.id.clone(), None)
+            .await
+            .unwrap();
+
+        let changes = read_at_least(&manager, ReadChangesRequest::default(), 3).await;
+        assert_eq!(
+            change_types(&changes),
+            [
+                ChangeType::Created,
+                ChangeType::Updated,
+                ChangeType::Deleted
+            ]
+        );
+        assert_eq!(changes[1].document.as_ref().unwrap().id, document.id);
+        // numbered like the changes a watch lists
+        assert_eq!(list_at_least(&manager, 0, 3).await, changes);
+
+        let resumed = ReadChangesRequest {
+            since_seq: changes[0].sequence,
+            limit: 1,
+            ..Default::default()
+        };
+        let resumed = read_changes(&manager, resumed).await.unwrap();
+        assert_eq!(change_types(&resumed), [ChangeType::Updated]);
+
+        let other_collection = ReadChangesRequest {
+            collection: "invoices".to_string(),
+            ..Default::default()
+        };
+        assert!(read_changes(&manager, other_collection)
+            .await
+            .unwrap()
+            .is_empty());
+
+        let unknown = ReadChangesRequest {
+            since_seq: changes[2].sequence + 100,
+            ..Default::default()
+        };
+        let err = read_changes(&manager, unknown).await.unwrap_err();
+        assert!(matches!(err, abi::Error::InvalidRequest(_)));
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn read_changes_should_wait_for_earlier_transactions(pool: PgPool) {
+        let manager = new_manager(pool.clone()).await;
+        let mut tx = pool.begin().await.unwrap();
+        sqlx::query(
+            "INSERT INTO dc.documents (user_id, org_id, store_id, data, items)
+            SELECT id, org_id, store_id, '{}', '[]' FROM dc.users WHERE id = $1",
+        )
+        .bind(Uuid::parse_str(USER_ID).unwrap())
+        .execute(&mut *tx)
+        .await
+        .unwrap();
+
+        // committed, but after a change of a transaction that is still running
+        let later = create_document(&manager, DEFAULT_COLLECTION).await;
+        let changes = read_changes(&manager, ReadChangesRequest::default())
+            .await
+            .unwrap();
+        assert!(changes.is_empty());
+
+        tx.commit().await.unwrap();
+        let changes = read_at_least(&manager, ReadChangesRequest::default(), 2).await;
+        let ids: Vec<&str> = changes
+            .iter()
+            .map(|c| c.document.as_ref().unwrap().id.as_str())
+            .collect();
+        assert_ne!(ids[0], later.id);
+        assert_eq!(ids[1], later.id);
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn purge_changes_should_keep_current_revisions_and_changes_not_delivered(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let collection = DEFAULT_COLLECTION.to_string();
+        let kept = create_document(&manager, &collection).await;
+        manager
+            .update(
+                collection.clone(),
+                kept.id.clone(),
+                Some(Struct::default()),
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        let purged = create_document(&manager, &collection).await;
+        manager
+            .delete(collection.clone(), purged.id.clone(), None)
+            .await
+            .unwrap();
+        let tomorrow = Utc::now() + Days::new(1);
+        manager.purge(tomorrow).await.unwrap();
+        let changes = list_at_least(&manager, 0, 4).await;
+
+        // no deliveries were created yet
+        assert_eq!(manager.purge_changes(tomorrow).await.unwrap(), 0);
+
+        let mut looked_at = 0;
+        for _ in 0..50 {
+            looked_at += manager.enqueue_deliveries(10).await.unwrap();
+            if looked_at >= 4 {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+        assert_eq!(looked_at, 4);
+        let updated = manager
+            .update(
+                collection.clone(),
+                kept.id.clone(),
+                Some(Struct::default()),
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        // the first two revisions of the kept document and the creation of the purged one, the
+        // deletion is where the webhook cursor stands
+        assert_eq!(manager.purge_changes(tomorrow).await.unwrap(), 3);
+        let revisions = manager
+            .list_revisions(collection.clone(), kept.id.clone())
+            .await
+            .unwrap();
+        let revisions: Vec<i64> = revisions.iter().map(|r| r.revision).collect();
+        assert_eq!(revisions, [updated.version]);
+
+        let remaining = list_at_least(&manager, 0, 2).await;
+        assert_eq!(remaining[0], changes[3]);
+        let created_at = &remaining[1].document.as_ref().unwrap().created_at;
+        assert_eq!(created_at, &kept.created_at);
+
+        let err = manager
+            .list_changes(changes[0].sequence, 10)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, abi::Error::InvalidRequest(_)));
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn held_back_changes_should_name_the_transaction_they_wait_for(pool: PgPool) {
+        let manager = new_manager(pool.clone()).await;
+        let mut tx = pool.begin().await.unwrap();
+        // a transaction given an id holds back later changes, though it changed no document
+        let pid: i32 = sqlx::query_scalar("SELECT pg_backend_pid() FROM pg_current_xact_id()")
+            .fetch_one(&mut *tx)
+            .await
+            .unwrap();
+        let document = create_document(&manager, DEFAULT_COLLECTION).await;
+        assert!(manager.list_changes(0, 10).await.unwrap().is_empty());
+
+        // a transaction of a test running alongside may be older for a moment
+        let mut held = None;
+        for _ in 0..50 {
+            held = manager.held_back().await.unwrap();
+            if held
+                .as_ref()
+                .and_then(|h| h.blocking.as_ref())
+                .map(|t| t.pid)
+                == Some(pid)
+            {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+        let held = held.unwrap();
+        let blocking = held.blocking.unwrap();
+        assert_eq!(blocking.pid, pid);
+        assert_eq!(blocking.state.as_deref(), Some("idle in transaction"));
+        assert_eq!(held.changes, 1);
+        assert!(held.since <= Utc::now());
+
+        tx.commit().await.unwrap();
+        let changes = list_at_least(&manager, 0, 1).await;
+        assert_eq!(changes[0].document.as_ref().unwrap().id, document.id);
+    }
+}
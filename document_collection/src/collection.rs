@@ -0,0 +1,641 @@
+use std::time::Duration;
+
+use abi::Collection;
+use async_trait::async_trait;
+use sqlx::{pool::PoolConnection, Acquire, PgConnection, Postgres};
+use tracing::warn;
+
+use crate::{filter::parse_path, Collections, DcManager, DEFAULT_COLLECTION};
+
+/// wait before trying again to lock a collection another server creates or drops
+const LOCK_RETRY_DELAY: Duration = Duration::from_millis(100);
+
+#[async_trait]
+impl Collections for DcManager {
+    async fn create_collection(&self, collection: Collection) -> Result<Collection, abi::Error> {
+        check_name(&collection.name)?;
+        for (i, path) in collection.indexes.iter().enumerate() {
+            index_definition(&collection.name, i, path)?;
+        }
+        // run by a task of its own, so a cancelled request does not leave the collection pending
+        let manager = self.clone();
+        tokio::spawn(async move { manager.create_locked(collection).await })
+            .await
+            .map_err(join_error)?
+    }
+
+    async fn list_collections(&self) -> Result<Vec<Collection>, abi::Error> {
+        let collections =
+            sqlx::query_as("SELECT * FROM dc.collections WHERE state <> 'dropping' ORDER BY name")
+                .fetch_all(&self.pool)
+                .await?;
+        Ok(collections)
+    }
+
+    async fn drop_collection(&self, name: String, force: bool) -> Result<Collection, abi::Error> {
+        if name == DEFAULT_COLLECTION {
+            return Err(abi::Error::InvalidCollection(
+                "the default collection can't be dropped".to_string(),
+            ));
+        }
+        let manager = self.clone();
+        tokio::spawn(async move { manager.drop_locked(name, force).await })
+            .await
+            .map_err(join_error)?
+    }
+
+    async fn repair_collections(&self) -> Result<usize, abi::Error> {
+        let names: Vec<String> =
+            sqlx::query_scalar("SELECT name FROM dc.collections WHERE state <> 'ready'")
+                .fetch_all(&self.pool)
+                .await?;
+        let mut repaired = 0;
+        for name in names {
+            // another server is still creating or dropping it
+            let Some(mut conn) = self.try_lock(&name).await? else {
+                continue;
+            };
+            let result = repair(&mut conn, &name).await;
+            unlock(conn).await;
+            if result? {
+                repaired += 1;
+            }
+        }
+        Ok(repaired)
+    }
+}
+
+impl DcManager {
+    /// insert a pending collection, build its indexes and mark it ready
+    async fn create_locked(&self, collection: Collection) -> Result<Collection, abi::Error> {
+        let mut conn = self.lock(&collection.name).await?;
+        let result = create_in(&mut conn, collection).await;
+        unlock(conn).await;
+        result
+    }
+
+    /// delete the documents of a collection, then drop its indexes and the collection itself
+    async fn drop_locked(&self, name: String, force: bool) -> Result<Collection, abi::Error> {
+        let mut conn = self.lock(&name).await?;
+        let result = drop_in(&mut conn, name, force).await;
+        unlock(conn).await;
+        result
+    }
+
+    /// A connection holding the lock of a collection, so only one server creates, drops or repairs
+    /// it at a time. The lock is only tried, as waiting for it in the database could deadlock with
+    /// the concurrent index build of the holder.
+    async fn lock(&self, name: &str) -> Result<PoolConnection<Postgres>, abi::Error> {
+        loop {
+            if let Some(conn) = self.try_lock(name).await? {
+                return Ok(conn);
+            }
+            tokio::time::sleep(LOCK_RETRY_DELAY).await;
+        }
+    }
+
+    async fn try_lock(&self, name: &str) -> Result<Option<PoolConnection<Postgres>>, abi::Error> {
+        let mut conn = self.pool.acquire().await?;
+        let locked: bool = sqlx::query_scalar(
+            "SELECT pg_try_advisory_lock(hashtextextended('dc.collections/' || $1, 0))",
+        )
+        .bind(name)
+        .fetch_one(&mut *conn)
+        .await?;
+        Ok(locked.then_some(conn))
+    }
+}
+
+/// Release the lock of a collection by closing its connection, collections are created and
+/// dropped rarely, and a failed unlock can't leave a pooled connection holding the lock.
+async fn unlock(conn: PoolConnection<Postgres>) {
+    if let Err(e) = conn.close().await {
+        warn!(
+            "Closing the connection of a collection lock failed: {:?}",
+            e
+        );
+    }
+}
+
+async fn create_in(
+    conn: &mut PgConnection,
+    collection: Collection,
+) -> Result<Collection, abi::Error> {
+    let created: Collection = sqlx::query_as(
+        "INSERT INTO dc.collections (name, document_type, retention_days, indexes, state)
+        VALUES ($1, $2, $3, $4, 'pending') ON CONFLICT (name) DO NOTHING RETURNING *",
+    )
+    .bind(&collection.name)
+    .bind(&collection.document_type)
+    .bind(collection.retention_days)
+    .bind(&collection.indexes)
+    .fetch_optional(&mut *conn)
+    .await?
+    .ok_or_else(|| abi::Error::CollectionExists(collection.name.clone()))?;
+    build(conn, &created).await?;
+    Ok(created)
+}
+
+/// Build the indexes of a pending collection concurrently, outside any transaction so writes go
+/// on, and mark it ready. The collection is removed again if an index fails to build.
+async fn build(conn: &mut PgConnection, collection: &Collection) -> Result<(), abi::Error> {
+    for (i, path) in collection.indexes.iter().enumerate() {
+        let index = index_definition(&collection.name, i, path)?;
+        if let Err(e) = sqlx::query(&index).execute(&mut *conn).await {
+            // a failed concurrent build leaves an invalid index behind
+            drop_indexes(conn, &collection.name, i + 1).await?;
+            remove(conn, &collection.name).await?;
+            return Err(e.into());
+        }
+    }
+    sqlx::query("UPDATE dc.collections SET state = 'ready' WHERE name = $1")
+        .bind(&collection.name)
+        .execute(&mut *conn)
+        .await?;
+    Ok(())
+}
+
+async fn drop_in(
+    conn: &mut PgConnection,
+    name: String,
+    force: bool,
+) -> Result<Collection, abi::Error> {
+    let mut tx = conn.begin().await?;
+    let collection: Collection = sqlx::query_as(
+        "SELECT * FROM dc.collections WHERE name = $1 AND state <> 'dropping' FOR UPDATE",
+    )
+    .bind(&name)
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or_else(|| abi::Error::CollectionNotFound(name.clone()))?;
+
+    let documents: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM dc.documents WHERE collection = $1")
+            .bind(&name)
+            .fetch_one(&mut *tx)
+            .await?;
+    if documents > 0 && !force {
+        return Err(abi::Error::CollectionNotEmpty(name));
+    }
+    // no document is written to a collection being dropped
+    sqlx::query("UPDATE dc.collections SET state = 'dropping' WHERE name = $1")
+        .bind(&name)
+        .execute(&mut *tx)
+        .await?;
+    // deleting the documents records the change of each, as deleting them one by one would
+    delete_documents(&mut tx, &name).await?;
+    tx.commit().await?;
+
+    finish_drop(conn, &collection).await?;
+    Ok(collection)
+}
+
+/// drop the indexes of a collection being dropped concurrently, then the collection itself
+async fn finish_drop(conn: &mut PgConnection, collection: &Collection) -> Result<(), abi::Error> {
+    drop_indexes(conn, &collection.name, collection.indexes.len()).await?;
+    remove(conn, &collection.name).await
+}
+
+/// Finish what a server creating or dropping a collection did not, return whether there was
+/// anything to finish. A pending collection gets its indexes built again.
+async fn repair(conn: &mut PgConnection, name: &str) -> Result<bool, abi::Error> {
+    let state: Option<String> =
+        sqlx::query_scalar("SELECT state FROM dc.collections WHERE name = $1")
+            .bind(name)
+            .fetch_optional(&mut *conn)
+            .await?;
+    let Some(state) = state.filter(|state| state != "ready") else {
+        return Ok(false);
+    };
+    let collection: Collection = sqlx::query_as("SELECT * FROM dc.collections WHERE name = $1")
+        .bind(name)
+        .fetch_one(&mut *conn)
+        .await?;
+    match state.as_str() {
+        "pending" => {
+            drop_indexes(conn, name, collection.indexes.len()).await?;
+            build(conn, &collection).await?;
+        }
+        _ => {
+            // documents a write that checked the collection before it was dropping added since
+            delete_documents(conn, name).await?;
+            finish_drop(conn, &collection).await?;
+        }
+    }
+    Ok(true)
+}
+
+async fn drop_indexes(conn: &mut PgConnection, name: &str, count: usize) -> Result<(), abi::Error> {
+    for i in 0..count {
+        sqlx::query(&format!(
+            "DROP INDEX CONCURRENTLY IF EXISTS dc.{}",
+            index_name(name, i)
+        ))
+        .execute(&mut *conn)
+        .await?;
+    }
+    Ok(())
+}
+
+async fn delete_documents(conn: &mut PgConnection, name: &str) -> Result<(), abi::Error> {
+    sqlx::query("DELETE FROM dc.documents WHERE collection = $1")
+        .bind(name)
+        .execute(&mut *conn)
+        .await?;
+    Ok(())
+}
+
+async fn remove(conn: &mut PgConnection, name: &str) -> Result<(), abi::Error> {
+    sqlx::query("DELETE FROM dc.collections WHERE name = $1")
+        .bind(name)
+        .execute(&mut *conn)
+        .await?;
+    Ok(())
+}
+
+fn join_error(e: tokio::task::JoinError) -> abi::Error {
+    warn!("Collection task failed: {:?}", e);
+    abi::Error::Unknown
+}
+
+/// names end up in index names and definitions, so only plain names are accepted
+fn check_name(name: &str) -> Result<(), abi::Error> {
+    let plain = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+    if plain {
+        Ok(())
+    } else {
+        Err(abi::Error::InvalidCollection(format!(
+            "invalid name: {:?}",
+            name
+        )))
+    }
+}
+
+fn index_name(collection: &str, i: usize) -> String {
+    format!("documents_{}_{}", collection, i)
+}
+
+/// a partial index over the documents of the collection, on the same expression filters use
+fn index_definition(collection: &str, i: usize, path: &str) -> Result<String, abi::Error> {
+    let (column, segments) = parse_path(path).map_err(abi::Error::InvalidCollection)?;
+    let plain = !segments.is_empty()
+        && segments
+            .iter()
+            .all(|s| s.chars().all(|c| c.is_ascii_alphanumeric() || c == '_'));
+    if !plain {
+        return Err(abi::Error::InvalidCollection(format!(
+            "invalid index path: {:?}",
+            path
+        )));
+    }
+    Ok(format!(
+        "CREATE INDEX CONCURRENTLY {} ON dc.documents (({} #> '{{{}}}')) WHERE collection = '{}'",
+        index_name(collection, i),
+        column,
+        segments.join(","),
+        collection
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use abi::DocumentQuery;
+    use chrono::{Days, Utc};
+    use prost_wkt_types::Struct;
+    use sqlx::{types::Uuid, PgPool};
+
+    use super::*;
+    use crate::{
+        testing::{new_manager, USER_ID},
+        Dc,
+    };
+
+    fn collection(name: &str) -> Collection {
+        Collection {
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    async fn create_document(manager: &DcManager, collection: &str) -> abi::Document {
+        manager
+            .create(
+                collection.to_string(),
+                USER_ID.to_string(),
+                Struct::default(),
+                vec![],
+                String::new(),
+            )
+            .await
+            .unwrap()
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn create_collection_should_add_its_indexes(pool: PgPool) {
+        let manager = new_manager(pool.clone()).await;
+        let invoices = Collection {
+            indexes: vec!["data.store".to_string(), "data.customer.id".to_string()],
+            ..collection("invoices")
+        };
+        manager.create_collection(invoices.clone()).await.unwrap();
+
+        let indexes: Vec<String> = sqlx::query_scalar(
+            "SELECT indexname FROM pg_indexes WHERE indexname LIKE 'documents_invoices_%' ORDER BY indexname",
+        )
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+        assert_eq!(indexes, ["documents_invoices_0", "documents_invoices_1"]);
+        let invalid: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM pg_index JOIN pg_class ON pg_class.oid = indexrelid
+            WHERE relname LIKE 'documents_invoices_%' AND NOT indisvalid",
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(invalid, 0);
+
+        let err = manager.create_collection(invoices).await.unwrap_err();
+        assert!(matches!(err, abi::Error::CollectionExists(_)));
+        let names: Vec<String> = manager
+            .list_collections()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|c| c.name)
+            .collect();
+        assert_eq!(names, ["documents", "invoices"]);
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn create_collection_should_be_undone_if_an_index_fails(pool: PgPool) {
+        let manager = new_manager(pool.clone()).await;
+        sqlx::query("CREATE INDEX documents_invoices_1 ON dc.documents (id)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let invoices = Collection {
+            indexes: vec!["data.store".to_string(), "data.customer.id".to_string()],
+            ..collection("invoices")
+        };
+        manager
+            .create_collection(invoices.clone())
+            .await
+            .unwrap_err();
+
+        let indexes: Vec<String> = sqlx::query_scalar(
+            "SELECT indexname FROM pg_indexes WHERE indexname LIKE 'documents_invoices_%'",
+        )
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+        assert!(indexes.is_empty());
+        let names: Vec<String> = manager
+            .list_collections()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|c| c.name)
+            .collect();
+        assert_eq!(names, ["documents"]);
+        manager.create_collection(invoices).await.unwrap();
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn documents_should_be_kept_apart_by_collection(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        manager
+            .create_collection(collection("invoices"))
+            .await
+            .unwrap();
+        let invoice = create_document(&manager, "invoices").await;
+        create_document(&manager, "").await;
+        assert_eq!(invoice.collection, "invoices");
+
+        let err = manager
+            .get(String::new(), invoice.id.clone(), false)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, abi::Error::NotFound));
+        let found = manager
+            .get("invoices".to_string(), invoice.id.clone(), false)
+            .await
+            .unwrap();
+        assert_eq!(found.id, invoice.id);
+
+        let page = manager
+            .list(abi::QueryRequest {
+                query: Some(DocumentQuery {
+                    collection: "invoices".to_string(),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(page.documents.len(), 1);
+
+        let err = manager
+            .create(
+                "stock_counts".to_string(),
+                USER_ID.to_string(),
+                Struct::default(),
+                vec![],
+                String::new(),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, abi::Error::CollectionNotFound(_)));
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn typed_collection_should_only_take_its_type(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let notes = Collection {
+            document_type: "delivery_note".to_string(),
+            ..collection("delivery_notes")
+        };
+        manager.create_collection(notes).await.unwrap();
+
+        let err = manager
+            .create(
+                "delivery_notes".to_string(),
+                USER_ID.to_string(),
+                Struct::default(),
+                vec![],
+                "invoice".to_string(),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, abi::Error::InvalidCollection(_)));
+        // the collection's type applies, and the test user's organization has no schema for it
+        let err = manager
+            .create(
+                "delivery_notes".to_string(),
+                USER_ID.to_string(),
+                Struct::default(),
+                vec![],
+                String::new(),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, abi::Error::SchemaNotFound(_)));
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn drop_collection_should_need_force_for_documents(pool: PgPool) {
+        let manager = new_manager(pool.clone()).await;
+        let invoices = Collection {
+            indexes: vec!["data.store".to_string()],
+            ..collection("invoices")
+        };
+        manager.create_collection(invoices).await.unwrap();
+        let invoice = create_document(&manager, "invoices").await;
+
+        let err = manager
+            .drop_collection("invoices".to_string(), false)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, abi::Error::CollectionNotEmpty(_)));
+        let err = manager
+            .drop_collection(DEFAULT_COLLECTION.to_string(), true)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, abi::Error::InvalidCollection(_)));
+
+        manager
+            .drop_collection("invoices".to_string(), true)
+            .await
+            .unwrap();
+        // the deletion is recorded like any other, so the change log has no hole
+        let revisions = manager
+            .list_revisions("invoices".to_string(), invoice.id.clone())
+            .await
+            .unwrap();
+        assert!(revisions.last().unwrap().deleted);
+        let err = manager
+            .get("invoices".to_string(), invoice.id, true)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, abi::Error::NotFound));
+        let indexes: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM pg_indexes WHERE indexname LIKE 'documents_invoices_%'",
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(indexes, 0);
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn cancelled_create_collection_should_still_finish(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let invoices = Collection {
+            indexes: vec!["data.store".to_string()],
+            ..collection("invoices")
+        };
+        // polled once, so its task is started, then given up on
+        let cancelled =
+            tokio::time::timeout(Duration::ZERO, manager.create_collection(invoices)).await;
+        assert!(cancelled.is_err());
+
+        for _ in 0..50 {
+            let state: Option<String> =
+                sqlx::query_scalar("SELECT state FROM dc.collections WHERE name = 'invoices'")
+                    .fetch_optional(&manager.pool)
+                    .await
+                    .unwrap();
+            if state.as_deref() == Some("ready") {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+        panic!("the collection never got ready");
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn repair_collections_should_finish_what_was_left_midway(pool: PgPool) {
+        let manager = new_manager(pool.clone()).await;
+        // as left by servers that stopped while building and dropping indexes
+        sqlx::query(
+            "INSERT INTO dc.collections (name, indexes, state) VALUES
+            ('invoices', '{data.store}', 'pending'), ('drafts', '{data.store}', 'dropping')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        let draft = create_document(&manager, DEFAULT_COLLECTION).await;
+        sqlx::query("UPDATE dc.documents SET collection = 'drafts' WHERE id = $1")
+            .bind(Uuid::parse_str(&draft.id).unwrap())
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query(&index_definition("drafts", 0, "data.store").unwrap())
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        assert_eq!(manager.repair_collections().await.unwrap(), 2);
+        assert_eq!(manager.repair_collections().await.unwrap(), 0);
+
+        let indexes: Vec<String> = sqlx::query_scalar(
+            "SELECT indexname FROM pg_indexes WHERE indexname LIKE 'documents_%_0' ORDER BY indexname",
+        )
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+        assert_eq!(indexes, ["documents_invoices_0"]);
+        let states: Vec<(String, String)> =
+            sqlx::query_as("SELECT name, state FROM dc.collections ORDER BY name")
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+        assert_eq!(
+            states,
+            [
+                ("documents".to_string(), "ready".to_string()),
+                ("invoices".to_string(), "ready".to_string())
+            ]
+        );
+        let err = manager
+            .get("drafts".to_string(), draft.id, true)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, abi::Error::NotFound));
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn purge_should_honour_collection_retention(pool: PgPool) {
+        let manager = new_manager(pool.clone()).await;
+        let short = Collection {
+            retention_days: 1,
+            ..collection("drafts")
+        };
+        manager.create_collection(short).await.unwrap();
+        let draft = create_document(&manager, "drafts").await;
+        let document = create_document(&manager, "").await;
+        for (collection, id) in [("drafts", &draft.id), ("", &document.id)] {
+            manager
+                .delete(collection.to_string(), id.clone(), None)
+                .await
+                .unwrap();
+        }
+        sqlx::query("UPDATE dc.documents SET deleted_at = NOW() - INTERVAL '2 days'")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        // the default collection keeps its trash for the configured 30 days
+        let purged = manager.purge(Utc::now() - Days::new(30)).await.unwrap();
+        assert_eq!(purged, 1);
+        let err = manager
+            .get("drafts".to_string(), draft.id, true)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, abi::Error::NotFound));
+        assert!(manager.get(String::new(), document.id, true).await.is_ok());
+    }
+}
@@ -0,0 +1,557 @@
+use std::cmp::Ordering;
+
+use abi::{filter, FieldFilter, Filter, FilterOperator};
+use serde_json::{Map, Value};
+use sqlx::{Postgres, QueryBuilder};
+
+/// deepest and/or nesting accepted in a filter
+const MAX_FILTER_DEPTH: usize = 8;
+
+/// jsonb columns a filter path may start with
+const FILTER_COLUMNS: [&str; 2] = ["data", "items"];
+
+/// append the filter as a parameterized sql predicate
+pub(crate) fn push_filter(
+    builder: &mut QueryBuilder<'_, Postgres>,
+    filter: &Filter,
+) -> Result<(), abi::Error> {
+    push_filter_at(builder, filter, 0)
+}
+
+fn push_filter_at(
+    builder: &mut QueryBuilder<'_, Postgres>,
+    filter: &Filter,
+    depth: usize,
+) -> Result<(), abi::Error> {
+    if depth > MAX_FILTER_DEPTH {
+        return Err(invalid_filter("nested too deeply"));
+    }
+
+    match &filter.filter {
+        Some(filter::Filter::Field(field)) => push_field_filter(builder, field),
+        Some(filter::Filter::And(list)) => {
+            push_filter_list(builder, &list.filters, " AND ", "TRUE", depth)
+        }
+        Some(filter::Filter::Or(list)) => {
+            push_filter_list(builder, &list.filters, " OR ", "FALSE", depth)
+        }
+        None => Err(invalid_filter("empty filter")),
+    }
+}
+
+fn push_filter_list(
+    builder: &mut QueryBuilder<'_, Postgres>,
+    filters: &[Filter],
+    separator: &str,
+    empty: &str,
+    depth: usize,
+) -> Result<(), abi::Error> {
+    if filters.is_empty() {
+        builder.push(empty);
+        return Ok(());
+    }
+
+    builder.push("(");
+    for (i, filter) in filters.iter().enumerate() {
+        if i > 0 {
+            builder.push(separator);
+        }
+        push_filter_at(builder, filter, depth + 1)?;
+    }
+    builder.push(")");
+    Ok(())
+}
+
+fn push_field_filter(
+    builder: &mut QueryBuilder<'_, Postgres>,
+    field: &FieldFilter,
+) -> Result<(), abi::Error> {
+    let (column, path) = parse_path(&field.path).map_err(invalid_filter)?;
+    let op = FilterOperator::try_from(field.op)
+        .map_err(|_| invalid_filter(format!("unknown operator: {}", field.op)))?;
+    let value = match &field.value {
+        Some(value) => serde_json::to_value(value).map_err(invalid_filter)?,
+        None => Value::Null,
+    };
+
+    if let (FilterOperator::Lt | FilterOperator::Gt, Value::String(text)) = (op, &value) {
+        let op = if op == FilterOperator::Lt {
+            " < "
+        } else {
+            " > "
+        };
+        push_text_comparison(builder, column, path, op, text.clone());
+        return Ok(());
+    }
+
+    let prefilter = containing(op, &path, &value);
+    if let Some(document) = prefilter.clone() {
+        builder.push(format!("({} @> ", column));
+        builder.push_bind(document);
+        builder.push("::JSONB AND ");
+    }
+    builder.push(format!("({} #> ", column));
+    builder.push_bind(path);
+    builder.push("::TEXT[])");
+    match op {
+        FilterOperator::Eq => push_comparison(builder, " = ", value),
+        FilterOperator::Ne => push_comparison(builder, " <> ", value),
+        FilterOperator::Lt => push_comparison(builder, " < ", value),
+        FilterOperator::Gt => push_comparison(builder, " > ", value),
+        FilterOperator::Contains => push_comparison(builder, " @> ", value),
+        FilterOperator::In => {
+            if !value.is_array() {
+                return Err(invalid_filter("in requires a list value"));
+            }
+            builder.push(" IN (SELECT jsonb_array_elements(");
+            builder.push_bind(value);
+            builder.push("::JSONB))");
+        }
+        FilterOperator::Exists => {
+            builder.push(" IS NOT NULL");
+        }
+        FilterOperator::Unspecified => return Err(invalid_filter("missing operator")),
+    }
+    if prefilter.is_some() {
+        builder.push(")");
+    }
+    Ok(())
+}
+
+/// a document every document matching the filter contains, for the gin index of the column to
+/// narrow them down before the filter itself is checked
+fn containing(op: FilterOperator, path: &[String], value: &Value) -> Option<Value> {
+    let contained = match op {
+        FilterOperator::Eq => true,
+        // a scalar is only contained in an array at the top level
+        FilterOperator::Contains => value.is_object() || value.is_array(),
+        _ => false,
+    };
+    // `#>` also steps into arrays by index, containment only into objects
+    if !contained || path.iter().any(|s| s.parse::<usize>().is_ok()) {
+        return None;
+    }
+    Some(path.iter().rev().fold(value.clone(), |value, segment| {
+        Value::Object(Map::from_iter([(segment.clone(), value)]))
+    }))
+}
+
+/// split a dot separated path into its jsonb column and the path inside it
+pub(crate) fn parse_path(path: &str) -> Result<(&'static str, Vec<String>), String> {
+    let mut segments = path.split('.');
+    let column = segments
+        .next()
+        .and_then(|c| FILTER_COLUMNS.into_iter().find(|column| *column == c))
+        .ok_or_else(|| format!("path must start with data or items: {}", path))?;
+    let segments: Vec<String> = segments.map(|s| s.to_string()).collect();
+    if segments.iter().any(|s| s.is_empty()) {
+        return Err(format!("empty segment in path: {}", path));
+    }
+    Ok((column, segments))
+}
+
+fn push_comparison(builder: &mut QueryBuilder<'_, Postgres>, op: &str, value: Value) {
+    builder.push(op);
+    builder.push_bind(value);
+    builder.push("::JSONB");
+}
+
+/// jsonb compares strings by the collation of the database, byte by byte like `eval_filter` only
+/// if compared as text in the "C" collation, values of other types order the same either way
+fn push_text_comparison(
+    builder: &mut QueryBuilder<'_, Postgres>,
+    column: &str,
+    path: Vec<String>,
+    op: &str,
+    text: String,
+) {
+    builder.push(format!("(CASE WHEN jsonb_typeof({} #> ", column));
+    builder.push_bind(path.clone());
+    builder.push(format!("::TEXT[]) = 'string' THEN ({} #>> ", column));
+    builder.push_bind(path.clone());
+    builder.push("::TEXT[]) COLLATE \"C\"");
+    builder.push(op);
+    builder.push_bind(text.clone());
+    builder.push(format!(" ELSE ({} #> ", column));
+    builder.push_bind(path);
+    builder.push("::TEXT[])");
+    push_comparison(builder, op, Value::String(text));
+    builder.push(" END)");
+}
+
+/// evaluate the filter against the `data` and `items` of a document in memory,
+/// the same way its sql predicate is evaluated by the database
+pub(crate) fn eval_filter(filter: &Filter, document: &Value) -> Result<bool, abi::Error> {
+    eval_filter_at(filter, document, 0)
+}
+
+fn eval_filter_at(filter: &Filter, document: &Value, depth: usize) -> Result<bool, abi::Error> {
+    if depth > MAX_FILTER_DEPTH {
+        return Err(invalid_filter("nested too deeply"));
+    }
+
+    match &filter.filter {
+        Some(filter::Filter::Field(field)) => eval_field_filter(field, document),
+        // every filter is evaluated, so an invalid one fails even if the result is already known
+        Some(filter::Filter::And(list)) => list.filters.iter().try_fold(true, |all, filter| {
+            Ok(eval_filter_at(filter, document, depth + 1)? && all)
+        }),
+        Some(filter::Filter::Or(list)) => list.filters.iter().try_fold(false, |any, filter| {
+            Ok(eval_filter_at(filter, document, depth + 1)? || any)
+        }),
+        None => Err(invalid_filter("empty filter")),
+    }
+}
+
+fn eval_field_filter(field: &FieldFilter, document: &Value) -> Result<bool, abi::Error> {
+    let (column, path) = parse_path(&field.path).map_err(invalid_filter)?;
+    let op = FilterOperator::try_from(field.op)
+        .map_err(|_| invalid_filter(format!("unknown operator: {}", field.op)))?;
+    let value = match &field.value {
+        Some(value) => serde_json::to_value(value).map_err(invalid_filter)?,
+        None => Value::Null,
+    };
+    if op == FilterOperator::Unspecified {
+        return Err(invalid_filter("missing operator"));
+    }
+    if op == FilterOperator::In && !value.is_array() {
+        return Err(invalid_filter("in requires a list value"));
+    }
+
+    // like `#>`, a missing path yields nothing and every comparison with it is false
+    let Some(actual) = path
+        .iter()
+        .try_fold(&document[column], |v, segment| match v {
+            Value::Object(map) => map.get(segment),
+            Value::Array(list) => segment.parse::<usize>().ok().and_then(|i| list.get(i)),
+            _ => None,
+        })
+    else {
+        return Ok(false);
+    };
+
+    Ok(match op {
+        FilterOperator::Eq => json_eq(actual, &value),
+        FilterOperator::Ne => !json_eq(actual, &value),
+        FilterOperator::Lt => json_cmp(actual, &value) == Some(Ordering::Less),
+        FilterOperator::Gt => json_cmp(actual, &value) == Some(Ordering::Greater),
+        FilterOperator::Contains => json_contains(actual, &value),
+        FilterOperator::In => value
+            .as_array()
+            .is_some_and(|list| list.iter().any(|v| json_eq(actual, v))),
+        FilterOperator::Exists => true,
+        FilterOperator::Unspecified => false,
+    })
+}
+
+/// jsonb equality, numbers are equal if their values are
+fn json_eq(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::Number(a), Value::Number(b)) => a.as_f64() == b.as_f64(),
+        (Value::Array(a), Value::Array(b)) => {
+            a.len() == b.len() && a.iter().zip(b).all(|(a, b)| json_eq(a, b))
+        }
+        (Value::Object(a), Value::Object(b)) => {
+            a.len() == b.len()
+                && a.iter()
+                    .all(|(k, a)| b.get(k).is_some_and(|b| json_eq(a, b)))
+        }
+        (a, b) => a == b,
+    }
+}
+
+/// jsonb ordering of scalars, values of different types order as
+/// null < string < number < boolean < array < object
+fn json_cmp(a: &Value, b: &Value) -> Option<Ordering> {
+    fn rank(v: &Value) -> u8 {
+        match v {
+            Value::Null => 0,
+            Value::String(_) => 1,
+            Value::Number(_) => 2,
+            Value::Bool(_) => 3,
+            Value::Array(_) => 4,
+            Value::Object(_) => 5,
+        }
+    }
+
+    match (a, b) {
+        (Value::Number(a), Value::Number(b)) => a.as_f64()?.partial_cmp(&b.as_f64()?),
+        (Value::String(a), Value::String(b)) => Some(a.cmp(b)),
+        (Value::Bool(a), Value::Bool(b)) => Some(a.cmp(b)),
+        (Value::Null, Value::Null) => Some(Ordering::Equal),
+        (a, b) if rank(a) != rank(b) => Some(rank(a).cmp(&rank(b))),
+        // containers of the same type are not compared
+        _ => None,
+    }
+}
+
+/// jsonb containment `@>`
+fn json_contains(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::Object(a), Value::Object(b)) => b
+            .iter()
+            .all(|(k, b)| a.get(k).is_some_and(|a| json_contains(a, b))),
+        (Value::Array(a), Value::Array(b)) => {
+            b.iter().all(|b| a.iter().any(|a| json_contains(a, b)))
+        }
+        // an array contains a scalar it has as an element
+        (Value::Array(a), b) if !b.is_object() => a.iter().any(|a| json_eq(a, b)),
+        (a, b) => json_eq(a, b),
+    }
+}
+
+fn invalid_filter(e: impl ToString) -> abi::Error {
+    abi::Error::InvalidFilter(e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use abi::FilterList;
+    use prost_wkt_types::Value as PbValue;
+    use sqlx::PgPool;
+
+    use super::*;
+
+    fn field(path: &str, op: FilterOperator, value: Option<PbValue>) -> Filter {
+        Filter {
+            filter: Some(filter::Filter::Field(FieldFilter {
+                path: path.to_string(),
+                op: op as i32,
+                value,
+            })),
+        }
+    }
+
+    fn to_sql(filter: &Filter) -> Result<String, abi::Error> {
+        let mut builder = QueryBuilder::new("");
+        push_filter(&mut builder, filter)?;
+        Ok(builder.into_sql())
+    }
+
+    #[test]
+    fn nested_filter_should_compile_to_parameterized_sql() {
+        let filter = Filter {
+            filter: Some(filter::Filter::Or(FilterList {
+                filters: vec![
+                    field(
+                        "data.store",
+                        FilterOperator::Eq,
+                        Some("north".to_string().into()),
+                    ),
+                    Filter {
+                        filter: Some(filter::Filter::And(FilterList {
+                            filters: vec![
+                                field("data.count", FilterOperator::Gt, Some(3.0.into())),
+                                field("items", FilterOperator::Exists, None),
+                            ],
+                        })),
+                    },
+                ],
+            })),
+        };
+
+        assert_eq!(
+            to_sql(&filter).unwrap(),
+            "((data @> $1::JSONB AND (data #> $2::TEXT[]) = $3::JSONB) OR ((data #> $4::TEXT[]) > $5::JSONB AND (items #> $6::TEXT[]) IS NOT NULL))"
+        );
+    }
+
+    #[test]
+    fn string_ordering_should_compare_bytes() {
+        let filter = field(
+            "data.store",
+            FilterOperator::Lt,
+            Some("north".to_string().into()),
+        );
+        assert_eq!(
+            to_sql(&filter).unwrap(),
+            "(CASE WHEN jsonb_typeof(data #> $1::TEXT[]) = 'string' THEN (data #>> $2::TEXT[]) COLLATE \"C\" < $3 ELSE (data #> $4::TEXT[]) < $5::JSONB END)"
+        );
+    }
+
+    #[test]
+    fn containing_should_nest_the_value_at_the_path() {
+        let path = |path: &str| parse_path(path).unwrap().1;
+        let value = serde_json::json!("north");
+        assert_eq!(
+            containing(FilterOperator::Eq, &path("data.store.name"), &value),
+            Some(serde_json::json!({ "store": { "name": "north" } }))
+        );
+        assert_eq!(
+            containing(FilterOperator::Eq, &path("items.0.name"), &value),
+            None
+        );
+        assert_eq!(
+            containing(FilterOperator::Contains, &path("data.tags"), &value),
+            None
+        );
+        assert_eq!(
+            containing(FilterOperator::Gt, &path("data.store"), &value),
+            None
+        );
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn equality_filter_should_use_the_gin_index(pool: PgPool) {
+        sqlx::query(
+            "INSERT INTO dc.documents (user_id, data)
+            SELECT uuid_generate_v4(), jsonb_build_object('store', 'store-' || i) FROM generate_series(1, 500) i",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        let mut conn = pool.acquire().await.unwrap();
+        sqlx::query("ANALYZE dc.documents")
+            .execute(&mut *conn)
+            .await
+            .unwrap();
+        // so a small table is not scanned whole anyway
+        sqlx::query("SET enable_seqscan = off")
+            .execute(&mut *conn)
+            .await
+            .unwrap();
+
+        let filter = field(
+            "data.store",
+            FilterOperator::Eq,
+            Some("store-7".to_string().into()),
+        );
+        let mut builder = QueryBuilder::new("EXPLAIN SELECT * FROM dc.documents WHERE ");
+        push_filter(&mut builder, &filter).unwrap();
+        let plan: Vec<String> = builder
+            .build_query_scalar()
+            .fetch_all(&mut *conn)
+            .await
+            .unwrap();
+        assert!(
+            plan.iter().any(|line| line.contains("documents_data_gin")),
+            "{:#?}",
+            plan
+        );
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn string_ordering_should_match_the_same_documents_in_sql_and_memory(pool: PgPool) {
+        let stores = [
+            serde_json::json!("B"),
+            serde_json::json!("a"),
+            serde_json::json!("ab"),
+            serde_json::json!(5),
+            Value::Null,
+        ];
+        for (i, store) in stores.iter().enumerate() {
+            sqlx::query("INSERT INTO dc.documents (user_id, data) VALUES (uuid_generate_v4(), $1)")
+                .bind(serde_json::json!({ "i": i, "store": store }))
+                .execute(&pool)
+                .await
+                .unwrap();
+        }
+
+        for op in [FilterOperator::Lt, FilterOperator::Gt] {
+            let filter = field("data.store", op, Some("a".to_string().into()));
+            let mut builder =
+                QueryBuilder::new("SELECT (data->>'i')::INT FROM dc.documents WHERE ");
+            push_filter(&mut builder, &filter).unwrap();
+            builder.push(" ORDER BY 1");
+            let in_sql: Vec<i32> = builder.build_query_scalar().fetch_all(&pool).await.unwrap();
+            let in_memory: Vec<i32> = (0..stores.len())
+                .filter(|&i| {
+                    let document = serde_json::json!({ "data": { "store": stores[i] } });
+                    eval_filter(&filter, &document).unwrap()
+                })
+                .map(|i| i as i32)
+                .collect();
+            assert_eq!(in_sql, in_memory, "{:?}", op);
+        }
+    }
+
+    #[test]
+    fn filter_on_unknown_column_should_fail() {
+        let filter = field("user_id", FilterOperator::Eq, None);
+        assert!(matches!(to_sql(&filter), Err(abi::Error::InvalidFilter(_))));
+
+        let filter = field("data;DROP TABLE", FilterOperator::Eq, None);
+        assert!(matches!(to_sql(&filter), Err(abi::Error::InvalidFilter(_))));
+    }
+
+    #[test]
+    fn eval_filter_should_follow_sql_semantics() {
+        let document = serde_json::json!({
+            "data": { "store": "north", "count": 4, "tags": ["a", "b"], "none": null },
+            "items": [{ "name": "tea", "items0": [{ "price": "2.50" }] }],
+        });
+        let eval = |filter: Filter| eval_filter(&filter, &document).unwrap();
+
+        assert!(eval(field(
+            "data.store",
+            FilterOperator::Eq,
+            Some("north".to_string().into())
+        )));
+        assert!(eval(field(
+            "data.count",
+            FilterOperator::Eq,
+            Some(4.0.into())
+        )));
+        assert!(eval(field(
+            "data.count",
+            FilterOperator::Gt,
+            Some(3.0.into())
+        )));
+        assert!(!eval(field(
+            "data.count",
+            FilterOperator::Lt,
+            Some(3.0.into())
+        )));
+        assert!(eval(field(
+            "data.tags",
+            FilterOperator::Contains,
+            Some("b".to_string().into())
+        )));
+        assert!(eval(field(
+            "items.0.name",
+            FilterOperator::In,
+            Some(vec![PbValue::from("tea".to_string())].into())
+        )));
+        assert!(eval(field("data.none", FilterOperator::Exists, None)));
+        // a missing path matches nothing, not even ne
+        assert!(!eval(field(
+            "data.missing",
+            FilterOperator::Ne,
+            Some(1.0.into())
+        )));
+        assert!(!eval(field("items.1.name", FilterOperator::Exists, None)));
+
+        let filter = Filter {
+            filter: Some(filter::Filter::And(FilterList {
+                filters: vec![
+                    field(
+                        "data.store",
+                        FilterOperator::Eq,
+                        Some("north".to_string().into()),
+                    ),
+                    field("data.count", FilterOperator::Gt, Some(5.0.into())),
+                ],
+            })),
+        };
+        assert!(!eval(filter));
+
+        let invalid = field("data.count", FilterOperator::In, Some(1.0.into()));
+        assert!(matches!(
+            eval_filter(&invalid, &document),
+            Err(abi::Error::InvalidFilter(_))
+        ));
+    }
+
+    #[test]
+    fn too_deep_filter_should_fail() {
+        let mut filter = field("data.a", FilterOperator::Exists, None);
+        for _ in 0..=MAX_FILTER_DEPTH {
+            filter = Filter {
+                filter: Some(filter::Filter::And(FilterList {
+                    filters: vec![filter],
+                })),
+            };
+        }
+        assert!(matches!(to_sql(&filter), Err(abi::Error::InvalidFilter(_))));
+    }
+}
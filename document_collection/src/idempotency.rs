@@ -0,0 +1,317 @@
+use std::time::Duration;
+
+use abi::{CreateRequest, Document, FieldViolation, UpdateRequest};
+use async_trait::async_trait;
+use serde::Serialize;
+use sqlx::{
+    types::{Json, Uuid},
+    Postgres, Transaction,
+};
+
+use crate::{manager::parse_user_id, DcManager, Idempotency, IdempotencyKey};
+
+#[async_trait]
+impl Idempotency for DcManager {
+    async fn create_once(
+        &self,
+        key: IdempotencyKey,
+        ttl: Duration,
+        request: CreateRequest,
+    ) -> Result<Document, abi::Error> {
+        let mut once = Once::begin(self, key, ttl, "create", &request).await?;
+        if let Some(document) = once.replay().await? {
+            return Ok(document);
+        }
+
+        let document = self
+            .create_in(
+                &mut once.tx,
+                request.collection,
+                request.user_id,
+                request.data.unwrap_or_default(),
+                request.items,
+                request.document_type,
+            )
+            .await?;
+        once.commit(document).await
+    }
+
+    async fn update_once(
+        &self,
+        key: IdempotencyKey,
+        ttl: Duration,
+        request: UpdateRequest,
+    ) -> Result<Document, abi::Error> {
+        let mut once = Once::begin(self, key, ttl, "update", &request).await?;
+        if let Some(document) = once.replay().await? {
+            return Ok(document);
+        }
+
+        let expected_version = (request.expected_version > 0).then_some(request.expected_version);
+        let document = self
+            .update_in(
+                &mut once.tx,
+                request.collection,
+                request.id,
+                request.data,
+                (!request.items.is_empty()).then_some(request.items),
+                expected_version,
+            )
+            .await?;
+        once.commit(document).await
+    }
+
+    async fn purge_idempotency_keys(&self) -> Result<u64, abi::Error> {
+        let result = sqlx::query("DELETE FROM dc.idempotency_keys WHERE expires_at <= NOW()")
+            .execute(&self.pool)
+            .await?;
+        Ok(result.rows_affected())
+    }
+}
+
+/// A write run at most once per key, in a transaction holding the lock of its key, so a retry
+/// arriving while the first attempt still runs waits for its outcome.
+struct Once {
+    tx: Transaction<'static, Postgres>,
+    user_id: Uuid,
+    key: String,
+    ttl: Duration,
+    operation: &'static str,
+    request: serde_json::Value,
+}
+
+impl Once {
+    async fn begin(
+        manager: &DcManager,
+        key: IdempotencyKey,
+        ttl: Duration,
+        operation: &'static str,
+        request: &impl Serialize,
+    ) -> Result<Self, abi::Error> {
+        let user_id = parse_user_id(&key.user_id)?;
+        let request =
+            serde_json::to_value(request).map_err(|e| abi::Error::InvalidData(e.to_string()))?;
+        let mut tx = manager.pool.begin().await?;
+        sqlx::query("SELECT pg_advisory_xact_lock(hashtextextended($1 || '/' || $2, 0))")
+            .bind(user_id.to_string())
+            .bind(&key.key)
+            .execute(&mut *tx)
+            .await?;
+        Ok(Self {
+            tx,
+            user_id,
+            key: key.key,
+            ttl,
+            operation,
+            request,
+        })
+    }
+
+    /// the document of an earlier attempt with the key, if it has not expired yet
+    async fn replay(&mut self) -> Result<Option<Document>, abi::Error> {
+        let recorded: Option<(bool, Json<Document>)> = sqlx::query_as(
+            "SELECT operation = $3 AND request = $4, document FROM dc.idempotency_keys
+            WHERE user_id = $1 AND key = $2 AND expires_at > NOW()",
+        )
+        .bind(self.user_id)
+        .bind(&self.key)
+        .bind(self.operation)
+        .bind(&self.request)
+        .fetch_optional(&mut *self.tx)
+        .await?;
+
+        match recorded {
+            None => Ok(None),
+            Some((true, Json(document))) => Ok(Some(document)),
+            Some((false, _)) => Err(abi::Error::InvalidRequest(vec![FieldViolation {
+                field: "idempotency_key".to_string(),
+                description: "already used for another request".to_string(),
+            }])),
+        }
+    }
+
+    /// remember the document the write returned, replacing an expired attempt with the same key
+    async fn commit(mut self, document: Document) -> Result<Document, abi::Error> {
+        sqlx::query(
+            "INSERT INTO dc.idempotency_keys (user_id, key, operation, request, document, expires_at)
+            VALUES ($1, $2, $3, $4, $5, NOW() + make_interval(secs => $6))
+            ON CONFLICT (user_id, key) DO UPDATE SET operation = EXCLUDED.operation,
+                request = EXCLUDED.request, document = EXCLUDED.document,
+                created_at = EXCLUDED.created_at, expires_at = EXCLUDED.expires_at",
+        )
+        .bind(self.user_id)
+        .bind(&self.key)
+        .bind(self.operation)
+        .bind(&self.request)
+        .bind(Json(&document))
+        .bind(self.ttl.as_secs_f64())
+        .execute(&mut *self.tx)
+        .await?;
+        self.tx.commit().await?;
+        Ok(document)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::{postgres::PgPoolOptions, PgPool};
+
+    use super::*;
+    use crate::{
+        testing::{new_manager, USER_ID},
+        Dc,
+    };
+
+    const TTL: Duration = Duration::from_secs(60);
+
+    fn key(key: &str) -> IdempotencyKey {
+        IdempotencyKey {
+            user_id: USER_ID.to_string(),
+            key: key.to_string(),
+        }
+    }
+
+    fn create_request() -> CreateRequest {
+        CreateRequest {
+            user_id: USER_ID.to_string(),
+            ..Default::default()
+        }
+    }
+
+    async fn document_count(manager: &DcManager) -> i64 {
+        sqlx::query_scalar("SELECT COUNT(*) FROM dc.documents")
+            .fetch_one(&manager.pool)
+            .await
+            .unwrap()
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn retried_create_should_return_the_first_document(pool: PgPool) {
+        let manager = new_manager(pool).await;
+
+        let first = manager
+            .create_once(key("receipt-1"), TTL, create_request())
+            .await
+            .unwrap();
+        let retry = manager
+            .create_once(key("receipt-1"), TTL, create_request())
+            .await
+            .unwrap();
+        assert_eq!(retry, first);
+        assert_eq!(document_count(&manager).await, 1);
+
+        manager
+            .create_once(key("receipt-2"), TTL, create_request())
+            .await
+            .unwrap();
+        assert_eq!(document_count(&manager).await, 2);
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn concurrent_retries_should_create_one_document(pool: PgPool) {
+        let manager = new_manager(pool).await;
+
+        let attempts = (0..4).map(|_| manager.create_once(key("receipt"), TTL, create_request()));
+        let documents = futures::future::try_join_all(attempts).await.unwrap();
+        assert!(documents.iter().all(|d| *d == documents[0]));
+        assert_eq!(document_count(&manager).await, 1);
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn key_reused_for_another_request_should_fail(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let document = manager
+            .create_once(key("receipt"), TTL, create_request())
+            .await
+            .unwrap();
+
+        let other = CreateRequest {
+            document_type: "other".to_string(),
+            ..create_request()
+        };
+        let err = manager
+            .create_once(key("receipt"), TTL, other)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, abi::Error::InvalidRequest(_)));
+
+        let update = UpdateRequest {
+            id: document.id,
+            ..Default::default()
+        };
+        let err = manager
+            .update_once(key("receipt"), TTL, update)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, abi::Error::InvalidRequest(_)));
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn retried_update_should_not_apply_again(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let document = manager
+            .create(
+                String::new(),
+                USER_ID.to_string(),
+                Default::default(),
+                vec![],
+                String::new(),
+            )
+            .await
+            .unwrap();
+        let update = UpdateRequest {
+            id: document.id.clone(),
+            expected_version: 1,
+            ..Default::default()
+        };
+
+        let first = manager
+            .update_once(key("edit"), TTL, update.clone())
+            .await
+            .unwrap();
+        // a retry would conflict, as the document moved on to version 2
+        let retry = manager.update_once(key("edit"), TTL, update).await.unwrap();
+        assert_eq!(retry, first);
+        assert_eq!(retry.version, 2);
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn keyed_writes_should_need_one_connection(pool: PgPool) {
+        let pool = PgPoolOptions::new()
+            .max_connections(1)
+            .acquire_timeout(Duration::from_secs(5))
+            .connect_with((*pool.connect_options()).clone())
+            .await
+            .unwrap();
+        let manager = new_manager(pool).await;
+
+        let document = manager
+            .create_once(key("receipt"), TTL, create_request())
+            .await
+            .unwrap();
+        let update = UpdateRequest {
+            id: document.id,
+            expected_version: 1,
+            ..Default::default()
+        };
+        let document = manager.update_once(key("edit"), TTL, update).await.unwrap();
+        assert_eq!(document.version, 2);
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn expired_keys_should_run_again_and_be_purged(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let first = manager
+            .create_once(key("receipt"), Duration::ZERO, create_request())
+            .await
+            .unwrap();
+        let second = manager
+            .create_once(key("receipt"), Duration::ZERO, create_request())
+            .await
+            .unwrap();
+        assert_ne!(first.id, second.id);
+
+        assert_eq!(manager.purge_idempotency_keys().await.unwrap(), 1);
+        assert_eq!(manager.purge_idempotency_keys().await.unwrap(), 0);
+    }
+}
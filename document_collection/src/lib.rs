@@ -1,29 +1,316 @@
+mod aggregate;
+mod batch;
+mod change;
+mod collection;
+mod filter;
+mod idempotency;
 mod manager;
+mod order;
+mod patch;
+mod schema;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+mod time_range;
+mod totals;
+mod webhook;
 
+use std::time::Duration;
+
+use abi::{
+    patch_request::Patch, Collection, CreateRequest, DeleteRequest, DocumentChange, DocumentItem,
+    DocumentRevision, DocumentSchema, ReadChangesRequest, UpdateRequest, Webhook, WebhookDelivery,
+};
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use prost_wkt_types::Struct;
-use serde_json::Value;
 use sqlx::PgPool;
 use tokio::sync::mpsc;
 
-#[derive(Debug)]
+pub use change::{check_filter, matches, BlockingTransaction, ChangeListener, HeldBack};
+pub use webhook::PendingDelivery;
+
+/// The collection documents live in if no other is named.
+pub const DEFAULT_COLLECTION: &str = "documents";
+
+#[derive(Debug, Clone)]
 pub struct DcManager {
     pool: PgPool,
+    schemas: schema::SchemaCache,
+}
+
+/// The user and organization a document belongs to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Owner {
+    pub user_id: String,
+    pub org_id: String,
+}
+
+/// The idempotency key a user sent with a write, keys of different users never clash.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdempotencyKey {
+    pub user_id: String,
+    pub key: String,
 }
 
+/// Every method taking a collection looks the document up in that collection only,
+/// an empty collection name means [`DEFAULT_COLLECTION`].
 #[async_trait]
 pub trait Dc {
-    /// Create a new document.
-    async fn create(&self, user_id: String, data: Struct) -> Result<abi::Document, abi::Error>;
-    /// Update a document.
-    async fn update(&self, id: abi::DocumentId, data: Struct) -> Result<abi::Document, abi::Error>;
-    /// Delete a document.
-    async fn delete(&self, id: abi::DocumentId) -> Result<abi::Document, abi::Error>;
-    /// Get a document.
-    async fn get(&self, id: abi::DocumentId) -> Result<abi::Document, abi::Error>;
+    /// Create a new document, if `document_type` is not empty the data must conform to its latest schema.
+    async fn create(
+        &self,
+        collection: String,
+        user_id: String,
+        data: Struct,
+        items: Vec<DocumentItem>,
+        document_type: String,
+    ) -> Result<abi::Document, abi::Error>;
+    /// Update a document, if `expected_version` is given the document must still be at that version.
+    /// The data or items not given are kept, given items replace every stored item.
+    async fn update(
+        &self,
+        collection: String,
+        id: abi::DocumentId,
+        data: Option<Struct>,
+        items: Option<Vec<DocumentItem>>,
+        expected_version: Option<i64>,
+    ) -> Result<abi::Document, abi::Error>;
+    /// Apply a merge patch or a json patch to a document atomically.
+    async fn patch(
+        &self,
+        collection: String,
+        id: abi::DocumentId,
+        patch: Patch,
+        expected_version: Option<i64>,
+    ) -> Result<abi::Document, abi::Error>;
+    /// Move a document to the trash, if `expected_version` is given the document must still be at that version.
+    async fn delete(
+        &self,
+        collection: String,
+        id: abi::DocumentId,
+        expected_version: Option<i64>,
+    ) -> Result<abi::Document, abi::Error>;
+    /// Take a document out of the trash.
+    async fn undelete(
+        &self,
+        collection: String,
+        id: abi::DocumentId,
+    ) -> Result<abi::Document, abi::Error>;
+    /// Permanently remove documents moved to the trash before the given time, or longer ago than the
+    /// retention of their collection if it has one, return how many were removed.
+    async fn purge(&self, deleted_before: DateTime<Utc>) -> Result<u64, abi::Error>;
+    /// Get a document, documents in the trash are only returned if `include_deleted` is set.
+    async fn get(
+        &self,
+        collection: String,
+        id: abi::DocumentId,
+        include_deleted: bool,
+    ) -> Result<abi::Document, abi::Error>;
+    /// Get who a document belongs to, also for documents in the trash or purged.
+    async fn owner(&self, collection: String, id: abi::DocumentId) -> Result<Owner, abi::Error>;
+    /// Get a document as it was at the given revision.
+    async fn get_revision(
+        &self,
+        collection: String,
+        id: abi::DocumentId,
+        revision: i64,
+    ) -> Result<abi::Document, abi::Error>;
+    /// List every revision of a document still kept, oldest first.
+    async fn list_revisions(
+        &self,
+        collection: String,
+        id: abi::DocumentId,
+    ) -> Result<Vec<DocumentRevision>, abi::Error>;
+    /// Restore a document to the content of the given revision, recreating it if it was deleted.
+    async fn restore(
+        &self,
+        collection: String,
+        id: abi::DocumentId,
+        revision: i64,
+        expected_version: Option<i64>,
+    ) -> Result<abi::Document, abi::Error>;
     /// query
     async fn query(
         &self,
-        query: abi::DocumentQuery,
+        request: abi::QueryRequest,
     ) -> mpsc::Receiver<Result<abi::Document, abi::Error>>;
+    /// Return one page of the documents matching a query.
+    async fn list(&self, request: abi::QueryRequest) -> Result<abi::ListResponse, abi::Error>;
+    /// Count documents and sum up numeric fields, grouped by user, period or field.
+    async fn aggregate(
+        &self,
+        request: abi::AggregateRequest,
+    ) -> Result<abi::AggregateResponse, abi::Error>;
+}
+
+/// Many creates, updates or deletes run together, each row of a batch written by one statement.
+/// Without `atomic` every request succeeds or fails on its own, with it a failed request fails
+/// the others with [`abi::Error::BatchAborted`]. The results are in request order. A row the
+/// database rejects fails only its own request, but fails an atomic batch as a whole.
+#[async_trait]
+pub trait Batches {
+    /// Create every document of the batch, checked like [`Dc::create`].
+    async fn batch_create(
+        &self,
+        requests: Vec<CreateRequest>,
+        atomic: bool,
+    ) -> Result<Vec<Result<abi::Document, abi::Error>>, abi::Error>;
+    /// Update every document of the batch, a batch may update each document only once.
+    async fn batch_update(
+        &self,
+        requests: Vec<UpdateRequest>,
+        atomic: bool,
+    ) -> Result<Vec<Result<abi::Document, abi::Error>>, abi::Error>;
+    /// Move every document of the batch to the trash, a batch may delete each document only once.
+    async fn batch_delete(
+        &self,
+        requests: Vec<DeleteRequest>,
+        atomic: bool,
+    ) -> Result<Vec<Result<abi::Document, abi::Error>>, abi::Error>;
+    /// Look up the owners of documents, given by collection and id, at once, each like
+    /// [`Dc::owner`], in the order they were asked for.
+    async fn batch_owners(
+        &self,
+        documents: Vec<(String, abi::DocumentId)>,
+    ) -> Result<Vec<Result<Owner, abi::Error>>, abi::Error>;
+}
+
+/// Creates and updates a client may safely retry: the first attempt with a key runs, retries of
+/// the same request within `ttl` return the document it returned instead of running again.
+#[async_trait]
+pub trait Idempotency {
+    /// Create a document like [`Dc::create`] once per key.
+    async fn create_once(
+        &self,
+        key: IdempotencyKey,
+        ttl: Duration,
+        request: CreateRequest,
+    ) -> Result<abi::Document, abi::Error>;
+    /// Update a document like [`Dc::update`] once per key.
+    async fn update_once(
+        &self,
+        key: IdempotencyKey,
+        ttl: Duration,
+        request: UpdateRequest,
+    ) -> Result<abi::Document, abi::Error>;
+    /// Remove the keys past their time to live, return how many were removed.
+    async fn purge_idempotency_keys(&self) -> Result<u64, abi::Error>;
+}
+
+/// Versioned JSON Schemas of the document types of each organization.
+#[async_trait]
+pub trait Schemas {
+    /// Register a schema for a document type, as the next version if the type already has one.
+    async fn register_schema(
+        &self,
+        org_id: String,
+        document_type: String,
+        schema: Struct,
+    ) -> Result<DocumentSchema, abi::Error>;
+    /// Get the given version of the schema of a document type, the latest if `version` is None.
+    async fn get_schema(
+        &self,
+        org_id: String,
+        document_type: String,
+        version: Option<i32>,
+    ) -> Result<DocumentSchema, abi::Error>;
+    /// List every version of the schemas of an organization, of one document type if given.
+    async fn list_schemas(
+        &self,
+        org_id: String,
+        document_type: Option<String>,
+    ) -> Result<Vec<DocumentSchema>, abi::Error>;
+}
+
+/// Named collections documents are kept apart in, each with its own settings.
+#[async_trait]
+pub trait Collections {
+    /// Create a collection and the indexes it asks for, built without blocking writes. The
+    /// collection is removed again if an index fails to build.
+    async fn create_collection(&self, collection: Collection) -> Result<Collection, abi::Error>;
+    /// List every collection by name.
+    async fn list_collections(&self) -> Result<Vec<Collection>, abi::Error>;
+    /// Drop a collection and its indexes, with every document in it if `force` is set, otherwise only if it is empty.
+    /// The documents are deleted one by one, each deletion recorded as a change, before the
+    /// indexes are dropped without blocking writes.
+    async fn drop_collection(&self, name: String, force: bool) -> Result<Collection, abi::Error>;
+    /// Finish creating or dropping the collections a server stopped working on midway, return
+    /// how many were finished. Collections another server is still working on are left alone.
+    async fn repair_collections(&self) -> Result<usize, abi::Error>;
+}
+
+/// The change recorded by every create, update and delete, numbered by an increasing sequence.
+///
+/// Changes are listed once no running transaction can add an earlier one. Transaction ids are
+/// shared by every database of the postgres server, so any transaction that wrote something, in any
+/// database, holds back the changes committed after it began until it ends: a long running or idle
+/// transaction, or `CREATE INDEX CONCURRENTLY`, stalls watchers, `read_changes` and webhooks
+/// meanwhile. `held_back` tells which transaction that is.
+#[async_trait]
+pub trait Changes {
+    /// List the changes after the one with the given sequence number, from the first if 0, in the
+    /// order of the transactions that made them, once no running transaction can add an earlier one.
+    async fn list_changes(
+        &self,
+        after_sequence: i64,
+        limit: i64,
+    ) -> Result<Vec<DocumentChange>, abi::Error>;
+    /// Get the changes with the given sequence numbers, in the order they were asked for.
+    async fn get_changes(&self, sequences: Vec<i64>) -> Result<Vec<DocumentChange>, abi::Error>;
+    /// The sequence number of the latest change `list_changes` would list, 0 if there is none.
+    async fn latest_sequence(&self) -> Result<i64, abi::Error>;
+    /// The committed changes `list_changes` does not list yet and the transaction they wait for,
+    /// None if every committed change is listed.
+    async fn held_back(&self) -> Result<Option<HeldBack>, abi::Error>;
+    /// Listen for new changes as they are committed, a change is only listed once every
+    /// transaction older than the one making it has ended.
+    async fn listen_changes(&self) -> Result<ChangeListener, abi::Error>;
+    /// Remove the changes recorded before the given time, except the current revision of every
+    /// document and the changes webhook deliveries were not created for yet, return how many were
+    /// removed. Watchers and consumers can no longer resume after a removed change.
+    async fn purge_changes(&self, recorded_before: DateTime<Utc>) -> Result<u64, abi::Error>;
+    /// Read the changes in the same stable order and with the same sequence numbers as
+    /// `list_changes`, after the change with the sequence `since_seq` if it is set.
+    async fn read_changes(
+        &self,
+        request: ReadChangesRequest,
+    ) -> mpsc::Receiver<Result<DocumentChange, abi::Error>>;
+}
+
+/// Webhook endpoints changes are posted to, and the deliveries posting them.
+#[async_trait]
+pub trait Webhooks {
+    /// Register a webhook, generating its secret if none is given.
+    async fn create_webhook(&self, webhook: Webhook) -> Result<Webhook, abi::Error>;
+    /// Get a webhook, without its secret.
+    async fn get_webhook(&self, id: String) -> Result<Webhook, abi::Error>;
+    /// List the webhooks of an organization, of every organization if empty, without their secrets.
+    async fn list_webhooks(&self, org_id: String) -> Result<Vec<Webhook>, abi::Error>;
+    /// Delete a webhook with all its deliveries.
+    async fn delete_webhook(&self, id: String) -> Result<Webhook, abi::Error>;
+    /// List the latest deliveries of a webhook, dead letters included, newest first.
+    async fn list_deliveries(
+        &self,
+        webhook_id: String,
+        limit: i64,
+    ) -> Result<Vec<WebhookDelivery>, abi::Error>;
+    /// Create the deliveries of up to `limit` changes not looked at before,
+    /// return how many changes were looked at.
+    async fn enqueue_deliveries(&self, limit: i64) -> Result<usize, abi::Error>;
+    /// Lease up to `limit` due deliveries, so no other server posts them until the lease ends.
+    async fn claim_deliveries(
+        &self,
+        limit: i64,
+        lease: Duration,
+    ) -> Result<Vec<PendingDelivery>, abi::Error>;
+    /// Record a successful attempt.
+    async fn complete_delivery(&self, id: String, response_status: i32) -> Result<(), abi::Error>;
+    /// Record a failed attempt, to retry at `retry_at` or, if None, move to the dead letters.
+    async fn fail_delivery(
+        &self,
+        id: String,
+        response_status: Option<i32>,
+        error: String,
+        retry_at: Option<DateTime<Utc>>,
+    ) -> Result<(), abi::Error>;
 }
@@ -1,116 +1,361 @@
-use std::time::SystemTime;
-
-use abi::{DbConfig, DocumentQuery};
+use abi::{
+    patch_request::Patch, DbConfig, DocumentItem, DocumentRevision, ListResponse, Validator,
+};
 use async_trait::async_trait;
-use chrono::{Date, DateTime, Days, NaiveDate, Utc};
+use chrono::{DateTime, Utc};
 use futures::StreamExt;
-use prost_wkt_types::{Struct, Timestamp};
-use serde_json::Value;
-use sqlx::{postgres::PgPoolOptions, types::Uuid, Either, PgPool};
+use prost_wkt_types::Struct;
+use sqlx::{
+    postgres::PgPoolOptions, types::Uuid, Either, FromRow, PgConnection, PgExecutor, PgPool,
+    Postgres, QueryBuilder, Row,
+};
 use tokio::sync::mpsc;
 use tracing::{debug, info, warn};
 
-use crate::{Dc, DcManager};
+use crate::{
+    aggregate::build_aggregate,
+    filter::push_filter,
+    order::{Ordering, PageToken},
+    patch::DocumentContent,
+    schema::SchemaCache,
+    time_range::TimeRange,
+    totals::{fill_items_totals, fill_totals},
+    Dc, DcManager, Owner, DEFAULT_COLLECTION,
+};
+
+/// page size of list if none is given
+const DEFAULT_PAGE_SIZE: usize = 50;
+/// largest page size list accepts
+const MAX_PAGE_SIZE: i32 = 500;
+
+/// select a revision shaped like a row of dc.documents
+const REVISION_AS_DOCUMENT: &str = "SELECT r.document_id AS id, r.user_id, r.data, r.items, r.revision AS version,
+    r.document_created_at AS created_at, r.created_at AS updated_at, NULL::TIMESTAMPTZ AS deleted_at, r.org_id, r.store_id,
+    r.document_type, r.schema_version, r.collection
+    FROM dc.document_revisions r
+    WHERE r.document_id = $1 AND r.revision = $2 AND r.collection = $3 AND NOT r.deleted";
 
 #[async_trait]
 impl Dc for DcManager {
-    async fn create(&self, user_id: String, data: Struct) -> Result<abi::Document, abi::Error> {
-        let data = serde_json::to_value(data).unwrap();
-        let user_id = Uuid::parse_str(&user_id).unwrap();
-        let document: abi::Document =
-            sqlx::query_as("INSERT INTO dc.documents (user_id, data) VALUES ($1, $2) RETURNING *")
-                .bind(user_id)
-                .bind(data)
-                .fetch_one(&self.pool)
-                .await?;
+    async fn create(
+        &self,
+        collection: String,
+        user_id: String,
+        data: Struct,
+        items: Vec<DocumentItem>,
+        document_type: String,
+    ) -> Result<abi::Document, abi::Error> {
+        let mut conn = self.pool.acquire().await?;
+        self.create_in(&mut conn, collection, user_id, data, items, document_type)
+            .await
+    }
+
+    async fn update(
+        &self,
+        collection: String,
+        id: abi::DocumentId,
+        data: Option<Struct>,
+        items: Option<Vec<DocumentItem>>,
+        expected_version: Option<i64>,
+    ) -> Result<abi::Document, abi::Error> {
+        let mut conn = self.pool.acquire().await?;
+        self.update_in(&mut conn, collection, id, data, items, expected_version)
+            .await
+    }
+
+    async fn patch(
+        &self,
+        collection: String,
+        id: abi::DocumentId,
+        patch: Patch,
+        expected_version: Option<i64>,
+    ) -> Result<abi::Document, abi::Error> {
+        let id = parse_id(&id)?;
+        let mut tx = self.pool.begin().await?;
+
+        let document: abi::Document = sqlx::query_as(
+            "SELECT * FROM dc.documents WHERE id = $1 AND collection = $2 AND deleted_at IS NULL FOR UPDATE",
+        )
+        .bind(id)
+        .bind(collection_name(collection))
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or(abi::Error::NotFound)?;
+        if let Some(expected) = expected_version {
+            if expected != document.version {
+                return Err(abi::Error::Conflict {
+                    expected,
+                    actual: document.version,
+                });
+            }
+        }
+
+        let org_id = Uuid::parse_str(&document.org_id).ok();
+        let document_type = document.document_type.clone();
+        let DocumentContent { data, items } = DocumentContent::from(document).apply(patch)?;
+        let items = items_to_value(items)?;
+        let data = data_to_value(data)?;
+        let schema_version = self
+            .check_data(&mut *tx, org_id, &document_type, &data)
+            .await?;
+        let mut document: abi::Document = sqlx::query_as(
+            "UPDATE dc.documents SET data = $1, items = $2, schema_version = $4 WHERE id = $3 RETURNING *",
+        )
+        .bind(data)
+        .bind(items)
+        .bind(id)
+        .bind(schema_version)
+        .fetch_one(&mut *tx)
+        .await?;
+        tx.commit().await?;
 
+        fill_totals(&mut document)?;
         Ok(document)
     }
 
-    async fn update(&self, id: abi::DocumentId, data: Struct) -> Result<abi::Document, abi::Error> {
-        info!("Updating document: {:?}", data);
-        let data = serde_json::to_value(data).unwrap();
-        let id = Uuid::parse_str(&id).unwrap();
-        let document: abi::Document =
-            sqlx::query_as("UPDATE dc.documents SET data = $1 WHERE id = $2 RETURNING *")
-                .bind(data)
-                .bind(id)
-                .fetch_one(&self.pool)
-                .await?;
+    async fn delete(
+        &self,
+        collection: String,
+        id: abi::DocumentId,
+        expected_version: Option<i64>,
+    ) -> Result<abi::Document, abi::Error> {
+        let id = parse_id(&id)?;
+        let collection = collection_name(collection);
+        let document: Option<abi::Document> = sqlx::query_as(
+            "UPDATE dc.documents SET deleted_at = NOW()
+            WHERE id = $1 AND collection = $3 AND deleted_at IS NULL AND ($2::BIGINT IS NULL OR version = $2)
+            RETURNING *",
+        )
+        .bind(id)
+        .bind(expected_version)
+        .bind(&collection)
+        .fetch_optional(&self.pool)
+        .await?;
 
+        let mut document = match document {
+            Some(document) => document,
+            None => {
+                return Err(self
+                    .precondition_error(&self.pool, &collection, id, expected_version)
+                    .await)
+            }
+        };
+        fill_totals(&mut document)?;
         Ok(document)
     }
 
-    async fn delete(&self, id: abi::DocumentId) -> Result<abi::Document, abi::Error> {
-        let id = Uuid::parse_str(&id).unwrap();
-        let document: abi::Document =
-            sqlx::query_as("DELETE FROM dc.documents WHERE id = $1 RETURNING *")
-                .bind(id)
-                .fetch_one(&self.pool)
-                .await?;
+    async fn undelete(
+        &self,
+        collection: String,
+        id: abi::DocumentId,
+    ) -> Result<abi::Document, abi::Error> {
+        let id = parse_id(&id)?;
+        let mut document: abi::Document = sqlx::query_as(
+            "UPDATE dc.documents SET deleted_at = NULL
+            WHERE id = $1 AND collection = $2 AND deleted_at IS NOT NULL RETURNING *",
+        )
+        .bind(id)
+        .bind(collection_name(collection))
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(abi::Error::NotFound)?;
 
+        fill_totals(&mut document)?;
         Ok(document)
     }
 
-    async fn get(&self, id: abi::DocumentId) -> Result<abi::Document, abi::Error> {
-        let id = Uuid::parse_str(&id).unwrap();
-        let document: abi::Document = sqlx::query_as("SELECT * FROM dc.documents WHERE id = $1")
+    async fn purge(&self, deleted_before: DateTime<Utc>) -> Result<u64, abi::Error> {
+        let result = sqlx::query(
+            "DELETE FROM dc.documents d USING dc.collections c
+            WHERE d.collection = c.name AND d.deleted_at IS NOT NULL AND d.deleted_at < CASE
+                WHEN c.retention_days > 0 THEN NOW() - make_interval(days => c.retention_days)
+                ELSE $1 END",
+        )
+        .bind(deleted_before)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    async fn get(
+        &self,
+        collection: String,
+        id: abi::DocumentId,
+        include_deleted: bool,
+    ) -> Result<abi::Document, abi::Error> {
+        let id = parse_id(&id)?;
+        let mut document: abi::Document = sqlx::query_as(
+            "SELECT * FROM dc.documents WHERE id = $1 AND collection = $3 AND ($2 OR deleted_at IS NULL)",
+        )
+        .bind(id)
+        .bind(include_deleted)
+        .bind(collection_name(collection))
+        .fetch_one(&self.pool)
+        .await?;
+
+        fill_totals(&mut document)?;
+        Ok(document)
+    }
+
+    async fn owner(&self, collection: String, id: abi::DocumentId) -> Result<Owner, abi::Error> {
+        let id = parse_id(&id)?;
+        let (user_id, org_id): (Uuid, Option<Uuid>) = sqlx::query_as(
+            "SELECT user_id, org_id FROM dc.documents WHERE id = $1 AND collection = $2
+            UNION ALL SELECT user_id, org_id FROM dc.document_revisions
+            WHERE document_id = $1 AND collection = $2 LIMIT 1",
+        )
+        .bind(id)
+        .bind(collection_name(collection))
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(abi::Error::NotFound)?;
+        Ok(Owner {
+            user_id: user_id.to_string(),
+            org_id: org_id.map(|id| id.to_string()).unwrap_or_default(),
+        })
+    }
+
+    async fn get_revision(
+        &self,
+        collection: String,
+        id: abi::DocumentId,
+        revision: i64,
+    ) -> Result<abi::Document, abi::Error> {
+        let id = parse_id(&id)?;
+        let mut document: abi::Document = sqlx::query_as(REVISION_AS_DOCUMENT)
             .bind(id)
-            .fetch_one(&self.pool)
+            .bind(revision)
+            .bind(collection_name(collection))
+            .fetch_optional(&self.pool)
+            .await?
+            .ok_or(abi::Error::NotFound)?;
+
+        fill_totals(&mut document)?;
+        Ok(document)
+    }
+
+    async fn list_revisions(
+        &self,
+        collection: String,
+        id: abi::DocumentId,
+    ) -> Result<Vec<DocumentRevision>, abi::Error> {
+        let id = parse_id(&id)?;
+        let revisions: Vec<DocumentRevision> = sqlx::query_as(
+            "SELECT * FROM dc.document_revisions WHERE document_id = $1 AND collection = $2 ORDER BY revision",
+        )
+        .bind(id)
+        .bind(collection_name(collection))
+        .fetch_all(&self.pool)
+        .await?;
+
+        if revisions.is_empty() {
+            return Err(abi::Error::NotFound);
+        }
+        Ok(revisions)
+    }
+
+    async fn restore(
+        &self,
+        collection: String,
+        id: abi::DocumentId,
+        revision: i64,
+        expected_version: Option<i64>,
+    ) -> Result<abi::Document, abi::Error> {
+        let id = parse_id(&id)?;
+        let collection = collection_name(collection);
+        let mut tx = self.pool.begin().await?;
+
+        let revision: DocumentRevision = sqlx::query_as(
+            "SELECT * FROM dc.document_revisions
+            WHERE document_id = $1 AND revision = $2 AND collection = $3 AND NOT deleted",
+        )
+        .bind(id)
+        .bind(revision)
+        .bind(&collection)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or(abi::Error::NotFound)?;
+        let items = items_to_value(revision.items)?;
+        let data = data_to_value(revision.data.unwrap_or_default())?;
+        // old content must still conform to the latest schema of its type
+        let (org_id, document_type) = self.document_type(&mut *tx, &collection, id).await?;
+        let schema_version = self
+            .check_data(&mut *tx, org_id, &document_type, &data)
             .await?;
 
+        let version: Option<i64> =
+            sqlx::query_scalar("SELECT version FROM dc.documents WHERE id = $1 FOR UPDATE")
+                .bind(id)
+                .fetch_optional(&mut *tx)
+                .await?;
+        let mut document: abi::Document = match version {
+            Some(actual) => {
+                if let Some(expected) = expected_version.filter(|v| *v != actual) {
+                    return Err(abi::Error::Conflict { expected, actual });
+                }
+                sqlx::query_as(
+                    "UPDATE dc.documents SET data = $1, items = $2, schema_version = $4, deleted_at = NULL
+                    WHERE id = $3 RETURNING *",
+                )
+                .bind(data)
+                .bind(items)
+                .bind(id)
+                .bind(schema_version)
+                .fetch_one(&mut *tx)
+                .await?
+            }
+            // the document was purged from the trash, bring it back with the next version number
+            None => sqlx::query_as(
+                "INSERT INTO dc.documents (id, user_id, org_id, store_id, collection, document_type, schema_version, data, items, version)
+                SELECT document_id, user_id, org_id, store_id, collection, document_type, $5, $3, $4,
+                    (SELECT MAX(revision) + 1 FROM dc.document_revisions WHERE document_id = $1)
+                FROM dc.document_revisions WHERE document_id = $1 AND revision = $2 RETURNING *",
+            )
+            .bind(id)
+            .bind(revision.revision)
+            .bind(data)
+            .bind(items)
+            .bind(schema_version)
+            .fetch_one(&mut *tx)
+            .await?,
+        };
+        tx.commit().await?;
+
+        fill_totals(&mut document)?;
         Ok(document)
     }
 
     async fn query(
         &self,
-        query: abi::DocumentQuery,
+        request: abi::QueryRequest,
     ) -> mpsc::Receiver<Result<abi::Document, abi::Error>> {
         let pool = self.pool.clone();
-        let DocumentQuery {
-            user_id,
-            start,
-            end,
-        } = query;
-        let user_id = Uuid::parse_str(&user_id).unwrap();
-        let start = start
-            .map(|t| {
-                DateTime::<Utc>::from(t)
-                    .date_naive()
-                    .and_hms_opt(0, 0, 0)
-                    .unwrap()
-            })
-            .unwrap_or_default();
-        let end = end
-            .map(|t| {
-                DateTime::<Utc>::from(t)
-                    .date_naive()
-                    .and_hms_opt(0, 0, 0)
-                    .unwrap()
-                    + Days::new(1)
-            })
-            .unwrap_or(
-                DateTime::<Utc>::from(SystemTime::now())
-                    .date_naive()
-                    .and_hms_opt(0, 0, 0)
-                    .unwrap()
-                    + Days::new(1),
-            );
-        debug!("Querying documents: {:?} {:?} {:?}", user_id, start, end);
         let (tx, rx) = mpsc::channel(128);
 
+        let page_size = request.page_size;
+        let built = Ordering::new(request.order_by.as_ref())
+            .and_then(|ordering| build_query(request, &ordering));
+        let mut builder = match built {
+            Ok(builder) => builder,
+            Err(e) => {
+                // the channel is empty, so this can't fail
+                let _ = tx.try_send(Err(e));
+                return rx;
+            }
+        };
+        if page_size > 0 {
+            builder.push(" LIMIT ").push_bind(i64::from(page_size));
+        }
+
         tokio::spawn(async move {
-            let sql = "SELECT * FROM dc.documents WHERE user_id = $1 AND created_at >= $2 AND created_at < $3";
-            let mut docs = sqlx::query_as(&sql)
-                .bind(user_id)
-                .bind(start)
-                .bind(end)
-                .fetch_many(&pool);
+            let mut docs = builder.build_query_as().fetch_many(&pool);
             while let Some(ret) = docs.next().await {
                 match ret {
-                    Ok(Either::Left(r)) => {}
-                    Ok(Either::Right(r)) => {
-                        if tx.send(Ok(r)).await.is_err() {
+                    Ok(Either::Left(_)) => {}
+                    Ok(Either::Right(mut r)) => {
+                        let ret = fill_totals(&mut r).map(|_| r);
+                        if tx.send(ret).await.is_err() {
                             // rx is dropped, so client disconnected
                             break;
                         }
@@ -127,11 +372,378 @@ impl Dc for DcManager {
         });
         rx
     }
+
+    async fn aggregate(
+        &self,
+        request: abi::AggregateRequest,
+    ) -> Result<abi::AggregateResponse, abi::Error> {
+        let mut builder = build_aggregate(&request)?;
+        let rows = builder.build().fetch_all(&self.pool).await?;
+
+        let groups = rows
+            .iter()
+            .map(|row| {
+                let fields = request
+                    .fields
+                    .iter()
+                    .enumerate()
+                    .map(|(i, field)| {
+                        let sum: Option<String> = row.try_get(format!("sum_{}", i).as_str())?;
+                        let avg: Option<String> = row.try_get(format!("avg_{}", i).as_str())?;
+                        Ok(abi::FieldAggregate {
+                            field: field.clone(),
+                            sum: sum.unwrap_or_default(),
+                            avg: avg.unwrap_or_default(),
+                        })
+                    })
+                    .collect::<Result<Vec<_>, sqlx::Error>>()?;
+                Ok(abi::AggregateGroup {
+                    key: row.try_get("key")?,
+                    count: row.try_get("count")?,
+                    fields,
+                })
+            })
+            .collect::<Result<Vec<_>, sqlx::Error>>()?;
+
+        Ok(abi::AggregateResponse { groups })
+    }
+
+    async fn list(&self, request: abi::QueryRequest) -> Result<ListResponse, abi::Error> {
+        let page_size = match request.page_size {
+            0 => DEFAULT_PAGE_SIZE,
+            n if !(1..=MAX_PAGE_SIZE).contains(&n) => {
+                return Err(abi::Error::InvalidQuery(format!(
+                    "page size must be between 1 and {}",
+                    MAX_PAGE_SIZE
+                )))
+            }
+            n => n as usize,
+        };
+        let ordering = Ordering::new(request.order_by.as_ref())?;
+        let mut builder = build_query(request, &ordering)?;
+        // fetch one more row than asked for to know if there is a next page
+        builder.push(" LIMIT ").push_bind(page_size as i64 + 1);
+        let rows = builder.build().fetch_all(&self.pool).await?;
+
+        let next_page_token = if rows.len() > page_size {
+            let last = &rows[page_size - 1];
+            ordering
+                .page_token(last.try_get("sort_key")?, last.try_get("id")?)
+                .encode()
+        } else {
+            String::new()
+        };
+        let documents = rows
+            .iter()
+            .take(page_size)
+            .map(|row| {
+                let mut document = abi::Document::from_row(row)?;
+                fill_totals(&mut document)?;
+                Ok(document)
+            })
+            .collect::<Result<Vec<_>, abi::Error>>()?;
+
+        Ok(ListResponse {
+            documents,
+            next_page_token,
+        })
+    }
+}
+
+/// compose the select statement of a query request, shared by query and list
+fn build_query(
+    request: abi::QueryRequest,
+    ordering: &Ordering,
+) -> Result<QueryBuilder<'static, Postgres>, abi::Error> {
+    let abi::QueryRequest {
+        query, page_token, ..
+    } = request;
+    let query = query.unwrap_or_default();
+
+    let mut builder = QueryBuilder::new("SELECT *, ");
+    ordering.push_select(&mut builder);
+    builder.push(" FROM dc.documents");
+    push_conditions(&mut builder, &query)?;
+    if !page_token.is_empty() {
+        builder.push(" AND ");
+        ordering.push_after(&mut builder, PageToken::decode(&page_token)?)?;
+    }
+    ordering.push_order_by(&mut builder);
+    Ok(builder)
+}
+
+/// append the where clause selecting the documents of a query, an empty user id means every user
+pub(crate) fn push_conditions(
+    builder: &mut QueryBuilder<'_, Postgres>,
+    query: &abi::DocumentQuery,
+) -> Result<(), abi::Error> {
+    let time_range = TimeRange::new(query)?;
+    debug!("Querying documents: {:?} {:?}", query.user_id, time_range);
+
+    builder
+        .push(" WHERE collection = ")
+        .push_bind(collection_name(query.collection.clone()));
+    if !query.user_id.is_empty() {
+        let user_id = parse_user_id(&query.user_id)?;
+        builder.push(" AND user_id = ").push_bind(user_id);
+    }
+    if !query.org_id.is_empty() {
+        let org_id = Uuid::parse_str(&query.org_id)
+            .map_err(|_| abi::Error::InvalidQuery(format!("invalid org id: {}", query.org_id)))?;
+        builder.push(" AND org_id = ").push_bind(org_id);
+    }
+    if !query.store_id.is_empty() {
+        let store_id = Uuid::parse_str(&query.store_id).map_err(|_| {
+            abi::Error::InvalidQuery(format!("invalid store id: {}", query.store_id))
+        })?;
+        builder.push(" AND store_id = ").push_bind(store_id);
+    }
+    if !query.document_type.is_empty() {
+        builder
+            .push(" AND document_type = ")
+            .push_bind(query.document_type.clone());
+    }
+    time_range.push(builder);
+    if !query.include_deleted {
+        builder.push(" AND deleted_at IS NULL");
+    }
+    if let Some(filter) = &query.filter {
+        builder.push(" AND ");
+        push_filter(builder, filter)?;
+    }
+    Ok(())
+}
+
+/// the organization of a user submitting documents, given whether it is active if it exists
+pub(crate) fn user_org(
+    user_id: Uuid,
+    user: Option<(bool, Option<Uuid>)>,
+) -> Result<Option<Uuid>, abi::Error> {
+    match user {
+        Some((true, org_id)) => Ok(org_id),
+        Some((false, _)) => Err(abi::Error::InactiveUser(user_id.to_string())),
+        None => Err(abi::Error::UserNotFound),
+    }
+}
+
+/// the type a new document has in a collection, given the type of the collection if it exists
+pub(crate) fn resolve_type(
+    collection: String,
+    collection_type: Option<String>,
+    document_type: String,
+) -> Result<(String, String), abi::Error> {
+    let collection_type =
+        collection_type.ok_or_else(|| abi::Error::CollectionNotFound(collection.clone()))?;
+    match (collection_type.as_str(), document_type.as_str()) {
+        ("", _) => Ok((collection, document_type)),
+        (_, "") => Ok((collection, collection_type)),
+        (expected, actual) if expected == actual => Ok((collection, document_type)),
+        (expected, _) => Err(abi::Error::InvalidCollection(format!(
+            "{} only holds documents of type {}",
+            collection, expected
+        ))),
+    }
+}
+
+/// the named collection, or the default one if the name is empty
+pub(crate) fn collection_name(name: String) -> String {
+    if name.is_empty() {
+        DEFAULT_COLLECTION.to_string()
+    } else {
+        name
+    }
+}
+
+pub(crate) fn parse_id(id: &str) -> Result<Uuid, abi::Error> {
+    Uuid::parse_str(id).map_err(|_| abi::Error::InvalidDocumentId(id.to_string()))
+}
+
+pub(crate) fn parse_user_id(user_id: &str) -> Result<Uuid, abi::Error> {
+    Uuid::parse_str(user_id).map_err(|_| abi::Error::InvalidUserId(user_id.to_string()))
+}
+
+pub(crate) fn data_to_value(data: Struct) -> Result<serde_json::Value, abi::Error> {
+    serde_json::to_value(data).map_err(|e| abi::Error::InvalidData(e.to_string()))
+}
+
+/// validate the document items, compute their totals and convert them to the jsonb column value
+pub(crate) fn items_to_value(
+    mut items: Vec<DocumentItem>,
+) -> Result<serde_json::Value, abi::Error> {
+    for item in &items {
+        item.validate()?;
+    }
+    fill_items_totals(&mut items)?;
+    serde_json::to_value(items).map_err(|e| abi::Error::InvalidItem(e.to_string()))
 }
 
 impl DcManager {
     pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+        Self {
+            pool,
+            schemas: SchemaCache::default(),
+        }
+    }
+
+    /// create a document, looking up what it needs and inserting it on the given connection
+    pub(crate) async fn create_in(
+        &self,
+        conn: &mut PgConnection,
+        collection: String,
+        user_id: String,
+        data: Struct,
+        items: Vec<DocumentItem>,
+        document_type: String,
+    ) -> Result<abi::Document, abi::Error> {
+        let items = items_to_value(items)?;
+        let data = data_to_value(data)?;
+        let user_id = parse_user_id(&user_id)?;
+        let org_id = self.check_user(&mut *conn, user_id).await?;
+        let (collection, document_type) = self
+            .collection_type(&mut *conn, collection, document_type)
+            .await?;
+        let schema_version = self
+            .check_data(&mut *conn, org_id, &document_type, &data)
+            .await?;
+        let mut document: abi::Document = sqlx::query_as(
+            "INSERT INTO dc.documents (user_id, org_id, store_id, data, items, document_type, schema_version, collection)
+            SELECT id, org_id, store_id, $2, $3, $4, $5, $6 FROM dc.users WHERE id = $1 RETURNING *",
+        )
+        .bind(user_id)
+        .bind(data)
+        .bind(items)
+        .bind(document_type)
+        .bind(schema_version)
+        .bind(collection)
+        .fetch_one(conn)
+        .await?;
+
+        fill_totals(&mut document)?;
+        Ok(document)
+    }
+
+    /// update a document on the given connection, keeping the data or items not given
+    pub(crate) async fn update_in(
+        &self,
+        conn: &mut PgConnection,
+        collection: String,
+        id: abi::DocumentId,
+        data: Option<Struct>,
+        items: Option<Vec<DocumentItem>>,
+        expected_version: Option<i64>,
+    ) -> Result<abi::Document, abi::Error> {
+        info!("Updating document: {:?}", data);
+        let items = items.map(items_to_value).transpose()?;
+        let data = data.map(data_to_value).transpose()?;
+        let id = parse_id(&id)?;
+        let collection = collection_name(collection);
+        let (org_id, document_type) = self.document_type(&mut *conn, &collection, id).await?;
+        let schema_version = match &data {
+            Some(data) => {
+                self.check_data(&mut *conn, org_id, &document_type, data)
+                    .await?
+            }
+            None => None,
+        };
+        let document: Option<abi::Document> = sqlx::query_as(
+            "UPDATE dc.documents SET data = COALESCE($1, data), items = COALESCE($2, items),
+                schema_version = CASE WHEN $1::JSONB IS NULL THEN schema_version ELSE $5 END
+            WHERE id = $3 AND collection = $6 AND deleted_at IS NULL AND ($4::BIGINT IS NULL OR version = $4)
+            RETURNING *",
+        )
+        .bind(data)
+        .bind(items)
+        .bind(id)
+        .bind(expected_version)
+        .bind(schema_version)
+        .bind(&collection)
+        .fetch_optional(&mut *conn)
+        .await?;
+
+        let mut document = match document {
+            Some(document) => document,
+            None => {
+                return Err(self
+                    .precondition_error(conn, &collection, id, expected_version)
+                    .await)
+            }
+        };
+        fill_totals(&mut document)?;
+        Ok(document)
+    }
+
+    /// only known, active users may submit documents, return the organization of the user
+    pub(crate) async fn check_user(
+        &self,
+        executor: impl PgExecutor<'_>,
+        user_id: Uuid,
+    ) -> Result<Option<Uuid>, abi::Error> {
+        let user: Option<(bool, Option<Uuid>)> =
+            sqlx::query_as("SELECT active, org_id FROM dc.users WHERE id = $1")
+                .bind(user_id)
+                .fetch_optional(executor)
+                .await?;
+        user_org(user_id, user)
+    }
+
+    /// resolve the collection of a new document and the type it has in it
+    pub(crate) async fn collection_type(
+        &self,
+        executor: impl PgExecutor<'_>,
+        collection: String,
+        document_type: String,
+    ) -> Result<(String, String), abi::Error> {
+        let collection = collection_name(collection);
+        let collection_type: Option<String> = sqlx::query_scalar(
+            "SELECT document_type FROM dc.collections WHERE name = $1 AND state <> 'dropping'",
+        )
+        .bind(&collection)
+        .fetch_optional(executor)
+        .await?;
+        resolve_type(collection, collection_type, document_type)
+    }
+
+    /// get the organization and type of a document, also for documents in the trash or purged
+    pub(crate) async fn document_type(
+        &self,
+        executor: impl PgExecutor<'_>,
+        collection: &str,
+        id: Uuid,
+    ) -> Result<(Option<Uuid>, String), abi::Error> {
+        let typed = sqlx::query_as(
+            "SELECT org_id, document_type FROM dc.documents WHERE id = $1 AND collection = $2
+            UNION ALL SELECT org_id, document_type FROM dc.document_revisions
+            WHERE document_id = $1 AND collection = $2 LIMIT 1",
+        )
+        .bind(id)
+        .bind(collection)
+        .fetch_optional(executor)
+        .await?
+        .ok_or(abi::Error::NotFound)?;
+        Ok(typed)
+    }
+
+    /// explain why a conditional write matched no row: the document is gone or its version moved on
+    pub(crate) async fn precondition_error(
+        &self,
+        executor: impl PgExecutor<'_>,
+        collection: &str,
+        id: Uuid,
+        expected_version: Option<i64>,
+    ) -> abi::Error {
+        let actual: Result<Option<i64>, sqlx::Error> = sqlx::query_scalar(
+            "SELECT version FROM dc.documents WHERE id = $1 AND collection = $2 AND deleted_at IS NULL",
+        )
+        .bind(id)
+        .bind(collection)
+        .fetch_optional(executor)
+        .await;
+
+        match (actual, expected_version) {
+            (Ok(Some(actual)), Some(expected)) => abi::Error::Conflict { expected, actual },
+            (Ok(_), _) => abi::Error::NotFound,
+            (Err(e), _) => e.into(),
+        }
     }
 
     pub async fn from_config(config: &DbConfig) -> Result<Self, abi::Error> {
@@ -146,11 +758,53 @@ impl DcManager {
 
 #[cfg(test)]
 mod tests {
-    use sqlx::{postgres::PgRow, PgPool, Row};
+    use abi::{DocumentItem, DocumentQuery, Item0, Item1};
+    use chrono::Days;
+    use prost_wkt_types::Struct;
+    use sqlx::{postgres::PgRow, PgPool};
+
+    use super::*;
+    use crate::testing::{new_manager, USER_ID};
+
+    fn item0(name: &str, quantity: i32, price: &str) -> Item0 {
+        Item0 {
+            name: name.to_string(),
+            quantity,
+            price: price.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn to_request(query: DocumentQuery) -> abi::QueryRequest {
+        abi::QueryRequest {
+            query: Some(query),
+            ..Default::default()
+        }
+    }
+
+    /// a manager whose pool already knows the active test user
+    fn sample_items() -> Vec<DocumentItem> {
+        vec![DocumentItem {
+            name: "drinks".to_string(),
+            items1: vec![Item1 {
+                name: "juice".to_string(),
+                items: vec![item0("orange", 2, "1.25")],
+                ..Default::default()
+            }],
+            items0: vec![item0("water", 6, "0.50")],
+            ..Default::default()
+        }]
+    }
 
     #[sqlx::test]
     async fn basic_test(pool: PgPool) -> sqlx::Result<()> {
         let mut conn = pool.acquire().await?;
+        sqlx::query("CREATE TABLE foo (bar TEXT NOT NULL)")
+            .execute(conn.as_mut())
+            .await?;
+        sqlx::query("INSERT INTO foo (bar) VALUES ('foobar!')")
+            .execute(conn.as_mut())
+            .await?;
 
         let foo: PgRow = sqlx::query("SELECT * FROM foo")
             .fetch_one(conn.as_mut())
@@ -160,4 +814,1056 @@ mod tests {
 
         Ok(())
     }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn create_for_unknown_user_should_fail(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let err = manager
+            .create(
+                DEFAULT_COLLECTION.to_string(),
+                "3f0c6a51-2b7e-4c1d-8e8f-6d1b9a2c4e70".to_string(),
+                Struct::default(),
+                sample_items(),
+                String::new(),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, abi::Error::UserNotFound));
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn create_for_inactive_user_should_fail(pool: PgPool) {
+        let manager = new_manager(pool.clone()).await;
+        sqlx::query("UPDATE dc.users SET active = FALSE WHERE id = $1")
+            .bind(Uuid::parse_str(USER_ID).unwrap())
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let err = manager
+            .create(
+                DEFAULT_COLLECTION.to_string(),
+                USER_ID.to_string(),
+                Struct::default(),
+                sample_items(),
+                String::new(),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, abi::Error::InactiveUser(_)));
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn create_with_items_should_work(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let created = manager
+            .create(
+                DEFAULT_COLLECTION.to_string(),
+                USER_ID.to_string(),
+                Struct::default(),
+                sample_items(),
+                String::new(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(created.items.len(), 1);
+        assert_eq!(created.items[0].items1[0].total, "2.50");
+        assert_eq!(created.items[0].items0[0].total, "3.00");
+        assert_eq!(created.total, "5.50");
+
+        let fetched = manager
+            .get(DEFAULT_COLLECTION.to_string(), created.id.clone(), false)
+            .await
+            .unwrap();
+        assert_eq!(fetched.items, created.items);
+        assert_eq!(fetched.total, "5.50");
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn update_should_replace_items(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let created = manager
+            .create(
+                DEFAULT_COLLECTION.to_string(),
+                USER_ID.to_string(),
+                Struct::default(),
+                sample_items(),
+                String::new(),
+            )
+            .await
+            .unwrap();
+
+        let items = vec![DocumentItem {
+            name: "snacks".to_string(),
+            items1: vec![],
+            items0: vec![item0("chips", 1, "2.99")],
+            ..Default::default()
+        }];
+        let updated = manager
+            .update(
+                DEFAULT_COLLECTION.to_string(),
+                created.id,
+                None,
+                Some(items),
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(updated.items[0].name, "snacks");
+        assert_eq!(updated.total, "2.99");
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn update_should_keep_what_is_not_given(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let data = |store: &str| -> Struct {
+            serde_json::from_value(serde_json::json!({ "store": store })).unwrap()
+        };
+        let created = manager
+            .create(
+                DEFAULT_COLLECTION.to_string(),
+                USER_ID.to_string(),
+                data("north"),
+                sample_items(),
+                String::new(),
+            )
+            .await
+            .unwrap();
+
+        let updated = manager
+            .update(
+                DEFAULT_COLLECTION.to_string(),
+                created.id.clone(),
+                Some(data("south")),
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(updated.data, Some(data("south")));
+        assert_eq!(updated.items, created.items);
+
+        let updated = manager
+            .update(
+                DEFAULT_COLLECTION.to_string(),
+                created.id,
+                None,
+                Some(vec![]),
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(updated.data, Some(data("south")));
+        assert!(updated.items.is_empty());
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn create_with_invalid_price_should_fail(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let items = vec![DocumentItem {
+            name: "drinks".to_string(),
+            items1: vec![],
+            items0: vec![item0("water", 1, "cheap")],
+            ..Default::default()
+        }];
+        let err = manager
+            .create(
+                DEFAULT_COLLECTION.to_string(),
+                USER_ID.to_string(),
+                Struct::default(),
+                items,
+                String::new(),
+            )
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, abi::Error::InvalidItem(_)));
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn update_should_bump_version(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let created = manager
+            .create(
+                DEFAULT_COLLECTION.to_string(),
+                USER_ID.to_string(),
+                Struct::default(),
+                sample_items(),
+                String::new(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(created.version, 1);
+
+        let updated = manager
+            .update(
+                DEFAULT_COLLECTION.to_string(),
+                created.id,
+                None,
+                Some(sample_items()),
+                Some(1),
+            )
+            .await
+            .unwrap();
+        assert_eq!(updated.version, 2);
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn update_with_stale_version_should_conflict(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let created = manager
+            .create(
+                DEFAULT_COLLECTION.to_string(),
+                USER_ID.to_string(),
+                Struct::default(),
+                sample_items(),
+                String::new(),
+            )
+            .await
+            .unwrap();
+        manager
+            .update(
+                DEFAULT_COLLECTION.to_string(),
+                created.id.clone(),
+                Some(Struct::default()),
+                None,
+                Some(1),
+            )
+            .await
+            .unwrap();
+
+        let err = manager
+            .update(
+                DEFAULT_COLLECTION.to_string(),
+                created.id.clone(),
+                None,
+                Some(sample_items()),
+                Some(1),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            abi::Error::Conflict {
+                expected: 1,
+                actual: 2
+            }
+        ));
+
+        let err = manager
+            .delete(DEFAULT_COLLECTION.to_string(), created.id, Some(1))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, abi::Error::Conflict { .. }));
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn delete_with_current_version_should_work(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let created = manager
+            .create(
+                DEFAULT_COLLECTION.to_string(),
+                USER_ID.to_string(),
+                Struct::default(),
+                sample_items(),
+                String::new(),
+            )
+            .await
+            .unwrap();
+
+        let deleted = manager
+            .delete(DEFAULT_COLLECTION.to_string(), created.id.clone(), Some(1))
+            .await
+            .unwrap();
+        assert_eq!(deleted.id, created.id);
+
+        let err = manager
+            .delete(DEFAULT_COLLECTION.to_string(), created.id, Some(1))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, abi::Error::NotFound));
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn patch_should_update_a_single_item(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let created = manager
+            .create(
+                DEFAULT_COLLECTION.to_string(),
+                USER_ID.to_string(),
+                Struct::default(),
+                sample_items(),
+                String::new(),
+            )
+            .await
+            .unwrap();
+
+        let patch = Patch::JsonPatch(abi::JsonPatch {
+            operations: vec![abi::JsonPatchOperation {
+                op: "replace".to_string(),
+                path: "/items/0/items0/0/quantity".to_string(),
+                value: Some(prost_wkt_types::Value::from(10.0)),
+                from: String::new(),
+            }],
+        });
+        let patched = manager
+            .patch(
+                DEFAULT_COLLECTION.to_string(),
+                created.id.clone(),
+                patch,
+                Some(created.version),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(patched.items[0].items0[0].quantity, 10);
+        assert_eq!(patched.items[0].items0[0].total, "5.00");
+        assert_eq!(patched.total, "7.50");
+        assert_eq!(patched.version, 2);
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn patch_with_invalid_item_should_leave_document_untouched(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let created = manager
+            .create(
+                DEFAULT_COLLECTION.to_string(),
+                USER_ID.to_string(),
+                Struct::default(),
+                sample_items(),
+                String::new(),
+            )
+            .await
+            .unwrap();
+
+        let patch: Struct = serde_json::from_value(serde_json::json!({
+            "items": [{ "name": "drinks", "items0": [{ "name": "water", "price": "-1" }] }]
+        }))
+        .unwrap();
+        let err = manager
+            .patch(
+                DEFAULT_COLLECTION.to_string(),
+                created.id.clone(),
+                Patch::MergePatch(patch),
+                None,
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, abi::Error::InvalidItem(_)));
+
+        let fetched = manager
+            .get(DEFAULT_COLLECTION.to_string(), created.id, false)
+            .await
+            .unwrap();
+        assert_eq!(fetched.version, 1);
+        assert_eq!(fetched.items, created.items);
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn every_mutation_should_record_a_revision(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let created = manager
+            .create(
+                DEFAULT_COLLECTION.to_string(),
+                USER_ID.to_string(),
+                Struct::default(),
+                sample_items(),
+                String::new(),
+            )
+            .await
+            .unwrap();
+        manager
+            .update(
+                DEFAULT_COLLECTION.to_string(),
+                created.id.clone(),
+                None,
+                Some(vec![]),
+                None,
+            )
+            .await
+            .unwrap();
+        manager
+            .delete(DEFAULT_COLLECTION.to_string(), created.id.clone(), None)
+            .await
+            .unwrap();
+
+        let revisions = manager
+            .list_revisions(DEFAULT_COLLECTION.to_string(), created.id.clone())
+            .await
+            .unwrap();
+        let summary: Vec<_> = revisions
+            .iter()
+            .map(|r| (r.revision, r.items.len(), r.deleted))
+            .collect();
+        assert_eq!(summary, vec![(1, 1, false), (2, 0, false), (3, 0, true)]);
+
+        let first = manager
+            .get_revision(DEFAULT_COLLECTION.to_string(), created.id, 1)
+            .await
+            .unwrap();
+        assert_eq!(first.items, created.items);
+        assert_eq!(first.version, 1);
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn restore_should_bring_back_old_content(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let created = manager
+            .create(
+                DEFAULT_COLLECTION.to_string(),
+                USER_ID.to_string(),
+                Struct::default(),
+                sample_items(),
+                String::new(),
+            )
+            .await
+            .unwrap();
+        manager
+            .update(
+                DEFAULT_COLLECTION.to_string(),
+                created.id.clone(),
+                Some(Struct::default()),
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        let err = manager
+            .restore(
+                DEFAULT_COLLECTION.to_string(),
+                created.id.clone(),
+                1,
+                Some(1),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, abi::Error::Conflict { .. }));
+
+        let restored = manager
+            .restore(
+                DEFAULT_COLLECTION.to_string(),
+                created.id.clone(),
+                1,
+                Some(2),
+            )
+            .await
+            .unwrap();
+        assert_eq!(restored.items, created.items);
+        assert_eq!(restored.version, 3);
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn restore_should_recreate_deleted_document(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let created = manager
+            .create(
+                DEFAULT_COLLECTION.to_string(),
+                USER_ID.to_string(),
+                Struct::default(),
+                sample_items(),
+                String::new(),
+            )
+            .await
+            .unwrap();
+        manager
+            .delete(DEFAULT_COLLECTION.to_string(), created.id.clone(), None)
+            .await
+            .unwrap();
+
+        let restored = manager
+            .restore(DEFAULT_COLLECTION.to_string(), created.id.clone(), 1, None)
+            .await
+            .unwrap();
+        assert_eq!(restored.id, created.id);
+        assert_eq!(restored.version, 3);
+
+        let fetched = manager
+            .get(DEFAULT_COLLECTION.to_string(), created.id, false)
+            .await
+            .unwrap();
+        assert_eq!(fetched.items, created.items);
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn delete_should_move_document_to_trash(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let created = manager
+            .create(
+                DEFAULT_COLLECTION.to_string(),
+                USER_ID.to_string(),
+                Struct::default(),
+                sample_items(),
+                String::new(),
+            )
+            .await
+            .unwrap();
+
+        let deleted = manager
+            .delete(DEFAULT_COLLECTION.to_string(), created.id.clone(), None)
+            .await
+            .unwrap();
+        assert!(deleted.deleted_at.is_some());
+
+        assert!(manager
+            .get(DEFAULT_COLLECTION.to_string(), created.id.clone(), false)
+            .await
+            .is_err());
+        let trashed = manager
+            .get(DEFAULT_COLLECTION.to_string(), created.id.clone(), true)
+            .await
+            .unwrap();
+        assert_eq!(trashed.deleted_at, deleted.deleted_at);
+
+        let err = manager
+            .update(
+                DEFAULT_COLLECTION.to_string(),
+                created.id.clone(),
+                Some(Struct::default()),
+                None,
+                None,
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, abi::Error::NotFound));
+
+        let undeleted = manager
+            .undelete(DEFAULT_COLLECTION.to_string(), created.id.clone())
+            .await
+            .unwrap();
+        assert!(undeleted.deleted_at.is_none());
+        assert_eq!(undeleted.items, created.items);
+        assert!(manager
+            .undelete(DEFAULT_COLLECTION.to_string(), created.id)
+            .await
+            .is_err());
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn query_should_skip_deleted_documents_by_default(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let kept = manager
+            .create(
+                DEFAULT_COLLECTION.to_string(),
+                USER_ID.to_string(),
+                Struct::default(),
+                sample_items(),
+                String::new(),
+            )
+            .await
+            .unwrap();
+        let deleted = manager
+            .create(
+                DEFAULT_COLLECTION.to_string(),
+                USER_ID.to_string(),
+                Struct::default(),
+                sample_items(),
+                String::new(),
+            )
+            .await
+            .unwrap();
+        manager
+            .delete(DEFAULT_COLLECTION.to_string(), deleted.id, None)
+            .await
+            .unwrap();
+
+        let query = DocumentQuery {
+            user_id: USER_ID.to_string(),
+            ..Default::default()
+        };
+        let mut rx = manager.query(to_request(query.clone())).await;
+        let mut ids = vec![];
+        while let Some(doc) = rx.recv().await {
+            ids.push(doc.unwrap().id);
+        }
+        assert_eq!(ids, vec![kept.id]);
+
+        let mut rx = manager
+            .query(to_request(DocumentQuery {
+                include_deleted: true,
+                ..query
+            }))
+            .await;
+        let mut count = 0;
+        while let Some(doc) = rx.recv().await {
+            doc.unwrap();
+            count += 1;
+        }
+        assert_eq!(count, 2);
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn purge_should_only_remove_old_tombstones(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let live = manager
+            .create(
+                DEFAULT_COLLECTION.to_string(),
+                USER_ID.to_string(),
+                Struct::default(),
+                sample_items(),
+                String::new(),
+            )
+            .await
+            .unwrap();
+        let trashed = manager
+            .create(
+                DEFAULT_COLLECTION.to_string(),
+                USER_ID.to_string(),
+                Struct::default(),
+                sample_items(),
+                String::new(),
+            )
+            .await
+            .unwrap();
+        manager
+            .delete(DEFAULT_COLLECTION.to_string(), trashed.id.clone(), None)
+            .await
+            .unwrap();
+
+        let purged = manager.purge(Utc::now() - Days::new(1)).await.unwrap();
+        assert_eq!(purged, 0);
+
+        let purged = manager.purge(Utc::now() + Days::new(1)).await.unwrap();
+        assert_eq!(purged, 1);
+        assert!(manager
+            .get(DEFAULT_COLLECTION.to_string(), trashed.id.clone(), true)
+            .await
+            .is_err());
+        assert!(manager
+            .get(DEFAULT_COLLECTION.to_string(), live.id, false)
+            .await
+            .is_ok());
+        assert_eq!(
+            manager
+                .owner(DEFAULT_COLLECTION.to_string(), trashed.id)
+                .await
+                .unwrap()
+                .user_id,
+            USER_ID
+        );
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn query_with_filter_should_match_item_contents(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let data: Struct = serde_json::from_value(serde_json::json!({ "store": "north" })).unwrap();
+        let with_water = manager
+            .create(
+                DEFAULT_COLLECTION.to_string(),
+                USER_ID.to_string(),
+                data,
+                sample_items(),
+                String::new(),
+            )
+            .await
+            .unwrap();
+        manager
+            .create(
+                DEFAULT_COLLECTION.to_string(),
+                USER_ID.to_string(),
+                Struct::default(),
+                vec![],
+                String::new(),
+            )
+            .await
+            .unwrap();
+
+        let contains: prost_wkt_types::Value = serde_json::from_value(serde_json::json!([
+            { "items0": [{ "name": "water" }] }
+        ]))
+        .unwrap();
+        let filter = abi::Filter {
+            filter: Some(abi::filter::Filter::And(abi::FilterList {
+                filters: vec![
+                    abi::Filter {
+                        filter: Some(abi::filter::Filter::Field(abi::FieldFilter {
+                            path: "items".to_string(),
+                            op: abi::FilterOperator::Contains as i32,
+                            value: Some(contains),
+                        })),
+                    },
+                    abi::Filter {
+                        filter: Some(abi::filter::Filter::Field(abi::FieldFilter {
+                            path: "data.store".to_string(),
+                            op: abi::FilterOperator::In as i32,
+                            value: Some(
+                                serde_json::from_value(serde_json::json!(["north", "south"]))
+                                    .unwrap(),
+                            ),
+                        })),
+                    },
+                ],
+            })),
+        };
+        let mut rx = manager
+            .query(to_request(DocumentQuery {
+                user_id: USER_ID.to_string(),
+                filter: Some(filter),
+                ..Default::default()
+            }))
+            .await;
+
+        let mut ids = vec![];
+        while let Some(doc) = rx.recv().await {
+            ids.push(doc.unwrap().id);
+        }
+        assert_eq!(ids, vec![with_water.id]);
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn query_with_invalid_filter_should_fail(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let mut rx = manager
+            .query(to_request(DocumentQuery {
+                user_id: USER_ID.to_string(),
+                filter: Some(abi::Filter::default()),
+                ..Default::default()
+            }))
+            .await;
+
+        let err = rx.recv().await.unwrap().unwrap_err();
+        assert!(matches!(err, abi::Error::InvalidFilter(_)));
+        assert!(rx.recv().await.is_none());
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn list_should_page_through_documents_in_order(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        for store in ["c", "a", "b", "d", "e"] {
+            let data: Struct =
+                serde_json::from_value(serde_json::json!({ "store": store })).unwrap();
+            manager
+                .create(
+                    DEFAULT_COLLECTION.to_string(),
+                    USER_ID.to_string(),
+                    data,
+                    vec![],
+                    String::new(),
+                )
+                .await
+                .unwrap();
+        }
+
+        let mut request = abi::QueryRequest {
+            query: Some(DocumentQuery {
+                user_id: USER_ID.to_string(),
+                ..Default::default()
+            }),
+            order_by: Some(abi::OrderBy {
+                field: abi::OrderField::Path as i32,
+                path: "data.store".to_string(),
+                descending: true,
+            }),
+            page_size: 2,
+            page_token: String::new(),
+        };
+        let mut stores = vec![];
+        let mut pages = 0;
+        loop {
+            let page = manager.list(request.clone()).await.unwrap();
+            pages += 1;
+            for document in page.documents {
+                let data = serde_json::to_value(document.data.unwrap()).unwrap();
+                stores.push(data["store"].as_str().unwrap().to_string());
+            }
+            if page.next_page_token.is_empty() {
+                break;
+            }
+            request.page_token = page.next_page_token;
+        }
+
+        assert_eq!(pages, 3);
+        assert_eq!(stores, vec!["e", "d", "c", "b", "a"]);
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn list_by_created_at_should_resume_after_token(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let mut ids = vec![];
+        for _ in 0..3 {
+            let document = manager
+                .create(
+                    DEFAULT_COLLECTION.to_string(),
+                    USER_ID.to_string(),
+                    Struct::default(),
+                    vec![],
+                    String::new(),
+                )
+                .await
+                .unwrap();
+            ids.push(document.id);
+        }
+
+        let request = abi::QueryRequest {
+            page_size: 1,
+            ..to_request(DocumentQuery {
+                user_id: USER_ID.to_string(),
+                ..Default::default()
+            })
+        };
+        let first = manager.list(request.clone()).await.unwrap();
+        assert_eq!(first.documents[0].id, ids[0]);
+
+        let rest = manager
+            .list(abi::QueryRequest {
+                page_size: 10,
+                page_token: first.next_page_token,
+                ..request
+            })
+            .await
+            .unwrap();
+        let rest: Vec<_> = rest.documents.into_iter().map(|d| d.id).collect();
+        assert_eq!(rest, ids[1..]);
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn query_in_exact_mode_should_honour_bounds(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let first = manager
+            .create(
+                DEFAULT_COLLECTION.to_string(),
+                USER_ID.to_string(),
+                Struct::default(),
+                vec![],
+                String::new(),
+            )
+            .await
+            .unwrap();
+        let second = manager
+            .create(
+                DEFAULT_COLLECTION.to_string(),
+                USER_ID.to_string(),
+                Struct::default(),
+                vec![],
+                String::new(),
+            )
+            .await
+            .unwrap();
+
+        let query = DocumentQuery {
+            user_id: USER_ID.to_string(),
+            start: first.created_at.clone(),
+            end: second.created_at.clone(),
+            range_mode: abi::TimeRangeMode::Exact as i32,
+            ..Default::default()
+        };
+        let exclusive = manager.list(to_request(query.clone())).await.unwrap();
+        let ids: Vec<_> = exclusive.documents.into_iter().map(|d| d.id).collect();
+        assert_eq!(ids, vec![first.id.clone()]);
+
+        let inclusive = manager
+            .list(to_request(DocumentQuery {
+                end_inclusive: true,
+                ..query
+            }))
+            .await
+            .unwrap();
+        let ids: Vec<_> = inclusive.documents.into_iter().map(|d| d.id).collect();
+        assert_eq!(ids, vec![first.id, second.id]);
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn aggregate_should_group_and_sum(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        for (store, amount) in [("north", 1.5), ("south", 2.0), ("north", 3.0)] {
+            let data: Struct =
+                serde_json::from_value(serde_json::json!({ "store": store, "amount": amount }))
+                    .unwrap();
+            manager
+                .create(
+                    DEFAULT_COLLECTION.to_string(),
+                    USER_ID.to_string(),
+                    data,
+                    sample_items(),
+                    String::new(),
+                )
+                .await
+                .unwrap();
+        }
+
+        let response = manager
+            .aggregate(abi::AggregateRequest {
+                query: None,
+                group_by: Some(abi::GroupBy {
+                    kind: abi::GroupKind::Path as i32,
+                    path: "data.store".to_string(),
+                }),
+                fields: vec!["data.amount".to_string(), "total".to_string()],
+            })
+            .await
+            .unwrap();
+
+        let groups: Vec<_> = response
+            .groups
+            .iter()
+            .map(|g| {
+                (
+                    g.key.as_str(),
+                    g.count,
+                    g.fields[0].sum.as_str(),
+                    g.fields[0].avg.as_str(),
+                    g.fields[1].sum.as_str(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            groups,
+            vec![
+                ("north", 2, "4.5", "2.25", "11.00"),
+                ("south", 1, "2.0", "2.00", "5.50"),
+            ]
+        );
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn aggregate_by_day_should_use_time_zone(pool: PgPool) {
+        let manager = new_manager(pool.clone()).await;
+        let created = manager
+            .create(
+                DEFAULT_COLLECTION.to_string(),
+                USER_ID.to_string(),
+                Struct::default(),
+                vec![],
+                String::new(),
+            )
+            .await
+            .unwrap();
+        sqlx::query("UPDATE dc.documents SET created_at = '2023-11-20T23:30:00Z' WHERE id = $1")
+            .bind(Uuid::parse_str(&created.id).unwrap())
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let response = manager
+            .aggregate(abi::AggregateRequest {
+                query: Some(DocumentQuery {
+                    time_zone: "Europe/Berlin".to_string(),
+                    ..Default::default()
+                }),
+                group_by: Some(abi::GroupBy {
+                    kind: abi::GroupKind::Day as i32,
+                    path: String::new(),
+                }),
+                fields: vec![],
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(response.groups.len(), 1);
+        assert_eq!(response.groups[0].key, "2023-11-21");
+        assert_eq!(response.groups[0].count, 1);
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn documents_should_be_scoped_by_organization_and_store(pool: PgPool) {
+        let manager = new_manager(pool.clone()).await;
+        let org_id = Uuid::parse_str("0d6c2c8a-4f3e-4b9a-9d51-7e2f1c3b5a10").unwrap();
+        let store_id = Uuid::parse_str("5c1f0c4e-9c55-4c1e-9a3b-1f9f2d8e6a01").unwrap();
+        sqlx::query("INSERT INTO dc.organizations (id, name) VALUES ($1, 'North')")
+            .bind(org_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO dc.stores (id, org_id, name) VALUES ($1, $2, 'Harbour')")
+            .bind(store_id)
+            .bind(org_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("UPDATE dc.users SET role = 'store', org_id = $1, store_id = $2 WHERE id = $3")
+            .bind(org_id)
+            .bind(store_id)
+            .bind(Uuid::parse_str(USER_ID).unwrap())
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let document = manager
+            .create(
+                DEFAULT_COLLECTION.to_string(),
+                USER_ID.to_string(),
+                Struct::default(),
+                vec![],
+                String::new(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(document.org_id, org_id.to_string());
+        assert_eq!(document.store_id, store_id.to_string());
+        assert_eq!(
+            manager
+                .owner(DEFAULT_COLLECTION.to_string(), document.id)
+                .await
+                .unwrap()
+                .org_id,
+            org_id.to_string()
+        );
+
+        let count = |query: DocumentQuery| {
+            let manager = manager.clone();
+            async move {
+                manager
+                    .list(to_request(query))
+                    .await
+                    .unwrap()
+                    .documents
+                    .len()
+            }
+        };
+        assert_eq!(
+            count(DocumentQuery {
+                org_id: org_id.to_string(),
+                store_id: store_id.to_string(),
+                ..Default::default()
+            })
+            .await,
+            1
+        );
+        assert_eq!(
+            count(DocumentQuery {
+                org_id: "3f0c6a51-2b7e-4c1d-8e8f-6d1b9a2c4e70".to_string(),
+                ..Default::default()
+            })
+            .await,
+            0
+        );
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn malformed_ids_should_be_rejected_without_panicking(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let err = manager
+            .get(
+                DEFAULT_COLLECTION.to_string(),
+                "not-a-uuid".to_string(),
+                false,
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, abi::Error::InvalidDocumentId(_)));
+
+        let err = manager
+            .create(
+                DEFAULT_COLLECTION.to_string(),
+                "not-a-uuid".to_string(),
+                Struct::default(),
+                vec![],
+                String::new(),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, abi::Error::InvalidUserId(_)));
+
+        let err = manager
+            .get(
+                DEFAULT_COLLECTION.to_string(),
+                "3f0c6a51-2b7e-4c1d-8e8f-6d1b9a2c4e70".to_string(),
+                false,
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, abi::Error::NotFound));
+    }
 }
@@ -0,0 +1,214 @@
+use abi::{OrderBy, OrderField};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::{types::Uuid, Postgres, QueryBuilder};
+
+use crate::filter::parse_path;
+
+/// how documents are sorted, `id` breaks ties so the order is total and pages are stable
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Ordering {
+    key: SortKey,
+    descending: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum SortKey {
+    CreatedAt,
+    UpdatedAt,
+    Path(&'static str, Vec<String>),
+}
+
+/// position of the last document of a page, handed to the client as an opaque string
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct PageToken {
+    /// the ordering the token was issued for
+    o: String,
+    /// sort key of the last document
+    k: Value,
+    /// id of the last document
+    id: Uuid,
+}
+
+impl Ordering {
+    pub fn new(order_by: Option<&OrderBy>) -> Result<Self, abi::Error> {
+        let Some(order_by) = order_by else {
+            return Ok(Self {
+                key: SortKey::CreatedAt,
+                descending: false,
+            });
+        };
+
+        let field = OrderField::try_from(order_by.field)
+            .map_err(|_| invalid_query(format!("unknown order field: {}", order_by.field)))?;
+        let key = match field {
+            OrderField::CreatedAt => SortKey::CreatedAt,
+            OrderField::UpdatedAt => SortKey::UpdatedAt,
+            OrderField::Path => {
+                let (column, path) = parse_path(&order_by.path).map_err(invalid_query)?;
+                SortKey::Path(column, path)
+            }
+        };
+        Ok(Self {
+            key,
+            descending: order_by.descending,
+        })
+    }
+
+    /// select the sort key as jsonb in a `sort_key` column, to build the next page token from
+    pub fn push_select(&self, builder: &mut QueryBuilder<'_, Postgres>) {
+        builder.push("to_jsonb(");
+        self.push_key(builder);
+        builder.push(") AS sort_key");
+    }
+
+    /// restrict the query to documents after the given page token
+    pub fn push_after(
+        &self,
+        builder: &mut QueryBuilder<'_, Postgres>,
+        token: PageToken,
+    ) -> Result<(), abi::Error> {
+        if token.o != self.fingerprint() {
+            return Err(invalid_query("page token was issued for a different order"));
+        }
+
+        builder.push("(");
+        self.push_key(builder);
+        builder.push(", id)");
+        builder.push(if self.descending { " < " } else { " > " });
+        builder.push("(");
+        match self.key {
+            SortKey::CreatedAt | SortKey::UpdatedAt => {
+                builder.push("(");
+                builder.push_bind(token.k);
+                builder.push("::JSONB #>> '{}')::TIMESTAMPTZ");
+            }
+            SortKey::Path(..) => {
+                builder.push_bind(token.k);
+                builder.push("::JSONB");
+            }
+        }
+        builder.push(", ");
+        builder.push_bind(token.id);
+        builder.push(")");
+        Ok(())
+    }
+
+    pub fn push_order_by(&self, builder: &mut QueryBuilder<'_, Postgres>) {
+        let direction = if self.descending { " DESC" } else { " ASC" };
+        builder.push(" ORDER BY ");
+        self.push_key(builder);
+        builder.push(direction);
+        builder.push(", id");
+        builder.push(direction);
+    }
+
+    pub fn page_token(&self, sort_key: Value, id: Uuid) -> PageToken {
+        PageToken {
+            o: self.fingerprint(),
+            k: sort_key,
+            id,
+        }
+    }
+
+    fn push_key(&self, builder: &mut QueryBuilder<'_, Postgres>) {
+        match &self.key {
+            SortKey::CreatedAt => {
+                builder.push("created_at");
+            }
+            SortKey::UpdatedAt => {
+                builder.push("updated_at");
+            }
+            SortKey::Path(column, path) => {
+                // missing values sort as json null instead of dropping out of the comparison
+                builder.push(format!("COALESCE({} #> ", column));
+                builder.push_bind(path.clone());
+                builder.push("::TEXT[], 'null'::JSONB)");
+            }
+        }
+    }
+
+    fn fingerprint(&self) -> String {
+        let key = match &self.key {
+            SortKey::CreatedAt => "created_at".to_string(),
+            SortKey::UpdatedAt => "updated_at".to_string(),
+            SortKey::Path(column, path) => format!("{}.{}", column, path.join(".")),
+        };
+        let direction = if self.descending { "desc" } else { "asc" };
+        format!("{} {}", key, direction)
+    }
+}
+
+impl PageToken {
+    pub fn encode(&self) -> String {
+        let json = serde_json::to_vec(self).expect("page token is always serializable");
+        URL_SAFE_NO_PAD.encode(json)
+    }
+
+    pub fn decode(token: &str) -> Result<Self, abi::Error> {
+        let json = URL_SAFE_NO_PAD
+            .decode(token)
+            .map_err(|_| invalid_query("malformed page token"))?;
+        serde_json::from_slice(&json).map_err(|_| invalid_query("malformed page token"))
+    }
+}
+
+fn invalid_query(e: impl ToString) -> abi::Error {
+    abi::Error::InvalidQuery(e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn to_sql(ordering: &Ordering, token: PageToken) -> Result<String, abi::Error> {
+        let mut builder = QueryBuilder::new("");
+        ordering.push_after(&mut builder, token)?;
+        ordering.push_order_by(&mut builder);
+        Ok(builder.into_sql())
+    }
+
+    #[test]
+    fn page_token_should_round_trip() {
+        let ordering = Ordering::new(None).unwrap();
+        let token = ordering.page_token(json!("2023-11-26T10:00:00.123456+00:00"), Uuid::nil());
+
+        assert_eq!(PageToken::decode(&token.encode()).unwrap(), token);
+        assert!(PageToken::decode("not a token").is_err());
+    }
+
+    #[test]
+    fn descending_path_order_should_compile() {
+        let ordering = Ordering::new(Some(&OrderBy {
+            field: OrderField::Path as i32,
+            path: "data.store".to_string(),
+            descending: true,
+        }))
+        .unwrap();
+        let token = ordering.page_token(json!("north"), Uuid::nil());
+
+        assert_eq!(
+            to_sql(&ordering, token).unwrap(),
+            "(COALESCE(data #> $1::TEXT[], 'null'::JSONB), id) < ($2::JSONB, $3) ORDER BY COALESCE(data #> $4::TEXT[], 'null'::JSONB) DESC, id DESC"
+        );
+    }
+
+    #[test]
+    fn page_token_for_another_order_should_fail() {
+        let created = Ordering::new(None).unwrap();
+        let updated = Ordering::new(Some(&OrderBy {
+            field: OrderField::UpdatedAt as i32,
+            ..Default::default()
+        }))
+        .unwrap();
+        let token = created.page_token(json!("2023-11-26T10:00:00+00:00"), Uuid::nil());
+
+        assert!(matches!(
+            to_sql(&updated, token),
+            Err(abi::Error::InvalidQuery(_))
+        ));
+    }
+}
@@ -0,0 +1,176 @@
+use abi::{patch_request::Patch, Document, DocumentItem, JsonPatch};
+use prost_wkt_types::Struct;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+/// the part of a document a patch is allowed to touch
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub(crate) struct DocumentContent {
+    #[serde(default)]
+    pub data: Struct,
+    #[serde(default)]
+    pub items: Vec<DocumentItem>,
+}
+
+impl From<Document> for DocumentContent {
+    fn from(document: Document) -> Self {
+        Self {
+            data: document.data.unwrap_or_default(),
+            items: document.items,
+        }
+    }
+}
+
+impl DocumentContent {
+    /// apply a merge patch or a json patch, the content is left untouched on failure
+    pub fn apply(self, patch: Patch) -> Result<Self, abi::Error> {
+        let mut value = serde_json::to_value(self).map_err(invalid_patch)?;
+        // numbers of the patch are normalized, so those of the document must be too to compare equal
+        normalize_numbers(&mut value);
+        match patch {
+            Patch::MergePatch(patch) => {
+                let mut patch = serde_json::to_value(patch).map_err(invalid_patch)?;
+                normalize_numbers(&mut patch);
+                json_patch::merge(&mut value, &patch);
+            }
+            Patch::JsonPatch(patch) => {
+                let patch = to_json_patch(patch)?;
+                json_patch::patch(&mut value, &patch).map_err(invalid_patch)?;
+            }
+        }
+        serde_json::from_value(value).map_err(invalid_patch)
+    }
+}
+
+fn to_json_patch(patch: JsonPatch) -> Result<json_patch::Patch, abi::Error> {
+    let operations = patch
+        .operations
+        .into_iter()
+        .map(|op| {
+            let mut value = json!({ "op": op.op, "path": op.path });
+            if let Some(v) = op.value {
+                let mut v = serde_json::to_value(v).map_err(invalid_patch)?;
+                normalize_numbers(&mut v);
+                value["value"] = v;
+            }
+            if !op.from.is_empty() {
+                value["from"] = Value::String(op.from);
+            }
+            Ok(value)
+        })
+        .collect::<Result<Vec<_>, abi::Error>>()?;
+
+    serde_json::from_value(Value::Array(operations)).map_err(invalid_patch)
+}
+
+/// protobuf values only carry doubles, turn the integral ones back into integers
+/// so they can land in integer fields such as `quantity`
+fn normalize_numbers(value: &mut Value) {
+    match value {
+        Value::Number(n) => {
+            if let Some(f) = n.as_f64() {
+                if f.fract() == 0.0 && f.abs() < i64::MAX as f64 {
+                    *value = Value::from(f as i64);
+                }
+            }
+        }
+        Value::Array(values) => values.iter_mut().for_each(normalize_numbers),
+        Value::Object(map) => map.values_mut().for_each(normalize_numbers),
+        _ => {}
+    }
+}
+
+fn invalid_patch(e: impl ToString) -> abi::Error {
+    abi::Error::InvalidPatch(e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use abi::{Item0, JsonPatchOperation};
+    use prost_wkt_types::Value as PbValue;
+
+    use super::*;
+
+    fn content() -> DocumentContent {
+        let data: Struct =
+            serde_json::from_value(json!({ "store": "north", "note": "x" })).unwrap();
+        DocumentContent {
+            data,
+            items: vec![DocumentItem {
+                name: "drinks".to_string(),
+                items0: vec![Item0 {
+                    name: "water".to_string(),
+                    quantity: 6,
+                    price: "0.50".to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+        }
+    }
+
+    #[test]
+    fn merge_patch_should_update_and_remove_fields() {
+        let patch: Struct =
+            serde_json::from_value(json!({ "data": { "store": "south", "note": null } })).unwrap();
+        let content = content().apply(Patch::MergePatch(patch)).unwrap();
+
+        let data = serde_json::to_value(content.data).unwrap();
+        assert_eq!(data, json!({ "store": "south" }));
+        assert_eq!(content.items.len(), 1);
+    }
+
+    #[test]
+    fn json_patch_should_change_a_single_item() {
+        let patch = JsonPatch {
+            operations: vec![JsonPatchOperation {
+                op: "replace".to_string(),
+                path: "/items/0/items0/0/quantity".to_string(),
+                value: Some(PbValue::from(8.0)),
+                from: String::new(),
+            }],
+        };
+        let content = content().apply(Patch::JsonPatch(patch)).unwrap();
+
+        assert_eq!(content.items[0].items0[0].quantity, 8);
+    }
+
+    #[test]
+    fn json_patch_with_failed_test_should_be_rejected() {
+        let patch = JsonPatch {
+            operations: vec![JsonPatchOperation {
+                op: "test".to_string(),
+                path: "/data/store".to_string(),
+                value: Some(PbValue::from("south".to_string())),
+                from: String::new(),
+            }],
+        };
+        let err = content().apply(Patch::JsonPatch(patch)).unwrap_err();
+
+        assert!(matches!(err, abi::Error::InvalidPatch(_)));
+    }
+
+    #[test]
+    fn json_patch_test_should_compare_numbers_by_value() {
+        let data: Struct = serde_json::from_value(json!({ "total": 2, "rate": 0.5 })).unwrap();
+        let content = DocumentContent { data, ..content() };
+        let op = |op: &str, path: &str, value: f64| JsonPatchOperation {
+            op: op.to_string(),
+            path: path.to_string(),
+            value: Some(PbValue::from(value)),
+            from: String::new(),
+        };
+        let patch = JsonPatch {
+            operations: vec![
+                op("test", "/data/total", 2.0),
+                op("test", "/data/rate", 0.5),
+                op("test", "/items/0/items0/0/quantity", 6.0),
+                op("replace", "/data/total", 3.0),
+            ],
+        };
+        let content = content.apply(Patch::JsonPatch(patch)).unwrap();
+
+        let data = serde_json::to_value(content.data).unwrap();
+        assert_eq!(data, json!({ "total": 3.0, "rate": 0.5 }));
+    }
+}
@@ -0,0 +1,560 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    sync::{Arc, Mutex},
+};
+
+use abi::{DocumentSchema, FieldViolation};
+use async_trait::async_trait;
+use jsonschema::{paths::PathChunk, JSONSchema};
+use prost_wkt_types::Struct;
+use serde_json::Value;
+use sqlx::{types::Uuid, PgExecutor};
+
+use crate::{DcManager, Schemas};
+
+#[async_trait]
+impl Schemas for DcManager {
+    async fn register_schema(
+        &self,
+        org_id: String,
+        document_type: String,
+        schema: Struct,
+    ) -> Result<DocumentSchema, abi::Error> {
+        let org_id = parse_org_id(&org_id)?;
+        let schema =
+            serde_json::to_value(schema).map_err(|e| abi::Error::InvalidSchema(e.to_string()))?;
+        compile(&schema)?;
+
+        let mut tx = self.pool.begin().await?;
+        // serializes registrations within the organization, so versions are handed out in order
+        sqlx::query("SELECT id FROM dc.organizations WHERE id = $1 FOR UPDATE")
+            .bind(org_id)
+            .fetch_optional(&mut *tx)
+            .await?
+            .ok_or(abi::Error::OrganizationNotFound)?;
+        let schema = sqlx::query_as(
+            "INSERT INTO dc.document_schemas (org_id, document_type, version, schema)
+            SELECT $1, $2, COALESCE(MAX(version), 0) + 1, $3
+            FROM dc.document_schemas WHERE org_id = $1 AND document_type = $2 RETURNING *",
+        )
+        .bind(org_id)
+        .bind(document_type)
+        .bind(schema)
+        .fetch_one(&mut *tx)
+        .await?;
+        tx.commit().await?;
+
+        Ok(schema)
+    }
+
+    async fn get_schema(
+        &self,
+        org_id: String,
+        document_type: String,
+        version: Option<i32>,
+    ) -> Result<DocumentSchema, abi::Error> {
+        let org_id = parse_org_id(&org_id)?;
+        sqlx::query_as(
+            "SELECT * FROM dc.document_schemas
+            WHERE org_id = $1 AND document_type = $2 AND ($3::INT IS NULL OR version = $3)
+            ORDER BY version DESC LIMIT 1",
+        )
+        .bind(org_id)
+        .bind(&document_type)
+        .bind(version)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(abi::Error::SchemaNotFound(document_type))
+    }
+
+    async fn list_schemas(
+        &self,
+        org_id: String,
+        document_type: Option<String>,
+    ) -> Result<Vec<DocumentSchema>, abi::Error> {
+        let org_id = parse_org_id(&org_id)?;
+        let schemas = sqlx::query_as(
+            "SELECT * FROM dc.document_schemas WHERE org_id = $1 AND ($2::TEXT IS NULL OR document_type = $2)
+            ORDER BY document_type, version",
+        )
+        .bind(org_id)
+        .bind(document_type)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(schemas)
+    }
+}
+
+impl DcManager {
+    /// Check data against the latest schema of its document type, return the version it was checked
+    /// against. Untyped documents, with an empty document type, are not checked.
+    pub(crate) async fn check_data(
+        &self,
+        executor: impl PgExecutor<'_>,
+        org_id: Option<Uuid>,
+        document_type: &str,
+        data: &Value,
+    ) -> Result<Option<i32>, abi::Error> {
+        if document_type.is_empty() {
+            return Ok(None);
+        }
+        let not_found = || abi::Error::SchemaNotFound(document_type.to_string());
+        let org_id = org_id.ok_or_else(not_found)?;
+        let (version, schema): (i32, Value) = sqlx::query_as(
+            "SELECT version, schema FROM dc.document_schemas
+            WHERE org_id = $1 AND document_type = $2 ORDER BY version DESC LIMIT 1",
+        )
+        .bind(org_id)
+        .bind(document_type)
+        .fetch_optional(executor)
+        .await?
+        .ok_or_else(not_found)?;
+
+        let compiled = self
+            .schemas
+            .compiled(org_id, document_type, version, &schema)?;
+        validate(&compiled, data)?;
+        Ok(Some(version))
+    }
+}
+
+/// a schema compiled for validation, shared by every check against it
+type Compiled = Arc<JSONSchema>;
+/// organization, document type and version of a schema
+type SchemaKey = (Uuid, String, i32);
+
+/// Compiled schemas by organization, document type and version. A version of a schema never
+/// changes once registered, so a schema is compiled once and never goes stale.
+#[derive(Clone, Default)]
+pub(crate) struct SchemaCache(Arc<Mutex<HashMap<SchemaKey, Compiled>>>);
+
+impl SchemaCache {
+    /// the compiled schema of a version, compiling it on first use
+    fn compiled(
+        &self,
+        org_id: Uuid,
+        document_type: &str,
+        version: i32,
+        schema: &Value,
+    ) -> Result<Compiled, abi::Error> {
+        let key = (org_id, document_type.to_string(), version);
+        if let Some(compiled) = self.0.lock().unwrap().get(&key) {
+            return Ok(compiled.clone());
+        }
+        let compiled = Arc::new(compile(schema)?);
+        self.0.lock().unwrap().insert(key, compiled.clone());
+        Ok(compiled)
+    }
+}
+
+impl fmt::Debug for SchemaCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let len = self
+            .0
+            .lock()
+            .map(|schemas| schemas.len())
+            .unwrap_or_default();
+        f.debug_tuple("SchemaCache").field(&len).finish()
+    }
+}
+
+/// The latest schema of every document type the documents of a batch have, looked up once for all
+/// of them.
+pub(crate) struct LatestSchemas {
+    schemas: HashMap<(Uuid, String), (i32, Result<Compiled, String>)>,
+}
+
+impl LatestSchemas {
+    pub(crate) async fn load(
+        executor: impl PgExecutor<'_>,
+        cache: &SchemaCache,
+        types: impl IntoIterator<Item = (Option<Uuid>, &str)>,
+    ) -> Result<Self, abi::Error> {
+        let types: HashSet<(Uuid, &str)> = types
+            .into_iter()
+            .filter_map(|(org_id, document_type)| Some((org_id?, document_type)))
+            .filter(|(_, document_type)| !document_type.is_empty())
+            .collect();
+        let (org_ids, document_types): (Vec<Uuid>, Vec<&str>) = types.into_iter().unzip();
+        let schemas: Vec<(Uuid, String, i32, Value)> = sqlx::query_as(
+            "SELECT DISTINCT ON (s.org_id, s.document_type) s.org_id, s.document_type, s.version, s.schema
+            FROM dc.document_schemas s
+            JOIN UNNEST($1::UUID[], $2::TEXT[]) AS t(org_id, document_type)
+                ON s.org_id = t.org_id AND s.document_type = t.document_type
+            ORDER BY s.org_id, s.document_type, s.version DESC",
+        )
+        .bind(org_ids)
+        .bind(document_types)
+        .fetch_all(executor)
+        .await?;
+
+        let schemas = schemas
+            .into_iter()
+            .map(|(org_id, document_type, version, schema)| {
+                let compiled = cache
+                    .compiled(org_id, &document_type, version, &schema)
+                    .map_err(|e| e.to_string());
+                ((org_id, document_type), (version, compiled))
+            })
+            .collect();
+        Ok(Self { schemas })
+    }
+
+    /// check data like `DcManager::check_data` does
+    pub(crate) fn check(
+        &self,
+        org_id: Option<Uuid>,
+        document_type: &str,
+        data: &Value,
+    ) -> Result<Option<i32>, abi::Error> {
+        if document_type.is_empty() {
+            return Ok(None);
+        }
+        let (version, compiled) = org_id
+            .and_then(|org_id| self.schemas.get(&(org_id, document_type.to_string())))
+            .ok_or_else(|| abi::Error::SchemaNotFound(document_type.to_string()))?;
+        let compiled = compiled
+            .as_ref()
+            .map_err(|e| abi::Error::InvalidSchema(e.clone()))?;
+        validate(compiled, data)?;
+        Ok(Some(*version))
+    }
+}
+
+fn validate(compiled: &JSONSchema, data: &Value) -> Result<(), abi::Error> {
+    if let Err(errors) = compiled.validate(data) {
+        let violations = errors
+            .map(|e| FieldViolation {
+                field: field_path(e.instance_path.iter()),
+                description: e.to_string(),
+            })
+            .collect();
+        return Err(abi::Error::SchemaViolation(violations));
+    }
+    Ok(())
+}
+
+fn compile(schema: &Value) -> Result<JSONSchema, abi::Error> {
+    JSONSchema::compile(schema).map_err(|e| abi::Error::InvalidSchema(e.to_string()))
+}
+
+/// the path of a value in the data, in the dotted form filters use, e.g. `data.lines[0].price`
+fn field_path<'a>(chunks: impl Iterator<Item = &'a PathChunk>) -> String {
+    let mut path = "data".to_string();
+    for chunk in chunks {
+        match chunk {
+            PathChunk::Property(name) => {
+                path.push('.');
+                path.push_str(name);
+            }
+            PathChunk::Index(index) => path.push_str(&format!("[{}]", index)),
+            PathChunk::Keyword(keyword) => {
+                path.push('.');
+                path.push_str(keyword);
+            }
+        }
+    }
+    path
+}
+
+fn parse_org_id(org_id: &str) -> Result<Uuid, abi::Error> {
+    Uuid::parse_str(org_id).map_err(|_| abi::Error::OrganizationNotFound)
+}
+
+#[cfg(test)]
+mod tests {
+    use abi::patch_request::Patch;
+    use serde_json::json;
+    use sqlx::PgPool;
+
+    use super::*;
+    use crate::{Batches, Dc, DEFAULT_COLLECTION};
+
+    const USER_ID: &str = "a8e1a8ec-5a21-4d4b-a2b0-8d5d0b2a7c11";
+    const ORG_ID: &str = "0d6c2c8a-4f3e-4b9a-9d51-7e2f1c3b5a10";
+
+    fn to_struct(value: Value) -> Struct {
+        serde_json::from_value(value).unwrap()
+    }
+
+    /// a manager with an organization whose receipts need a store and a list of lines
+    async fn new_manager(pool: PgPool) -> DcManager {
+        let org_id = Uuid::parse_str(ORG_ID).unwrap();
+        sqlx::query("INSERT INTO dc.organizations (id, name) VALUES ($1, 'Test')")
+            .bind(org_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query(
+            "INSERT INTO dc.users (id, display_name, email, role, org_id) VALUES ($1, 'Test', 'test@example.com', 'admin', $2)",
+        )
+        .bind(Uuid::parse_str(USER_ID).unwrap())
+        .bind(org_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let manager = DcManager::new(pool);
+        let schema = json!({
+            "type": "object",
+            "required": ["store", "lines"],
+            "properties": {
+                "store": { "type": "string" },
+                "lines": {
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "properties": { "price": { "type": "number" } }
+                    }
+                }
+            }
+        });
+        manager
+            .register_schema(ORG_ID.to_string(), "receipt".to_string(), to_struct(schema))
+            .await
+            .unwrap();
+        manager
+    }
+
+    fn violations(err: abi::Error) -> Vec<String> {
+        match err {
+            abi::Error::SchemaViolation(violations) => {
+                violations.into_iter().map(|v| v.field).collect()
+            }
+            other => panic!("expected schema violations, got {:?}", other),
+        }
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn register_schema_should_add_versions(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let second = manager
+            .register_schema(
+                ORG_ID.to_string(),
+                "receipt".to_string(),
+                to_struct(json!({ "type": "object" })),
+            )
+            .await
+            .unwrap();
+        assert_eq!(second.version, 2);
+
+        let latest = manager
+            .get_schema(ORG_ID.to_string(), "receipt".to_string(), None)
+            .await
+            .unwrap();
+        assert_eq!(latest.version, 2);
+        let first = manager
+            .get_schema(ORG_ID.to_string(), "receipt".to_string(), Some(1))
+            .await
+            .unwrap();
+        assert_eq!(first.version, 1);
+        let schemas = manager
+            .list_schemas(ORG_ID.to_string(), None)
+            .await
+            .unwrap();
+        assert_eq!(schemas.len(), 2);
+
+        let err = manager
+            .register_schema(
+                ORG_ID.to_string(),
+                "receipt".to_string(),
+                to_struct(json!({ "type": "no such type" })),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, abi::Error::InvalidSchema(_)));
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn create_should_reject_data_not_matching_the_schema(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let data = json!({ "lines": [{ "price": 1.5 }, { "price": "free" }] });
+        let err = manager
+            .create(
+                DEFAULT_COLLECTION.to_string(),
+                USER_ID.to_string(),
+                to_struct(data),
+                vec![],
+                "receipt".to_string(),
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(violations(err), ["data.lines[1].price", "data"]);
+
+        let data = json!({ "store": "north", "lines": [{ "price": 1.5 }] });
+        let document = manager
+            .create(
+                DEFAULT_COLLECTION.to_string(),
+                USER_ID.to_string(),
+                to_struct(data),
+                vec![],
+                "receipt".to_string(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(document.document_type, "receipt");
+        assert_eq!(document.schema_version, 1);
+
+        let err = manager
+            .create(
+                DEFAULT_COLLECTION.to_string(),
+                USER_ID.to_string(),
+                Struct::default(),
+                vec![],
+                "invoice".to_string(),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, abi::Error::SchemaNotFound(_)));
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn batches_should_be_checked_per_document(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let receipt = |data: Value, document_type: &str| abi::CreateRequest {
+            user_id: USER_ID.to_string(),
+            data: Some(to_struct(data)),
+            document_type: document_type.to_string(),
+            ..Default::default()
+        };
+        let requests = vec![
+            receipt(json!({ "store": "north", "lines": [] }), "receipt"),
+            receipt(json!({ "lines": [] }), "receipt"),
+            receipt(json!({}), "invoice"),
+            receipt(json!({ "anything": true }), ""),
+        ];
+        let mut results = manager.batch_create(requests, false).await.unwrap();
+        assert_eq!(results[0].as_ref().unwrap().schema_version, 1);
+        assert_eq!(violations(results.remove(1).unwrap_err()), ["data"]);
+        assert!(matches!(results[1], Err(abi::Error::SchemaNotFound(_))));
+        assert_eq!(results[2].as_ref().unwrap().schema_version, 0);
+
+        let id = results[0].as_ref().unwrap().id.clone();
+        let update = |data: Option<Value>| abi::UpdateRequest {
+            id: id.clone(),
+            data: data.map(to_struct),
+            items: vec![abi::DocumentItem {
+                name: "tea".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let requests = vec![update(Some(json!({ "store": 1 })))];
+        let err = manager
+            .batch_update(requests, false)
+            .await
+            .unwrap()
+            .remove(0)
+            .unwrap_err();
+        assert_eq!(violations(err), ["data.store", "data"]);
+        // items alone are not checked against the schema
+        let updated = manager
+            .batch_update(vec![update(None)], false)
+            .await
+            .unwrap();
+        assert_eq!(updated[0].as_ref().unwrap().version, 2);
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn update_and_patch_should_be_checked_against_the_latest_schema(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let data = json!({ "store": "north", "lines": [] });
+        let document = manager
+            .create(
+                DEFAULT_COLLECTION.to_string(),
+                USER_ID.to_string(),
+                to_struct(data),
+                vec![],
+                "receipt".to_string(),
+            )
+            .await
+            .unwrap();
+
+        let err = manager
+            .update(
+                DEFAULT_COLLECTION.to_string(),
+                document.id.clone(),
+                Some(to_struct(json!({ "store": 7, "lines": [] }))),
+                None,
+                None,
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(violations(err), ["data.store"]);
+
+        let patch = Patch::MergePatch(to_struct(json!({ "data": { "store": null } })));
+        let err = manager
+            .patch(
+                DEFAULT_COLLECTION.to_string(),
+                document.id.clone(),
+                patch,
+                None,
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(violations(err), ["data"]);
+
+        manager
+            .register_schema(
+                ORG_ID.to_string(),
+                "receipt".to_string(),
+                to_struct(json!({ "type": "object" })),
+            )
+            .await
+            .unwrap();
+        let patch = Patch::MergePatch(to_struct(json!({ "data": { "store": null } })));
+        let patched = manager
+            .patch(DEFAULT_COLLECTION.to_string(), document.id, patch, None)
+            .await
+            .unwrap();
+        assert_eq!(patched.schema_version, 2);
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn each_schema_version_should_be_compiled_once(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let create = || {
+            manager.create(
+                DEFAULT_COLLECTION.to_string(),
+                USER_ID.to_string(),
+                to_struct(json!({ "store": "north", "lines": [] })),
+                vec![],
+                "receipt".to_string(),
+            )
+        };
+        let cached = || manager.schemas.0.lock().unwrap().len();
+        create().await.unwrap();
+        create().await.unwrap();
+        assert_eq!(cached(), 1);
+
+        manager
+            .register_schema(
+                ORG_ID.to_string(),
+                "receipt".to_string(),
+                to_struct(json!({ "type": "object" })),
+            )
+            .await
+            .unwrap();
+        let document = create().await.unwrap();
+        assert_eq!(document.schema_version, 2);
+        assert_eq!(cached(), 2);
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn untyped_documents_should_not_be_checked(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let document = manager
+            .create(
+                DEFAULT_COLLECTION.to_string(),
+                USER_ID.to_string(),
+                to_struct(json!({ "anything": true })),
+                vec![],
+                String::new(),
+            )
+            .await
+            .unwrap();
+        assert!(document.document_type.is_empty());
+        assert_eq!(document.schema_version, 0);
+    }
+}
@@ -0,0 +1,21 @@
+//! Fixtures for the tests of this crate and of the crates built on it, enabled by the `testing`
+//! feature outside of this crate.
+
+use sqlx::{types::Uuid, PgPool};
+
+use crate::DcManager;
+
+/// an admin without an organization, created by `new_manager`
+pub const USER_ID: &str = "a8e1a8ec-5a21-4d4b-a2b0-8d5d0b2a7c11";
+
+/// a manager whose pool already knows the user `USER_ID`
+pub async fn new_manager(pool: PgPool) -> DcManager {
+    sqlx::query(
+        "INSERT INTO dc.users (id, display_name, email, role) VALUES ($1, 'Test', 'test@example.com', 'admin')",
+    )
+    .bind(Uuid::parse_str(USER_ID).unwrap())
+    .execute(&pool)
+    .await
+    .unwrap();
+    DcManager::new(pool)
+}
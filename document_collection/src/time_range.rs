@@ -0,0 +1,242 @@
+use abi::{timestamp_to_utc, DocumentQuery, FieldViolation, TimeField, TimeRangeMode};
+use chrono::{DateTime, Days, Duration, NaiveDate, Offset, TimeZone, Utc};
+use chrono_tz::Tz;
+use sqlx::{Postgres, QueryBuilder};
+
+/// the time bounds of a document query, resolved to utc instants
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct TimeRange {
+    field: &'static str,
+    start: Option<DateTime<Utc>>,
+    end: Option<DateTime<Utc>>,
+    end_inclusive: bool,
+}
+
+impl TimeRange {
+    pub fn new(query: &DocumentQuery) -> Result<Self, abi::Error> {
+        Self::resolve(query, Utc::now())
+    }
+
+    fn resolve(query: &DocumentQuery, now: DateTime<Utc>) -> Result<Self, abi::Error> {
+        let field = match TimeField::try_from(query.time_field) {
+            Ok(TimeField::CreatedAt) => "created_at",
+            Ok(TimeField::UpdatedAt) => "updated_at",
+            Err(_) => return Err(abi::Error::InvalidTime),
+        };
+        let start = query
+            .start
+            .as_ref()
+            .map(|t| timestamp_to_utc(t).ok_or_else(|| out_of_range("start")))
+            .transpose()?;
+        let end = query
+            .end
+            .as_ref()
+            .map(|t| timestamp_to_utc(t).ok_or_else(|| out_of_range("end")))
+            .transpose()?;
+        if let (Some(start), Some(end)) = (start, end) {
+            if start > end {
+                return Err(abi::Error::InvalidTime);
+            }
+        }
+
+        match TimeRangeMode::try_from(query.range_mode) {
+            Ok(TimeRangeMode::Exact) => Ok(Self {
+                field,
+                start,
+                end,
+                end_inclusive: query.end_inclusive,
+            }),
+            Ok(TimeRangeMode::Days) => {
+                let tz = parse_time_zone(&query.time_zone)?;
+                let start = start
+                    .map(|t| {
+                        local_date(tz, t)
+                            .and_then(|date| start_of_day(tz, date))
+                            .ok_or_else(|| out_of_range("start"))
+                    })
+                    .transpose()?;
+                let end = local_date(tz, end.unwrap_or(now))
+                    .and_then(|date| date.checked_add_days(Days::new(1)))
+                    .and_then(|date| start_of_day(tz, date))
+                    .ok_or_else(|| out_of_range("end"))?;
+                Ok(Self {
+                    field,
+                    start,
+                    end: Some(end),
+                    end_inclusive: false,
+                })
+            }
+            Err(_) => Err(abi::Error::InvalidTime),
+        }
+    }
+
+    /// the column the range applies to
+    pub fn field(&self) -> &'static str {
+        self.field
+    }
+
+    pub fn push(&self, builder: &mut QueryBuilder<'_, Postgres>) {
+        if let Some(start) = self.start {
+            builder
+                .push(format!(" AND {} >= ", self.field))
+                .push_bind(start);
+        }
+        if let Some(end) = self.end {
+            let op = if self.end_inclusive { "<=" } else { "<" };
+            builder
+                .push(format!(" AND {} {} ", self.field, op))
+                .push_bind(end);
+        }
+    }
+}
+
+pub(crate) fn parse_time_zone(time_zone: &str) -> Result<Tz, abi::Error> {
+    if time_zone.is_empty() {
+        return Ok(Tz::UTC);
+    }
+    time_zone
+        .parse()
+        .map_err(|_| abi::Error::InvalidTimeZone(time_zone.to_string()))
+}
+
+/// a bound of the query that chrono cannot represent
+fn out_of_range(field: &str) -> abi::Error {
+    abi::Error::InvalidRequest(vec![FieldViolation {
+        field: format!("query.{}", field),
+        description: "out of range".to_string(),
+    }])
+}
+
+/// the local day of an instant, None when it lies outside the supported range
+fn local_date(tz: Tz, t: DateTime<Utc>) -> Option<NaiveDate> {
+    let offset = tz.offset_from_utc_datetime(&t.naive_utc()).fix();
+    t.naive_utc()
+        .checked_add_signed(Duration::seconds(offset.local_minus_utc().into()))
+        .map(|local| local.date())
+}
+
+/// the first instant of a local day, which is not always midnight when DST starts at midnight,
+/// None when it lies outside the supported range
+fn start_of_day(tz: Tz, date: NaiveDate) -> Option<DateTime<Utc>> {
+    let midnight = date.and_hms_opt(0, 0, 0)?;
+    let first = (0..24)
+        .map(|hour| midnight + Duration::hours(hour))
+        .find_map(|local| Some((local, tz.offset_from_local_datetime(&local).earliest()?)));
+    let utc = match first {
+        Some((local, offset)) => {
+            local.checked_sub_signed(Duration::seconds(offset.fix().local_minus_utc().into()))?
+        }
+        None => midnight,
+    };
+    Some(Utc.from_utc_datetime(&utc))
+}
+
+#[cfg(test)]
+mod tests {
+    use prost_wkt_types::Timestamp;
+
+    use super::*;
+
+    fn timestamp(s: &str) -> Option<Timestamp> {
+        Some(Timestamp::from(s.parse::<DateTime<Utc>>().unwrap()))
+    }
+
+    fn utc(s: &str) -> Option<DateTime<Utc>> {
+        Some(s.parse().unwrap())
+    }
+
+    #[test]
+    fn days_mode_should_widen_to_local_days() {
+        let query = DocumentQuery {
+            start: timestamp("2023-11-20T23:30:00Z"),
+            end: timestamp("2023-11-21T10:00:00Z"),
+            time_zone: "Europe/Berlin".to_string(),
+            ..Default::default()
+        };
+        let range = TimeRange::resolve(&query, Utc::now()).unwrap();
+
+        // 23:30 UTC is already the 21st in Berlin
+        assert_eq!(range.start, utc("2023-11-20T23:00:00Z"));
+        assert_eq!(range.end, utc("2023-11-21T23:00:00Z"));
+        assert!(!range.end_inclusive);
+    }
+
+    #[test]
+    fn days_mode_without_bounds_should_end_today() {
+        let now = "2023-11-21T10:00:00Z".parse().unwrap();
+        let range = TimeRange::resolve(&DocumentQuery::default(), now).unwrap();
+
+        assert_eq!(range.start, None);
+        assert_eq!(range.end, utc("2023-11-22T00:00:00Z"));
+    }
+
+    #[test]
+    fn exact_mode_should_keep_bounds() {
+        let query = DocumentQuery {
+            start: timestamp("2023-11-20T08:15:00Z"),
+            end: timestamp("2023-11-20T08:45:00Z"),
+            range_mode: TimeRangeMode::Exact as i32,
+            time_field: TimeField::UpdatedAt as i32,
+            end_inclusive: true,
+            ..Default::default()
+        };
+        let range = TimeRange::resolve(&query, Utc::now()).unwrap();
+
+        let mut builder = QueryBuilder::new("");
+        range.push(&mut builder);
+        assert_eq!(
+            builder.into_sql(),
+            " AND updated_at >= $1 AND updated_at <= $2"
+        );
+        assert_eq!(range.start, utc("2023-11-20T08:15:00Z"));
+        assert_eq!(range.end, utc("2023-11-20T08:45:00Z"));
+    }
+
+    #[test]
+    fn invalid_range_or_time_zone_should_fail() {
+        let query = DocumentQuery {
+            start: timestamp("2023-11-21T00:00:00Z"),
+            end: timestamp("2023-11-20T00:00:00Z"),
+            ..Default::default()
+        };
+        assert!(matches!(
+            TimeRange::resolve(&query, Utc::now()),
+            Err(abi::Error::InvalidTime)
+        ));
+
+        let query = DocumentQuery {
+            time_zone: "Mars/Olympus".to_string(),
+            ..Default::default()
+        };
+        assert!(matches!(
+            TimeRange::resolve(&query, Utc::now()),
+            Err(abi::Error::InvalidTimeZone(_))
+        ));
+    }
+
+    #[test]
+    fn bounds_out_of_range_should_fail() {
+        let query = DocumentQuery {
+            start: Some(Timestamp {
+                seconds: i64::MIN,
+                nanos: 0,
+            }),
+            ..Default::default()
+        };
+        assert!(matches!(
+            TimeRange::resolve(&query, Utc::now()),
+            Err(abi::Error::InvalidRequest(_))
+        ));
+
+        // the last day chrono knows has no day after it
+        let query = DocumentQuery {
+            end: Some(Timestamp::from(DateTime::<Utc>::MAX_UTC)),
+            time_zone: "Asia/Tokyo".to_string(),
+            ..Default::default()
+        };
+        assert!(matches!(
+            TimeRange::resolve(&query, Utc::now()),
+            Err(abi::Error::InvalidRequest(_))
+        ));
+    }
+}
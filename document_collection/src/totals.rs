@@ -0,0 +1,121 @@
+use abi::{Document, DocumentItem, Item0, Item1};
+use rust_decimal::{Decimal, RoundingStrategy};
+
+/// number of decimal places totals are rounded to
+const TOTAL_SCALE: u32 = 2;
+
+/// compute the per-item, per-section and grand totals of a document
+pub(crate) fn fill_totals(document: &mut Document) -> Result<(), abi::Error> {
+    let total = fill_items_totals(&mut document.items)?;
+    document.total = total.to_string();
+    Ok(())
+}
+
+/// compute the totals of every item, return the sum of all of them
+pub(crate) fn fill_items_totals(items: &mut [DocumentItem]) -> Result<Decimal, abi::Error> {
+    let mut total = Decimal::ZERO;
+    for item in items.iter_mut() {
+        total += fill_document_item_total(item)?;
+    }
+    Ok(round(total))
+}
+
+fn fill_document_item_total(item: &mut DocumentItem) -> Result<Decimal, abi::Error> {
+    let mut total = Decimal::ZERO;
+    for item in item.items1.iter_mut() {
+        total += fill_item1_total(item)?;
+    }
+    for item in item.items0.iter_mut() {
+        total += fill_item0_total(item)?;
+    }
+    let total = round(total);
+    item.total = total.to_string();
+    Ok(total)
+}
+
+fn fill_item1_total(item: &mut Item1) -> Result<Decimal, abi::Error> {
+    let mut total = Decimal::ZERO;
+    for item in item.items.iter_mut() {
+        total += fill_item0_total(item)?;
+    }
+    let total = round(total);
+    item.total = total.to_string();
+    Ok(total)
+}
+
+fn fill_item0_total(item: &mut Item0) -> Result<Decimal, abi::Error> {
+    let price = item.parse_price()?;
+    let total = price
+        .checked_mul(Decimal::from(item.quantity))
+        .ok_or_else(|| abi::Error::InvalidItem(format!("total overflow for {}", item.name)))?;
+    let total = round(total);
+    item.total = total.to_string();
+    Ok(total)
+}
+
+fn round(value: Decimal) -> Decimal {
+    let mut value =
+        value.round_dp_with_strategy(TOTAL_SCALE, RoundingStrategy::MidpointAwayFromZero);
+    value.rescale(TOTAL_SCALE);
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item0(name: &str, quantity: i32, price: &str) -> Item0 {
+        Item0 {
+            name: name.to_string(),
+            quantity,
+            price: price.to_string(),
+            total: String::new(),
+        }
+    }
+
+    #[test]
+    fn fill_totals_should_sum_every_level() {
+        let mut document = Document {
+            items: vec![DocumentItem {
+                name: "drinks".to_string(),
+                items1: vec![Item1 {
+                    name: "juice".to_string(),
+                    items: vec![item0("orange", 3, "0.10"), item0("apple", 1, "1.005")],
+                    total: String::new(),
+                }],
+                items0: vec![item0("water", 6, "0.50")],
+                total: String::new(),
+            }],
+            ..Default::default()
+        };
+
+        fill_totals(&mut document).unwrap();
+
+        let item = &document.items[0];
+        assert_eq!(item.items1[0].items[0].total, "0.30");
+        assert_eq!(item.items1[0].items[1].total, "1.01");
+        assert_eq!(item.items1[0].total, "1.31");
+        assert_eq!(item.items0[0].total, "3.00");
+        assert_eq!(item.total, "4.31");
+        assert_eq!(document.total, "4.31");
+    }
+
+    #[test]
+    fn fill_totals_of_empty_document_should_be_zero() {
+        let mut document = Document::default();
+        fill_totals(&mut document).unwrap();
+        assert_eq!(document.total, "0.00");
+    }
+
+    #[test]
+    fn fill_totals_with_invalid_price_should_fail() {
+        let mut items = vec![DocumentItem {
+            name: "drinks".to_string(),
+            items1: vec![],
+            items0: vec![item0("water", 1, "1,50")],
+            total: String::new(),
+        }];
+        let err = fill_items_totals(&mut items).unwrap_err();
+        assert!(matches!(err, abi::Error::InvalidItem(_)));
+    }
+}
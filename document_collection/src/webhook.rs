@@ -0,0 +1,283 @@
+use std::time::Duration;
+
+use abi::{ChangeType, DocumentChange, Webhook, WebhookDelivery};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_json::{json, Value};
+use sqlx::types::Uuid;
+use tracing::warn;
+
+use crate::{change::read_changes_query, matches, totals::fill_totals, DcManager, Webhooks};
+
+/// A delivery due to be posted, leased to whoever claimed it.
+#[derive(Debug, Clone)]
+pub struct PendingDelivery {
+    pub id: String,
+    pub url: String,
+    pub secret: String,
+    /// the json body to post
+    pub payload: Value,
+    /// attempts made before this one
+    pub attempts: i32,
+}
+
+#[async_trait]
+impl Webhooks for DcManager {
+    async fn create_webhook(&self, webhook: Webhook) -> Result<Webhook, abi::Error> {
+        let org_id = parse_optional_id(&webhook.org_id)?;
+        let event_types = webhook
+            .event_types
+            .iter()
+            .map(|t| {
+                ChangeType::try_from(*t)
+                    .map(|t| t.as_str_name())
+                    .map_err(|_| abi::Error::InvalidWebhook(format!("unknown change type: {}", t)))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let query = serde_json::to_value(webhook.query.unwrap_or_default())
+            .map_err(|e| abi::Error::InvalidWebhook(e.to_string()))?;
+        let secret = if webhook.secret.is_empty() {
+            format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple())
+        } else {
+            webhook.secret
+        };
+
+        let webhook = sqlx::query_as(
+            "INSERT INTO dc.webhooks (org_id, url, event_types, query, secret) VALUES ($1, $2, $3, $4, $5) RETURNING *",
+        )
+        .bind(org_id)
+        .bind(webhook.url)
+        .bind(event_types)
+        .bind(query)
+        .bind(secret)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(webhook)
+    }
+
+    async fn get_webhook(&self, id: String) -> Result<Webhook, abi::Error> {
+        let webhook: Webhook = sqlx::query_as("SELECT * FROM dc.webhooks WHERE id = $1")
+            .bind(parse_webhook_id(&id)?)
+            .fetch_optional(&self.pool)
+            .await?
+            .ok_or(abi::Error::WebhookNotFound(id))?;
+        Ok(without_secret(webhook))
+    }
+
+    async fn list_webhooks(&self, org_id: String) -> Result<Vec<Webhook>, abi::Error> {
+        let webhooks: Vec<Webhook> = sqlx::query_as(
+            "SELECT * FROM dc.webhooks WHERE ($1::UUID IS NULL OR org_id = $1) ORDER BY created_at, id",
+        )
+        .bind(parse_optional_id(&org_id)?)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(webhooks.into_iter().map(without_secret).collect())
+    }
+
+    async fn delete_webhook(&self, id: String) -> Result<Webhook, abi::Error> {
+        let webhook: Webhook = sqlx::query_as("DELETE FROM dc.webhooks WHERE id = $1 RETURNING *")
+            .bind(parse_webhook_id(&id)?)
+            .fetch_optional(&self.pool)
+            .await?
+            .ok_or(abi::Error::WebhookNotFound(id))?;
+        Ok(without_secret(webhook))
+    }
+
+    async fn list_deliveries(
+        &self,
+        webhook_id: String,
+        limit: i64,
+    ) -> Result<Vec<WebhookDelivery>, abi::Error> {
+        let deliveries = sqlx::query_as(
+            "SELECT id, webhook_id, seq, change_type, document_id,
+                CASE WHEN delivered_at IS NULL THEN 'PENDING' ELSE 'DELIVERED' END AS status,
+                attempts, response_status, last_error,
+                CASE WHEN delivered_at IS NULL THEN next_attempt_at END AS next_attempt_at,
+                created_at, delivered_at AS finished_at
+            FROM dc.webhook_deliveries WHERE webhook_id = $1
+            UNION ALL
+            SELECT id, webhook_id, seq, change_type, document_id, 'DEAD', attempts, response_status, last_error,
+                NULL, created_at, failed_at
+            FROM dc.webhook_dead_letters WHERE webhook_id = $1
+            ORDER BY created_at DESC, seq DESC LIMIT $2",
+        )
+        .bind(parse_webhook_id(&webhook_id)?)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(deliveries)
+    }
+
+    async fn enqueue_deliveries(&self, limit: i64) -> Result<usize, abi::Error> {
+        let mut tx = self.pool.begin().await?;
+        // locking the cursor keeps servers from creating the same deliveries twice
+        let since_seq: i64 =
+            sqlx::query_scalar("SELECT since_seq FROM dc.webhook_cursor FOR UPDATE")
+                .fetch_one(&mut *tx)
+                .await?;
+        let changes: Vec<DocumentChange> = sqlx::query_as(&read_changes_query())
+            .bind(since_seq)
+            .bind(None::<Uuid>)
+            .bind("")
+            .bind(limit)
+            .fetch_all(&mut *tx)
+            .await?;
+        let Some(last) = changes.last() else {
+            return Ok(0);
+        };
+        let webhooks: Vec<Webhook> = sqlx::query_as("SELECT * FROM dc.webhooks")
+            .fetch_all(&mut *tx)
+            .await?;
+
+        for change in &changes {
+            let Some(document) = &change.document else {
+                continue;
+            };
+            let mut document = document.clone();
+            fill_totals(&mut document)?;
+            let change_type = change.change_type().as_str_name();
+            let payload = json!({
+                "sequence": change.sequence,
+                "change_type": change_type,
+                "document": document,
+            });
+            for webhook in webhooks.iter().filter(|w| wants(w, change)) {
+                sqlx::query(
+                    "INSERT INTO dc.webhook_deliveries (webhook_id, seq, change_type, document_id, payload)
+                    VALUES ($1, $2, $3, $4, $5) ON CONFLICT (webhook_id, seq) DO NOTHING",
+                )
+                .bind(parse_webhook_id(&webhook.id)?)
+                .bind(change.sequence)
+                .bind(change_type)
+                .bind(Uuid::parse_str(&document.id).map_err(|_| abi::Error::NotFound)?)
+                .bind(&payload)
+                .execute(&mut *tx)
+                .await?;
+            }
+        }
+
+        sqlx::query("UPDATE dc.webhook_cursor SET since_seq = $1")
+            .bind(last.sequence)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        Ok(changes.len())
+    }
+
+    async fn claim_deliveries(
+        &self,
+        limit: i64,
+        lease: Duration,
+    ) -> Result<Vec<PendingDelivery>, abi::Error> {
+        let rows: Vec<(Uuid, Value, i32, String, String)> = sqlx::query_as(
+            "UPDATE dc.webhook_deliveries d SET next_attempt_at = NOW() + make_interval(secs => $2)
+            FROM dc.webhooks w
+            WHERE w.id = d.webhook_id AND d.id IN (
+                SELECT id FROM dc.webhook_deliveries WHERE delivered_at IS NULL AND next_attempt_at <= NOW()
+                ORDER BY next_attempt_at LIMIT $1 FOR UPDATE SKIP LOCKED
+            )
+            RETURNING d.id, d.payload, d.attempts, w.url, w.secret",
+        )
+        .bind(limit)
+        .bind(lease.as_secs_f64())
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(id, payload, attempts, url, secret)| PendingDelivery {
+                id: id.to_string(),
+                url,
+                secret,
+                payload,
+                attempts,
+            })
+            .collect())
+    }
+
+    async fn complete_delivery(&self, id: String, response_status: i32) -> Result<(), abi::Error> {
+        sqlx::query(
+            "UPDATE dc.webhook_deliveries SET attempts = attempts + 1, response_status = $2, last_error = '',
+            delivered_at = NOW() WHERE id = $1",
+        )
+        .bind(parse_delivery_id(&id)?)
+        .bind(response_status)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn fail_delivery(
+        &self,
+        id: String,
+        response_status: Option<i32>,
+        error: String,
+        retry_at: Option<DateTime<Utc>>,
+    ) -> Result<(), abi::Error> {
+        let id = parse_delivery_id(&id)?;
+        let query = match retry_at {
+            Some(retry_at) => sqlx::query(
+                "UPDATE dc.webhook_deliveries SET attempts = attempts + 1, response_status = $2, last_error = $3,
+                next_attempt_at = $4 WHERE id = $1",
+            )
+            .bind(id)
+            .bind(response_status)
+            .bind(error)
+            .bind(retry_at),
+            None => sqlx::query(
+                "WITH failed AS (DELETE FROM dc.webhook_deliveries WHERE id = $1 RETURNING *)
+                INSERT INTO dc.webhook_dead_letters
+                    (id, webhook_id, seq, change_type, document_id, payload, attempts, response_status, last_error, created_at)
+                SELECT id, webhook_id, seq, change_type, document_id, payload, attempts + 1, $2, $3, created_at FROM failed",
+            )
+            .bind(id)
+            .bind(response_status)
+            .bind(error),
+        };
+        query.execute(&self.pool).await?;
+        Ok(())
+    }
+}
+
+/// check a webhook is interested in a change
+fn wants(webhook: &Webhook, change: &DocumentChange) -> bool {
+    if !webhook.event_types.is_empty() && !webhook.event_types.contains(&change.change_type) {
+        return false;
+    }
+    let Some(document) = &change.document else {
+        return false;
+    };
+    let mut query = webhook.query.clone().unwrap_or_default();
+    if !webhook.org_id.is_empty() {
+        query.org_id.clone_from(&webhook.org_id);
+    }
+    matches(&query, document).unwrap_or_else(|e| {
+        warn!("Skipping webhook {}: {:?}", webhook.id, e);
+        false
+    })
+}
+
+/// the secret is only shown when a webhook is created
+fn without_secret(webhook: Webhook) -> Webhook {
+    Webhook {
+        secret: String::new(),
+        ..webhook
+    }
+}
+
+fn parse_webhook_id(id: &str) -> Result<Uuid, abi::Error> {
+    Uuid::parse_str(id).map_err(|_| abi::Error::WebhookNotFound(id.to_string()))
+}
+
+fn parse_delivery_id(id: &str) -> Result<Uuid, abi::Error> {
+    Uuid::parse_str(id).map_err(|_| abi::Error::NotFound)
+}
+
+fn parse_optional_id(org_id: &str) -> Result<Option<Uuid>, abi::Error> {
+    if org_id.is_empty() {
+        return Ok(None);
+    }
+    Uuid::parse_str(org_id)
+        .map(Some)
+        .map_err(|_| abi::Error::OrganizationNotFound)
+}
@@ -0,0 +1 @@
+ALTER TABLE dc.documents DROP COLUMN items;
\ No newline at end of file
@@ -0,0 +1,3 @@
+ALTER TABLE dc.documents
+    ADD COLUMN items JSONB NOT NULL DEFAULT '[]',
+    ADD CONSTRAINT documents_items_array CHECK (jsonb_typeof(items) = 'array');
\ No newline at end of file
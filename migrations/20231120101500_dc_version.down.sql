@@ -0,0 +1,3 @@
+DROP TRIGGER documents_version ON dc.documents;
+DROP FUNCTION dc.increment_version();
+ALTER TABLE dc.documents DROP COLUMN version;
\ No newline at end of file
@@ -0,0 +1,14 @@
+ALTER TABLE dc.documents ADD COLUMN version BIGINT NOT NULL DEFAULT 1;
+
+CREATE OR REPLACE FUNCTION dc.increment_version()
+    RETURNS TRIGGER AS $$
+BEGIN
+    NEW.version = OLD.version + 1;
+    RETURN NEW;
+END;
+$$ language 'plpgsql';
+
+CREATE TRIGGER documents_version
+    BEFORE UPDATE ON dc.documents
+    FOR EACH ROW
+    EXECUTE PROCEDURE dc.increment_version();
\ No newline at end of file
@@ -0,0 +1,3 @@
+DROP TRIGGER documents_revision ON dc.documents;
+DROP FUNCTION dc.record_revision();
+DROP TABLE dc.document_revisions;
\ No newline at end of file
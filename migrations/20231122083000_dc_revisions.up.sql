@@ -0,0 +1,34 @@
+CREATE TABLE dc.document_revisions (
+    document_id UUID NOT NULL,
+    revision BIGINT NOT NULL,
+    user_id UUID NOT NULL,
+    data JSONB NOT NULL,
+    items JSONB NOT NULL,
+    deleted BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT document_revisions_pk PRIMARY KEY (document_id, revision)
+);
+
+INSERT INTO dc.document_revisions (document_id, revision, user_id, data, items, created_at)
+    SELECT id, version, user_id, data, items, updated_at FROM dc.documents;
+
+CREATE OR REPLACE FUNCTION dc.record_revision()
+    RETURNS TRIGGER AS $$
+BEGIN
+    IF TG_OP = 'DELETE' THEN
+        INSERT INTO dc.document_revisions (document_id, revision, user_id, data, items, deleted)
+            VALUES (OLD.id, OLD.version + 1, OLD.user_id, OLD.data, OLD.items, TRUE);
+        RETURN OLD;
+    END IF;
+
+    INSERT INTO dc.document_revisions (document_id, revision, user_id, data, items, created_at)
+        VALUES (NEW.id, NEW.version, NEW.user_id, NEW.data, NEW.items, NEW.updated_at);
+    RETURN NEW;
+END;
+$$ language 'plpgsql';
+
+CREATE TRIGGER documents_revision
+    AFTER INSERT OR UPDATE OR DELETE ON dc.documents
+    FOR EACH ROW
+    EXECUTE PROCEDURE dc.record_revision();
\ No newline at end of file
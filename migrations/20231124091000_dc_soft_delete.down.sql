@@ -0,0 +1,17 @@
+CREATE OR REPLACE FUNCTION dc.record_revision()
+    RETURNS TRIGGER AS $$
+BEGIN
+    IF TG_OP = 'DELETE' THEN
+        INSERT INTO dc.document_revisions (document_id, revision, user_id, data, items, deleted)
+            VALUES (OLD.id, OLD.version + 1, OLD.user_id, OLD.data, OLD.items, TRUE);
+        RETURN OLD;
+    END IF;
+
+    INSERT INTO dc.document_revisions (document_id, revision, user_id, data, items, created_at)
+        VALUES (NEW.id, NEW.version, NEW.user_id, NEW.data, NEW.items, NEW.updated_at);
+    RETURN NEW;
+END;
+$$ language 'plpgsql';
+
+DROP INDEX dc.documents_deleted_at;
+ALTER TABLE dc.documents DROP COLUMN deleted_at;
\ No newline at end of file
@@ -0,0 +1,21 @@
+ALTER TABLE dc.documents ADD COLUMN deleted_at TIMESTAMPTZ;
+
+CREATE INDEX documents_deleted_at ON dc.documents (deleted_at) WHERE deleted_at IS NOT NULL;
+
+CREATE OR REPLACE FUNCTION dc.record_revision()
+    RETURNS TRIGGER AS $$
+BEGIN
+    IF TG_OP = 'DELETE' THEN
+        -- purging a document from the trash was already recorded when it was deleted
+        IF OLD.deleted_at IS NULL THEN
+            INSERT INTO dc.document_revisions (document_id, revision, user_id, data, items, deleted)
+                VALUES (OLD.id, OLD.version + 1, OLD.user_id, OLD.data, OLD.items, TRUE);
+        END IF;
+        RETURN OLD;
+    END IF;
+
+    INSERT INTO dc.document_revisions (document_id, revision, user_id, data, items, deleted, created_at)
+        VALUES (NEW.id, NEW.version, NEW.user_id, NEW.data, NEW.items, NEW.deleted_at IS NOT NULL, NEW.updated_at);
+    RETURN NEW;
+END;
+$$ language 'plpgsql';
\ No newline at end of file
@@ -0,0 +1,2 @@
+DROP INDEX dc.documents_data_gin;
+DROP INDEX dc.documents_items_gin;
\ No newline at end of file
@@ -0,0 +1,2 @@
+CREATE INDEX documents_data_gin ON dc.documents USING GIN (data jsonb_path_ops);
+CREATE INDEX documents_items_gin ON dc.documents USING GIN (items jsonb_path_ops);
\ No newline at end of file
@@ -0,0 +1 @@
+DROP TABLE dc.users;
\ No newline at end of file
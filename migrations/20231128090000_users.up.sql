@@ -0,0 +1,21 @@
+CREATE TABLE dc.users (
+    id UUID NOT NULL DEFAULT uuid_generate_v4(),
+    display_name TEXT NOT NULL,
+    email TEXT NOT NULL,
+    role TEXT NOT NULL,
+    store_id UUID,
+    active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT users_pk PRIMARY KEY (id),
+    CONSTRAINT users_email_key UNIQUE (email),
+    CONSTRAINT users_role CHECK (role IN ('store', 'admin', 'auditor'))
+);
+
+CREATE INDEX users_store_id ON dc.users (store_id);
+
+CREATE TRIGGER users_updated_at
+    BEFORE UPDATE ON dc.users
+    FOR EACH ROW
+    EXECUTE PROCEDURE dc.update_updated_at();
\ No newline at end of file
@@ -0,0 +1 @@
+DROP TABLE dc.api_keys;
\ No newline at end of file
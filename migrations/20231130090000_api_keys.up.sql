@@ -0,0 +1,18 @@
+CREATE TABLE dc.api_keys (
+    id UUID NOT NULL DEFAULT uuid_generate_v4(),
+    user_id UUID NOT NULL,
+    name TEXT NOT NULL,
+    permissions TEXT[] NOT NULL,
+    prefix TEXT NOT NULL,
+    key_hash TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    last_used_at TIMESTAMPTZ,
+    revoked_at TIMESTAMPTZ,
+
+    CONSTRAINT api_keys_pk PRIMARY KEY (id),
+    CONSTRAINT api_keys_user_fk FOREIGN KEY (user_id) REFERENCES dc.users (id),
+    CONSTRAINT api_keys_key_hash_key UNIQUE (key_hash),
+    CONSTRAINT api_keys_permissions CHECK (permissions <@ ARRAY['read', 'write', 'manage_users'])
+);
+
+CREATE INDEX api_keys_user_id ON dc.api_keys (user_id);
\ No newline at end of file
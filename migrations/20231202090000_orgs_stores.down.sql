@@ -0,0 +1,32 @@
+CREATE OR REPLACE FUNCTION dc.record_revision()
+    RETURNS TRIGGER AS $$
+BEGIN
+    IF TG_OP = 'DELETE' THEN
+        -- purging a document from the trash was already recorded when it was deleted
+        IF OLD.deleted_at IS NULL THEN
+            INSERT INTO dc.document_revisions (document_id, revision, user_id, data, items, deleted)
+                VALUES (OLD.id, OLD.version + 1, OLD.user_id, OLD.data, OLD.items, TRUE);
+        END IF;
+        RETURN OLD;
+    END IF;
+
+    INSERT INTO dc.document_revisions (document_id, revision, user_id, data, items, deleted, created_at)
+        VALUES (NEW.id, NEW.version, NEW.user_id, NEW.data, NEW.items, NEW.deleted_at IS NOT NULL, NEW.updated_at);
+    RETURN NEW;
+END;
+$$ language 'plpgsql';
+
+ALTER TABLE dc.document_revisions DROP COLUMN store_id;
+ALTER TABLE dc.document_revisions DROP COLUMN org_id;
+
+DROP INDEX dc.documents_store_id;
+DROP INDEX dc.documents_org_id;
+ALTER TABLE dc.documents DROP COLUMN store_id;
+ALTER TABLE dc.documents DROP COLUMN org_id;
+
+DROP INDEX dc.users_org_id;
+ALTER TABLE dc.users DROP CONSTRAINT users_store_fk;
+ALTER TABLE dc.users DROP COLUMN org_id;
+
+DROP TABLE dc.stores;
+DROP TABLE dc.organizations;
\ No newline at end of file
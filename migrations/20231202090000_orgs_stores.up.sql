@@ -0,0 +1,68 @@
+CREATE TABLE dc.organizations (
+    id UUID NOT NULL DEFAULT uuid_generate_v4(),
+    name TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT organizations_pk PRIMARY KEY (id)
+);
+
+CREATE TABLE dc.stores (
+    id UUID NOT NULL DEFAULT uuid_generate_v4(),
+    org_id UUID NOT NULL,
+    name TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT stores_pk PRIMARY KEY (id),
+    CONSTRAINT stores_org_fk FOREIGN KEY (org_id) REFERENCES dc.organizations (id)
+);
+
+CREATE INDEX stores_org_id ON dc.stores (org_id);
+
+-- everything created before organizations existed moves into one organization, with a store
+-- for every store id its users already had
+INSERT INTO dc.organizations (name)
+    SELECT 'Default' WHERE EXISTS (SELECT 1 FROM dc.users) OR EXISTS (SELECT 1 FROM dc.documents);
+INSERT INTO dc.stores (id, org_id, name)
+    SELECT DISTINCT store_id, (SELECT id FROM dc.organizations), store_id::TEXT
+    FROM dc.users WHERE store_id IS NOT NULL;
+
+ALTER TABLE dc.users ADD COLUMN org_id UUID;
+UPDATE dc.users SET org_id = (SELECT id FROM dc.organizations);
+ALTER TABLE dc.users ADD CONSTRAINT users_org_fk FOREIGN KEY (org_id) REFERENCES dc.organizations (id);
+ALTER TABLE dc.users ADD CONSTRAINT users_store_fk FOREIGN KEY (store_id) REFERENCES dc.stores (id);
+CREATE INDEX users_org_id ON dc.users (org_id);
+
+ALTER TABLE dc.documents ADD COLUMN org_id UUID;
+ALTER TABLE dc.documents ADD COLUMN store_id UUID;
+-- backfilling is not a change of the documents, so it bumps no version and records no revision
+ALTER TABLE dc.documents DISABLE TRIGGER USER;
+UPDATE dc.documents d SET org_id = (SELECT id FROM dc.organizations),
+    store_id = (SELECT store_id FROM dc.users u WHERE u.id = d.user_id);
+ALTER TABLE dc.documents ENABLE TRIGGER USER;
+ALTER TABLE dc.documents ADD CONSTRAINT documents_org_fk FOREIGN KEY (org_id) REFERENCES dc.organizations (id);
+ALTER TABLE dc.documents ADD CONSTRAINT documents_store_fk FOREIGN KEY (store_id) REFERENCES dc.stores (id);
+CREATE INDEX documents_org_id ON dc.documents (org_id, created_at);
+CREATE INDEX documents_store_id ON dc.documents (store_id, created_at);
+
+ALTER TABLE dc.document_revisions ADD COLUMN org_id UUID;
+ALTER TABLE dc.document_revisions ADD COLUMN store_id UUID;
+UPDATE dc.document_revisions r SET org_id = (SELECT id FROM dc.organizations),
+    store_id = (SELECT store_id FROM dc.users u WHERE u.id = r.user_id);
+
+CREATE OR REPLACE FUNCTION dc.record_revision()
+    RETURNS TRIGGER AS $$
+BEGIN
+    IF TG_OP = 'DELETE' THEN
+        -- purging a document from the trash was already recorded when it was deleted
+        IF OLD.deleted_at IS NULL THEN
+            INSERT INTO dc.document_revisions (document_id, revision, user_id, org_id, store_id, data, items, deleted)
+                VALUES (OLD.id, OLD.version + 1, OLD.user_id, OLD.org_id, OLD.store_id, OLD.data, OLD.items, TRUE);
+        END IF;
+        RETURN OLD;
+    END IF;
+
+    INSERT INTO dc.document_revisions (document_id, revision, user_id, org_id, store_id, data, items, deleted, created_at)
+        VALUES (NEW.id, NEW.version, NEW.user_id, NEW.org_id, NEW.store_id, NEW.data, NEW.items, NEW.deleted_at IS NOT NULL, NEW.updated_at);
+    RETURN NEW;
+END;
+$$ language 'plpgsql';
\ No newline at end of file
@@ -0,0 +1,25 @@
+CREATE OR REPLACE FUNCTION dc.record_revision()
+    RETURNS TRIGGER AS $$
+BEGIN
+    IF TG_OP = 'DELETE' THEN
+        -- purging a document from the trash was already recorded when it was deleted
+        IF OLD.deleted_at IS NULL THEN
+            INSERT INTO dc.document_revisions (document_id, revision, user_id, org_id, store_id, data, items, deleted)
+                VALUES (OLD.id, OLD.version + 1, OLD.user_id, OLD.org_id, OLD.store_id, OLD.data, OLD.items, TRUE);
+        END IF;
+        RETURN OLD;
+    END IF;
+
+    INSERT INTO dc.document_revisions (document_id, revision, user_id, org_id, store_id, data, items, deleted, created_at)
+        VALUES (NEW.id, NEW.version, NEW.user_id, NEW.org_id, NEW.store_id, NEW.data, NEW.items, NEW.deleted_at IS NOT NULL, NEW.updated_at);
+    RETURN NEW;
+END;
+$$ language 'plpgsql';
+
+ALTER TABLE dc.document_revisions DROP COLUMN document_type;
+
+DROP INDEX dc.documents_document_type;
+ALTER TABLE dc.documents DROP COLUMN schema_version;
+ALTER TABLE dc.documents DROP COLUMN document_type;
+
+DROP TABLE dc.document_schemas;
\ No newline at end of file
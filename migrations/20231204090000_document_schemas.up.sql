@@ -0,0 +1,36 @@
+CREATE TABLE dc.document_schemas (
+    org_id UUID NOT NULL,
+    document_type TEXT NOT NULL,
+    version INT NOT NULL,
+    schema JSONB NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT document_schemas_pk PRIMARY KEY (org_id, document_type, version),
+    CONSTRAINT document_schemas_org_fk FOREIGN KEY (org_id) REFERENCES dc.organizations (id),
+    CONSTRAINT document_schemas_type_check CHECK (document_type <> '')
+);
+
+-- untyped documents have an empty document_type and no schema_version
+ALTER TABLE dc.documents ADD COLUMN document_type TEXT NOT NULL DEFAULT '';
+ALTER TABLE dc.documents ADD COLUMN schema_version INT;
+CREATE INDEX documents_document_type ON dc.documents (org_id, document_type, created_at);
+
+ALTER TABLE dc.document_revisions ADD COLUMN document_type TEXT NOT NULL DEFAULT '';
+
+CREATE OR REPLACE FUNCTION dc.record_revision()
+    RETURNS TRIGGER AS $$
+BEGIN
+    IF TG_OP = 'DELETE' THEN
+        -- purging a document from the trash was already recorded when it was deleted
+        IF OLD.deleted_at IS NULL THEN
+            INSERT INTO dc.document_revisions (document_id, revision, user_id, org_id, store_id, document_type, data, items, deleted)
+                VALUES (OLD.id, OLD.version + 1, OLD.user_id, OLD.org_id, OLD.store_id, OLD.document_type, OLD.data, OLD.items, TRUE);
+        END IF;
+        RETURN OLD;
+    END IF;
+
+    INSERT INTO dc.document_revisions (document_id, revision, user_id, org_id, store_id, document_type, data, items, deleted, created_at)
+        VALUES (NEW.id, NEW.version, NEW.user_id, NEW.org_id, NEW.store_id, NEW.document_type, NEW.data, NEW.items, NEW.deleted_at IS NOT NULL, NEW.updated_at);
+    RETURN NEW;
+END;
+$$ language 'plpgsql';
\ No newline at end of file
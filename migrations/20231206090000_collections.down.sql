@@ -0,0 +1,25 @@
+CREATE OR REPLACE FUNCTION dc.record_revision()
+    RETURNS TRIGGER AS $$
+BEGIN
+    IF TG_OP = 'DELETE' THEN
+        -- purging a document from the trash was already recorded when it was deleted
+        IF OLD.deleted_at IS NULL THEN
+            INSERT INTO dc.document_revisions (document_id, revision, user_id, org_id, store_id, document_type, data, items, deleted)
+                VALUES (OLD.id, OLD.version + 1, OLD.user_id, OLD.org_id, OLD.store_id, OLD.document_type, OLD.data, OLD.items, TRUE);
+        END IF;
+        RETURN OLD;
+    END IF;
+
+    INSERT INTO dc.document_revisions (document_id, revision, user_id, org_id, store_id, document_type, data, items, deleted, created_at)
+        VALUES (NEW.id, NEW.version, NEW.user_id, NEW.org_id, NEW.store_id, NEW.document_type, NEW.data, NEW.items, NEW.deleted_at IS NOT NULL, NEW.updated_at);
+    RETURN NEW;
+END;
+$$ language 'plpgsql';
+
+ALTER TABLE dc.document_revisions DROP COLUMN collection;
+
+-- the indexes of named collections are dropped along with the column they filter on
+DROP INDEX dc.documents_collection;
+ALTER TABLE dc.documents DROP COLUMN collection;
+
+DROP TABLE dc.collections;
\ No newline at end of file
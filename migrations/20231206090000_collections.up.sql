@@ -0,0 +1,41 @@
+CREATE TABLE dc.collections (
+    name TEXT NOT NULL,
+    -- documents of the collection default to and must have this type, any type if empty
+    document_type TEXT NOT NULL DEFAULT '',
+    -- documents in the trash are purged this many days after deletion, after the configured retention if 0
+    retention_days INT NOT NULL DEFAULT 0,
+    -- dot separated paths of the data or items fields indexed for this collection
+    indexes TEXT[] NOT NULL DEFAULT '{}',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT collections_pk PRIMARY KEY (name),
+    CONSTRAINT collections_name_check CHECK (name ~ '^[a-z0-9_]{1,48}$'),
+    CONSTRAINT collections_retention_check CHECK (retention_days >= 0)
+);
+
+-- every document created before collections existed lives in the default collection
+INSERT INTO dc.collections (name) VALUES ('documents');
+
+ALTER TABLE dc.documents ADD COLUMN collection TEXT NOT NULL DEFAULT 'documents';
+ALTER TABLE dc.documents ADD CONSTRAINT documents_collection_fk FOREIGN KEY (collection) REFERENCES dc.collections (name);
+CREATE INDEX documents_collection ON dc.documents (collection, created_at);
+
+ALTER TABLE dc.document_revisions ADD COLUMN collection TEXT NOT NULL DEFAULT 'documents';
+
+CREATE OR REPLACE FUNCTION dc.record_revision()
+    RETURNS TRIGGER AS $$
+BEGIN
+    IF TG_OP = 'DELETE' THEN
+        -- purging a document from the trash was already recorded when it was deleted
+        IF OLD.deleted_at IS NULL THEN
+            INSERT INTO dc.document_revisions (document_id, revision, user_id, org_id, store_id, collection, document_type, data, items, deleted)
+                VALUES (OLD.id, OLD.version + 1, OLD.user_id, OLD.org_id, OLD.store_id, OLD.collection, OLD.document_type, OLD.data, OLD.items, TRUE);
+        END IF;
+        RETURN OLD;
+    END IF;
+
+    INSERT INTO dc.document_revisions (document_id, revision, user_id, org_id, store_id, collection, document_type, data, items, deleted, created_at)
+        VALUES (NEW.id, NEW.version, NEW.user_id, NEW.org_id, NEW.store_id, NEW.collection, NEW.document_type, NEW.data, NEW.items, NEW.deleted_at IS NOT NULL, NEW.updated_at);
+    RETURN NEW;
+END;
+$$ language 'plpgsql';
\ No newline at end of file
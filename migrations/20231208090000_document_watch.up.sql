@@ -0,0 +1,32 @@
+-- every revision gets a sequence number identifying the change it recorded, watchers resume from it
+ALTER TABLE dc.document_revisions ADD COLUMN seq BIGINT GENERATED ALWAYS AS IDENTITY;
+CREATE UNIQUE INDEX document_revisions_seq ON dc.document_revisions (seq);
+-- sequence numbers are taken before commit, so changes are watched in the order of the transactions
+-- that made them, once every older one has ended
+ALTER TABLE dc.document_revisions ADD COLUMN txid XID8 NOT NULL DEFAULT pg_current_xact_id();
+CREATE INDEX document_revisions_order ON dc.document_revisions (txid, seq);
+
+CREATE OR REPLACE FUNCTION dc.record_revision()
+    RETURNS TRIGGER AS $$
+DECLARE
+    change BIGINT;
+BEGIN
+    IF TG_OP = 'DELETE' THEN
+        -- purging a document from the trash was already recorded when it was deleted
+        IF OLD.deleted_at IS NULL THEN
+            INSERT INTO dc.document_revisions (document_id, revision, user_id, org_id, store_id, collection, document_type, data, items, deleted)
+                VALUES (OLD.id, OLD.version + 1, OLD.user_id, OLD.org_id, OLD.store_id, OLD.collection, OLD.document_type, OLD.data, OLD.items, TRUE)
+                RETURNING seq INTO change;
+            PERFORM pg_notify('dc_document_changes', change::TEXT);
+        END IF;
+        RETURN OLD;
+    END IF;
+
+    INSERT INTO dc.document_revisions (document_id, revision, user_id, org_id, store_id, collection, document_type, data, items, deleted, created_at)
+        VALUES (NEW.id, NEW.version, NEW.user_id, NEW.org_id, NEW.store_id, NEW.collection, NEW.document_type, NEW.data, NEW.items, NEW.deleted_at IS NOT NULL, NEW.updated_at)
+        RETURNING seq INTO change;
+    -- delivered when the transaction commits, in commit order
+    PERFORM pg_notify('dc_document_changes', change::TEXT);
+    RETURN NEW;
+END;
+$$ language 'plpgsql';
\ No newline at end of file
@@ -0,0 +1,3 @@
+DROP TRIGGER documents_outbox ON dc.documents;
+DROP FUNCTION dc.record_outbox_event();
+DROP TABLE dc.outbox;
\ No newline at end of file
@@ -0,0 +1,46 @@
+-- every change of a document, written in the transaction making it, for downstream systems to consume
+CREATE TABLE dc.outbox (
+    seq BIGINT GENERATED ALWAYS AS IDENTITY,
+    -- changes are read in the order of the transactions that made them, once every older one has ended
+    txid XID8 NOT NULL DEFAULT pg_current_xact_id(),
+    document_id UUID NOT NULL,
+    version BIGINT NOT NULL,
+    user_id UUID NOT NULL,
+    org_id UUID,
+    store_id UUID,
+    collection TEXT NOT NULL,
+    document_type TEXT NOT NULL,
+    schema_version INT,
+    data JSONB NOT NULL,
+    items JSONB NOT NULL,
+    deleted BOOLEAN NOT NULL,
+    document_created_at TIMESTAMPTZ NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT outbox_pk PRIMARY KEY (seq)
+);
+
+CREATE INDEX outbox_order ON dc.outbox (txid, seq);
+
+CREATE OR REPLACE FUNCTION dc.record_outbox_event()
+    RETURNS TRIGGER AS $$
+BEGIN
+    IF TG_OP = 'DELETE' THEN
+        -- purging a document from the trash was already recorded when it was deleted
+        IF OLD.deleted_at IS NULL THEN
+            INSERT INTO dc.outbox (document_id, version, user_id, org_id, store_id, collection, document_type, schema_version, data, items, deleted, document_created_at)
+                VALUES (OLD.id, OLD.version + 1, OLD.user_id, OLD.org_id, OLD.store_id, OLD.collection, OLD.document_type, OLD.schema_version, OLD.data, OLD.items, TRUE, OLD.created_at);
+        END IF;
+        RETURN OLD;
+    END IF;
+
+    INSERT INTO dc.outbox (document_id, version, user_id, org_id, store_id, collection, document_type, schema_version, data, items, deleted, document_created_at, created_at)
+        VALUES (NEW.id, NEW.version, NEW.user_id, NEW.org_id, NEW.store_id, NEW.collection, NEW.document_type, NEW.schema_version, NEW.data, NEW.items, NEW.deleted_at IS NOT NULL, NEW.created_at, NEW.updated_at);
+    RETURN NEW;
+END;
+$$ language 'plpgsql';
+
+CREATE TRIGGER documents_outbox
+    AFTER INSERT OR UPDATE OR DELETE ON dc.documents
+    FOR EACH ROW
+    EXECUTE PROCEDURE dc.record_outbox_event();
\ No newline at end of file
@@ -0,0 +1,4 @@
+DROP TABLE dc.webhook_cursor;
+DROP TABLE dc.webhook_dead_letters;
+DROP TABLE dc.webhook_deliveries;
+DROP TABLE dc.webhooks;
\ No newline at end of file
@@ -0,0 +1,68 @@
+CREATE TABLE dc.webhooks (
+    id UUID NOT NULL DEFAULT uuid_generate_v4(),
+    -- changes of every organization are posted if NULL
+    org_id UUID,
+    url TEXT NOT NULL,
+    -- names of the kinds of changes posted, every kind if empty
+    event_types TEXT[] NOT NULL DEFAULT '{}',
+    -- json encoded DocumentQuery the changed documents must match
+    query JSONB NOT NULL DEFAULT '{}',
+    -- key the payloads are signed with
+    secret TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT webhooks_pk PRIMARY KEY (id),
+    CONSTRAINT webhooks_org_fk FOREIGN KEY (org_id) REFERENCES dc.organizations (id)
+);
+
+CREATE TABLE dc.webhook_deliveries (
+    id UUID NOT NULL DEFAULT uuid_generate_v4(),
+    webhook_id UUID NOT NULL,
+    -- the change of the outbox this delivery posts
+    seq BIGINT NOT NULL,
+    change_type TEXT NOT NULL,
+    document_id UUID NOT NULL,
+    payload JSONB NOT NULL,
+    attempts INT NOT NULL DEFAULT 0,
+    next_attempt_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    -- http status of the last attempt, NULL if it got no response
+    response_status INT,
+    last_error TEXT NOT NULL DEFAULT '',
+    delivered_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT webhook_deliveries_pk PRIMARY KEY (id),
+    CONSTRAINT webhook_deliveries_change UNIQUE (webhook_id, seq),
+    CONSTRAINT webhook_deliveries_webhook_fk FOREIGN KEY (webhook_id) REFERENCES dc.webhooks (id) ON DELETE CASCADE
+);
+
+CREATE INDEX webhook_deliveries_due ON dc.webhook_deliveries (next_attempt_at) WHERE delivered_at IS NULL;
+
+-- deliveries that failed every attempt, kept for inspection
+CREATE TABLE dc.webhook_dead_letters (
+    id UUID NOT NULL,
+    webhook_id UUID NOT NULL,
+    seq BIGINT NOT NULL,
+    change_type TEXT NOT NULL,
+    document_id UUID NOT NULL,
+    payload JSONB NOT NULL,
+    attempts INT NOT NULL,
+    response_status INT,
+    last_error TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL,
+    failed_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT webhook_dead_letters_pk PRIMARY KEY (id),
+    CONSTRAINT webhook_dead_letters_webhook_fk FOREIGN KEY (webhook_id) REFERENCES dc.webhooks (id) ON DELETE CASCADE
+);
+
+-- the change of the outbox up to which deliveries were created, a single row
+CREATE TABLE dc.webhook_cursor (
+    id BOOLEAN NOT NULL DEFAULT TRUE,
+    since_seq BIGINT NOT NULL DEFAULT 0,
+
+    CONSTRAINT webhook_cursor_pk PRIMARY KEY (id),
+    CONSTRAINT webhook_cursor_single CHECK (id)
+);
+
+INSERT INTO dc.webhook_cursor DEFAULT VALUES;
\ No newline at end of file
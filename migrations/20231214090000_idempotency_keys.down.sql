@@ -0,0 +1 @@
+DROP TABLE dc.idempotency_keys;
\ No newline at end of file
@@ -0,0 +1,18 @@
+-- the outcome of creates and updates sent with an idempotency key, replayed to retries of the same request
+CREATE TABLE dc.idempotency_keys (
+    -- keys are scoped to the user sending the request
+    user_id UUID NOT NULL,
+    key TEXT NOT NULL,
+    -- create or update
+    operation TEXT NOT NULL,
+    -- the json encoded request, a key may only be reused for the same request
+    request JSONB NOT NULL,
+    -- the json encoded document returned to the first attempt
+    document JSONB NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    expires_at TIMESTAMPTZ NOT NULL,
+
+    CONSTRAINT idempotency_keys_pk PRIMARY KEY (user_id, key)
+);
+
+CREATE INDEX idempotency_keys_expires_at ON dc.idempotency_keys (expires_at);
\ No newline at end of file
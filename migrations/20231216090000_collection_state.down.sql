@@ -0,0 +1 @@
+ALTER TABLE dc.collections DROP COLUMN state;
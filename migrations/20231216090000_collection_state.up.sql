@@ -0,0 +1,5 @@
+-- the indexes of a collection are built and dropped outside the transaction creating or dropping it,
+-- a collection is pending until they are built and dropping until they are gone, servers finish
+-- what another one stopped working on midway when they start
+ALTER TABLE dc.collections ADD COLUMN state TEXT NOT NULL DEFAULT 'ready';
+ALTER TABLE dc.collections ADD CONSTRAINT collections_state_check CHECK (state IN ('pending', 'ready', 'dropping'));
@@ -0,0 +1,57 @@
+-- the revisions are the one log of changes: watch, read_changes and webhook deliveries all number a
+-- change by the sequence of its revision, the outbox copying every change a second time goes away
+ALTER TABLE dc.document_revisions ADD COLUMN schema_version INT;
+-- the first revisions of a document are removed once past the retention of changes
+ALTER TABLE dc.document_revisions ADD COLUMN document_created_at TIMESTAMPTZ;
+
+UPDATE dc.document_revisions r SET schema_version = o.schema_version
+    FROM dc.outbox o WHERE o.document_id = r.document_id AND o.version = r.revision;
+UPDATE dc.document_revisions r SET document_created_at = f.created_at
+    FROM (SELECT document_id, MIN(created_at) AS created_at FROM dc.document_revisions GROUP BY document_id) f
+    WHERE f.document_id = r.document_id;
+ALTER TABLE dc.document_revisions ALTER COLUMN document_created_at SET NOT NULL;
+-- changes past the retention are removed oldest first
+CREATE INDEX document_revisions_created_at ON dc.document_revisions (created_at);
+
+-- deliveries and the webhook cursor name changes by their outbox sequence, point them at the same
+-- change in the revisions, the two sequences may overlap while they are renumbered
+ALTER TABLE dc.webhook_deliveries DROP CONSTRAINT webhook_deliveries_change;
+UPDATE dc.webhook_deliveries d SET seq = r.seq, payload = jsonb_set(d.payload, '{sequence}', to_jsonb(r.seq))
+    FROM dc.outbox o JOIN dc.document_revisions r ON r.document_id = o.document_id AND r.revision = o.version
+    WHERE o.seq = d.seq;
+ALTER TABLE dc.webhook_deliveries ADD CONSTRAINT webhook_deliveries_change UNIQUE (webhook_id, seq);
+UPDATE dc.webhook_dead_letters d SET seq = r.seq, payload = jsonb_set(d.payload, '{sequence}', to_jsonb(r.seq))
+    FROM dc.outbox o JOIN dc.document_revisions r ON r.document_id = o.document_id AND r.revision = o.version
+    WHERE o.seq = d.seq;
+UPDATE dc.webhook_cursor c SET since_seq = r.seq
+    FROM dc.outbox o JOIN dc.document_revisions r ON r.document_id = o.document_id AND r.revision = o.version
+    WHERE o.seq = c.since_seq;
+
+DROP TRIGGER documents_outbox ON dc.documents;
+DROP FUNCTION dc.record_outbox_event();
+DROP TABLE dc.outbox;
+
+CREATE OR REPLACE FUNCTION dc.record_revision()
+    RETURNS TRIGGER AS $$
+DECLARE
+    change BIGINT;
+BEGIN
+    IF TG_OP = 'DELETE' THEN
+        -- purging a document from the trash was already recorded when it was deleted
+        IF OLD.deleted_at IS NULL THEN
+            INSERT INTO dc.document_revisions (document_id, revision, user_id, org_id, store_id, collection, document_type, schema_version, data, items, deleted, document_created_at)
+                VALUES (OLD.id, OLD.version + 1, OLD.user_id, OLD.org_id, OLD.store_id, OLD.collection, OLD.document_type, OLD.schema_version, OLD.data, OLD.items, TRUE, OLD.created_at)
+                RETURNING seq INTO change;
+            PERFORM pg_notify('dc_document_changes', change::TEXT);
+        END IF;
+        RETURN OLD;
+    END IF;
+
+    INSERT INTO dc.document_revisions (document_id, revision, user_id, org_id, store_id, collection, document_type, schema_version, data, items, deleted, document_created_at, created_at)
+        VALUES (NEW.id, NEW.version, NEW.user_id, NEW.org_id, NEW.store_id, NEW.collection, NEW.document_type, NEW.schema_version, NEW.data, NEW.items, NEW.deleted_at IS NOT NULL, NEW.created_at, NEW.updated_at)
+        RETURNING seq INTO change;
+    -- delivered when the transaction commits, in commit order
+    PERFORM pg_notify('dc_document_changes', change::TEXT);
+    RETURN NEW;
+END;
+$$ language 'plpgsql';
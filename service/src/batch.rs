@@ -0,0 +1,314 @@
+use std::future::Future;
+
+use abi::{
+    BatchCreateRequest, BatchDeleteRequest, BatchResponse, BatchResult, BatchUpdateRequest,
+    CreateRequest, Document, Validator,
+};
+use document_collection::{Batches, Owner};
+use futures::{Stream, StreamExt};
+use tokio::sync::mpsc;
+use tonic::Status;
+use user::Users;
+
+use crate::{BatchStream, DcService, Principal};
+
+impl DcService {
+    /// check the caller may create the document, for themselves if no user is given
+    pub(crate) async fn check_create(
+        &self,
+        principal: &Principal,
+        mut request: CreateRequest,
+    ) -> Result<CreateRequest, abi::Error> {
+        if request.user_id.is_empty() {
+            request.user_id = principal.user_id.clone();
+        }
+        let user = self.users.get(request.user_id.clone()).await?;
+        principal.check_write(&Owner {
+            user_id: user.id,
+            org_id: user.org_id,
+        })?;
+        Ok(request)
+    }
+
+    pub(crate) async fn run_batch_create(
+        &self,
+        principal: &Principal,
+        batch: BatchCreateRequest,
+    ) -> Result<Vec<BatchResult>, abi::Error> {
+        let mut requests = batch.requests;
+        for request in requests.iter_mut().filter(|r| r.user_id.is_empty()) {
+            request.user_id = principal.user_id.clone();
+        }
+        let user_ids = requests.iter().map(|r| r.user_id.clone()).collect();
+        let users = self.users.get_many(user_ids).await?;
+        let checked = check_batch(requests, users, batch.atomic, |request, user| {
+            let user = user?;
+            principal.check_write(&Owner {
+                user_id: user.id,
+                org_id: user.org_id,
+            })?;
+            Ok(request)
+        });
+        let results = self
+            .manager
+            .batch_create(checked.accepted, batch.atomic)
+            .await?;
+        Ok(merge(checked.errors, results))
+    }
+
+    pub(crate) async fn run_batch_update(
+        &self,
+        principal: &Principal,
+        batch: BatchUpdateRequest,
+    ) -> Result<Vec<BatchResult>, abi::Error> {
+        let documents = batch
+            .requests
+            .iter()
+            .map(|r| (r.collection.clone(), r.id.clone()))
+            .collect();
+        let owners = self.manager.batch_owners(documents).await?;
+        let checked = check_batch(batch.requests, owners, batch.atomic, |request, owner| {
+            principal.check_write(&owner?)?;
+            Ok(request)
+        });
+        let results = self
+            .manager
+            .batch_update(checked.accepted, batch.atomic)
+            .await?;
+        Ok(merge(checked.errors, results))
+    }
+
+    pub(crate) async fn run_batch_delete(
+        &self,
+        principal: &Principal,
+        batch: BatchDeleteRequest,
+    ) -> Result<Vec<BatchResult>, abi::Error> {
+        let documents = batch
+            .requests
+            .iter()
+            .map(|r| (r.collection.clone(), r.id.clone()))
+            .collect();
+        let owners = self.manager.batch_owners(documents).await?;
+        let checked = check_batch(batch.requests, owners, batch.atomic, |request, owner| {
+            principal.check_write(&owner?)?;
+            Ok(request)
+        });
+        let results = self
+            .manager
+            .batch_delete(checked.accepted, batch.atomic)
+            .await?;
+        Ok(merge(checked.errors, results))
+    }
+}
+
+/// run the batches of a stream as they arrive and answer every one with its own response, a batch
+/// failing as a whole ends the stream once the batches before it were answered
+pub(crate) fn stream_batches<T, S, F, Fut>(mut batches: S, run: F) -> BatchStream
+where
+    T: Validator + Send + 'static,
+    S: Stream<Item = Result<T, Status>> + Send + Unpin + 'static,
+    F: Fn(T) -> Fut + Send + 'static,
+    Fut: Future<Output = Result<Vec<BatchResult>, abi::Error>> + Send,
+{
+    let (tx, mut rx) = mpsc::channel(4);
+    tokio::spawn(async move {
+        loop {
+            let response = match batches.next().await {
+                Some(Ok(batch)) => match batch.validate() {
+                    Ok(()) => run(batch).await.map(|results| BatchResponse { results }),
+                    Err(e) => Err(e),
+                }
+                .map_err(Status::from),
+                Some(Err(status)) => Err(status),
+                None => return,
+            };
+            let failed = response.is_err();
+            // the caller is gone once the receiver is dropped
+            if tx.send(response).await.is_err() || failed {
+                return;
+            }
+        }
+    });
+    Box::pin(futures::stream::poll_fn(move |cx| rx.poll_recv(cx)))
+}
+
+/// The requests of a batch that may run, and why each of the others may not.
+struct Checked<T> {
+    accepted: Vec<T>,
+    /// one for every request of the batch, None for the accepted ones
+    errors: Vec<Option<abi::Error>>,
+}
+
+/// validate every request of a batch and check the caller may run it, given what was looked up
+/// for it, an atomic batch with a request failing either runs none
+fn check_batch<T, L, F>(requests: Vec<T>, found: Vec<L>, atomic: bool, check: F) -> Checked<T>
+where
+    T: Validator,
+    F: Fn(T, L) -> Result<T, abi::Error>,
+{
+    let mut accepted = Vec::with_capacity(requests.len());
+    let mut errors = Vec::with_capacity(requests.len());
+    for (request, found) in requests.into_iter().zip(found) {
+        let checked = match request.validate() {
+            Ok(()) => check(request, found),
+            Err(e) => Err(e),
+        };
+        match checked {
+            Ok(request) => {
+                accepted.push(request);
+                errors.push(None);
+            }
+            Err(e) => errors.push(Some(e)),
+        }
+    }
+
+    if atomic && errors.iter().any(Option::is_some) {
+        accepted.clear();
+        for error in errors.iter_mut() {
+            error.get_or_insert(abi::Error::BatchAborted);
+        }
+    }
+    Checked { accepted, errors }
+}
+
+/// put the results of the accepted requests back between the rejected ones, in request order
+fn merge(
+    errors: Vec<Option<abi::Error>>,
+    results: Vec<Result<Document, abi::Error>>,
+) -> Vec<BatchResult> {
+    let mut results = results.into_iter();
+    errors
+        .into_iter()
+        .map(|error| {
+            let result = match error {
+                Some(e) => Err(e),
+                None => results.next().unwrap_or(Err(abi::Error::Unknown)),
+            };
+            match result {
+                Ok(document) => BatchResult {
+                    document: Some(document),
+                    ..Default::default()
+                },
+                Err(e) => {
+                    let status = Status::from(e);
+                    BatchResult {
+                        document: None,
+                        code: status.code() as i32,
+                        message: status.message().to_string(),
+                    }
+                }
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use abi::DeleteRequest;
+    use tonic::Code;
+
+    use super::*;
+
+    const ID: &str = "0d6f2a4c-3b1e-4f7a-9c58-6e2d1b7a9f30";
+    const DENIED_ID: &str = "9e4b7c1d-2a6f-4d83-b5e0-7c3f8a1d6b24";
+
+    fn delete_request(id: &str) -> DeleteRequest {
+        DeleteRequest {
+            id: id.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// accept every valid request but those for DENIED_ID
+    fn check(requests: Vec<DeleteRequest>, atomic: bool) -> Checked<DeleteRequest> {
+        let found = requests.iter().map(|r| r.id.clone()).collect();
+        check_batch(requests, found, atomic, |request, id| {
+            if id == DENIED_ID {
+                Err(abi::Error::PermissionDenied("not yours".to_string()))
+            } else {
+                Ok(request)
+            }
+        })
+    }
+
+    fn document(id: &str) -> Document {
+        Document {
+            id: id.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn check_batch_should_reject_requests_on_their_own() {
+        let checked = check(vec![delete_request(ID), delete_request(DENIED_ID)], false);
+        assert_eq!(checked.accepted, vec![delete_request(ID)]);
+        assert!(checked.errors[0].is_none());
+        assert!(matches!(
+            checked.errors[1],
+            Some(abi::Error::PermissionDenied(_))
+        ));
+    }
+
+    #[test]
+    fn atomic_check_batch_should_abort_every_other_request() {
+        let requests = vec![
+            delete_request(ID),
+            delete_request("not-a-uuid"),
+            delete_request(ID),
+        ];
+        let checked = check(requests, true);
+        assert!(checked.accepted.is_empty());
+        assert!(matches!(checked.errors[0], Some(abi::Error::BatchAborted)));
+        assert!(matches!(
+            checked.errors[1],
+            Some(abi::Error::InvalidRequest(_))
+        ));
+        assert!(matches!(checked.errors[2], Some(abi::Error::BatchAborted)));
+    }
+
+    #[tokio::test]
+    async fn stream_batches_should_answer_every_batch_until_one_fails() {
+        let batch = |id: &str| BatchDeleteRequest {
+            requests: vec![delete_request(id)],
+            atomic: false,
+        };
+        let batches = futures::stream::iter(vec![
+            Ok(batch(ID)),
+            // more than the 1000 requests a batch may have
+            Ok(BatchDeleteRequest {
+                requests: vec![delete_request(ID); 1001],
+                atomic: false,
+            }),
+            Ok(batch(ID)),
+        ]);
+        let stream = stream_batches(batches, |batch: BatchDeleteRequest| async move {
+            let results = batch.requests.iter().map(|r| Ok(document(&r.id)));
+            Ok(merge(
+                batch.requests.iter().map(|_| None).collect(),
+                results.collect(),
+            ))
+        });
+
+        let responses: Vec<_> = stream.collect().await;
+        assert_eq!(responses.len(), 2);
+        let first = responses[0].as_ref().unwrap();
+        assert_eq!(first.results[0].document, Some(document(ID)));
+        assert_eq!(
+            responses[1].as_ref().unwrap_err().code(),
+            Code::InvalidArgument
+        );
+    }
+
+    #[test]
+    fn merge_should_keep_request_order() {
+        let errors = vec![None, Some(abi::Error::NotFound), None];
+        let results = vec![Ok(document(ID)), Err(abi::Error::BatchAborted)];
+
+        let merged = merge(errors, results);
+        assert_eq!(merged[0].document, Some(document(ID)));
+        assert_eq!(merged[0].code, Code::Ok as i32);
+        assert_eq!(merged[1].code, Code::NotFound as i32);
+        assert!(merged[1].document.is_none());
+        assert_eq!(merged[2].code, Code::Aborted as i32);
+    }
+}
@@ -1,15 +1,39 @@
+mod auth;
+mod batch;
+mod permission;
+mod purge;
+mod repair;
 mod service;
+mod user_service;
+mod watch;
+mod webhook;
 
 use std::pin::Pin;
 
-use abi::{document_collection_server::DocumentCollectionServer, Config, Document};
+use abi::{
+    document_collection_server::DocumentCollectionServer, user_service_server::UserServiceServer,
+    BatchResponse, Config, Document, DocumentChange, IdempotencyConfig, WebhookConfig,
+};
 use document_collection::DcManager;
 use futures::Stream;
 use tokio::sync::mpsc;
 use tonic::{transport::Server, Status};
+use user::UserManager;
+use watch::WatchHub;
 
+pub use auth::{AuthLayer, AuthService, Authenticator, Principal};
+
+#[derive(Clone)]
 pub struct DcService {
     manager: DcManager,
+    users: UserManager,
+    hub: WatchHub,
+    idempotency: IdempotencyConfig,
+    webhooks: WebhookConfig,
+}
+
+pub struct UserSvc {
+    manager: UserManager,
 }
 
 pub struct TonicReceiverStream<T> {
@@ -17,14 +41,32 @@ pub struct TonicReceiverStream<T> {
 }
 
 type DocumentStream = Pin<Box<dyn Stream<Item = Result<Document, Status>> + Send>>;
+type ChangeStream = Pin<Box<dyn Stream<Item = Result<DocumentChange, Status>> + Send>>;
+type BatchStream = Pin<Box<dyn Stream<Item = Result<BatchResponse, Status>> + Send>>;
 
 pub async fn start_server(config: &Config) -> Result<(), anyhow::Error> {
     let addr = format!("{}:{}", config.server.host, config.server.port).parse()?;
 
+    let auth = Authenticator::new(&config.auth)?;
     let svc = DcService::from_config(config).await?;
+    tokio::spawn(repair::run(svc.manager.clone()));
+    tokio::spawn(purge::run(
+        svc.manager.clone(),
+        config.trash.clone(),
+        config.changes.clone(),
+    ));
+    tokio::spawn(webhook::run(svc.manager.clone(), config.webhooks.clone()));
     let svc = DocumentCollectionServer::new(svc);
+    let user_svc = UserSvc::from_config(config).await?;
+    let auth = AuthLayer::new(auth, user_svc.manager.clone());
+    let user_svc = UserServiceServer::new(user_svc);
 
     println!("Listening on {}", addr);
-    Server::builder().add_service(svc).serve(addr).await?;
+    Server::builder()
+        .layer(auth)
+        .add_service(svc)
+        .add_service(user_svc)
+        .serve(addr)
+        .await?;
     Ok(())
 }
@@ -0,0 +1,509 @@
+use abi::{DocumentQuery, Permission, Role, User};
+use document_collection::Owner;
+use tonic::Request;
+
+use crate::Principal;
+
+/// Get the principal the authenticator put into the request.
+pub fn principal<T>(request: &Request<T>) -> Result<Principal, abi::Error> {
+    request
+        .extensions()
+        .get::<Principal>()
+        .cloned()
+        .ok_or_else(|| abi::Error::Unauthenticated("no principal".to_string()))
+}
+
+impl Principal {
+    fn owns(&self, user_id: &str) -> bool {
+        self.user_id.eq_ignore_ascii_case(user_id)
+    }
+
+    /// admins without an organization are the system admins, of every organization
+    fn is_system_admin(&self) -> bool {
+        self.role == Role::Admin && self.org_id.is_empty()
+    }
+
+    /// system admins are not limited to one organization, other callers without one belong to none
+    fn in_org(&self, org_id: &str) -> bool {
+        self.is_system_admin()
+            || (!self.org_id.is_empty() && self.org_id.eq_ignore_ascii_case(org_id))
+    }
+
+    fn check_permission(&self, permission: Permission) -> Result<(), abi::Error> {
+        if self.permissions.contains(&permission) {
+            Ok(())
+        } else {
+            Err(abi::Error::PermissionDenied(format!(
+                "missing permission {}",
+                permission.to_db()
+            )))
+        }
+    }
+
+    fn check_org(&self, org_id: &str) -> Result<(), abi::Error> {
+        if self.in_org(org_id) {
+            Ok(())
+        } else {
+            Err(abi::Error::PermissionDenied(
+                "belongs to another organization".to_string(),
+            ))
+        }
+    }
+
+    /// Store users may only read their own documents, admins and auditors everything in their organization.
+    pub fn check_read(&self, owner: &Owner) -> Result<(), abi::Error> {
+        self.check_permission(Permission::Read)?;
+        self.check_org(&owner.org_id)?;
+        match self.role {
+            Role::Admin | Role::Auditor => Ok(()),
+            Role::Store if self.owns(&owner.user_id) => Ok(()),
+            _ => Err(abi::Error::PermissionDenied(
+                "document belongs to another user".to_string(),
+            )),
+        }
+    }
+
+    /// Like check_read, but a document the caller may not read is not found, so callers cannot
+    /// tell whether documents of others exist.
+    pub fn check_found(&self, owner: &Owner) -> Result<(), abi::Error> {
+        self.check_permission(Permission::Read)?;
+        self.check_read(owner).map_err(|_| abi::Error::NotFound)
+    }
+
+    /// Store users may only write their own documents, admins everything in their organization and auditors nothing.
+    pub fn check_write(&self, owner: &Owner) -> Result<(), abi::Error> {
+        self.check_permission(Permission::Write)?;
+        self.check_org(&owner.org_id)?;
+        match self.role {
+            Role::Admin => Ok(()),
+            Role::Auditor => Err(abi::Error::PermissionDenied(
+                "auditors have read only access".to_string(),
+            )),
+            Role::Store if self.owns(&owner.user_id) => Ok(()),
+            _ => Err(abi::Error::PermissionDenied(
+                "document belongs to another user".to_string(),
+            )),
+        }
+    }
+
+    /// Restrict an organization id to the caller's organization, defaulting to it if empty.
+    pub fn scope_org(&self, org_id: &mut String) -> Result<(), abi::Error> {
+        if org_id.is_empty() {
+            org_id.clone_from(&self.org_id);
+        }
+        self.check_org(org_id)
+    }
+
+    /// Restrict a query to the caller's organization, and for store users to their own documents.
+    pub fn scope_query(&self, query: &mut DocumentQuery) -> Result<(), abi::Error> {
+        self.scope_org(&mut query.org_id)?;
+        if self.role != Role::Store {
+            return Ok(());
+        }
+        if query.user_id.is_empty() {
+            query.user_id = self.user_id.clone();
+        }
+        self.check_read(&Owner {
+            user_id: query.user_id.clone(),
+            org_id: query.org_id.clone(),
+        })
+    }
+
+    /// Only admins manage the users and stores of their organization, admins without one manage every organization.
+    pub fn check_org_admin(&self, org_id: &str) -> Result<(), abi::Error> {
+        if self.role != Role::Admin {
+            return Err(abi::Error::PermissionDenied(
+                "only admins manage users".to_string(),
+            ));
+        }
+        self.check_permission(Permission::ManageUsers)?;
+        self.check_org(org_id)
+    }
+
+    /// Only admins register document schemas, for their own organization.
+    pub fn check_register_schema(&self, org_id: &str) -> Result<(), abi::Error> {
+        if self.role != Role::Admin {
+            return Err(abi::Error::PermissionDenied(
+                "only admins register schemas".to_string(),
+            ));
+        }
+        self.check_permission(Permission::Write)?;
+        self.check_org(org_id)
+    }
+
+    /// Everybody may read the schemas of their organization, defaulting to it if empty.
+    pub fn scope_schemas(&self, org_id: &mut String) -> Result<(), abi::Error> {
+        self.check_permission(Permission::Read)?;
+        self.scope_org(org_id)
+    }
+
+    /// Collections are shared by every organization, so only admins without an organization manage them.
+    pub fn check_manage_collections(&self) -> Result<(), abi::Error> {
+        if !self.is_system_admin() {
+            return Err(abi::Error::PermissionDenied(
+                "only system admins manage collections".to_string(),
+            ));
+        }
+        self.check_permission(Permission::Write)
+    }
+
+    /// Admins and auditors read the changes of every document of their organization, defaulting to it if empty.
+    pub fn scope_changes(&self, org_id: &mut String) -> Result<(), abi::Error> {
+        if self.role == Role::Store {
+            return Err(abi::Error::PermissionDenied(
+                "only admins and auditors read changes".to_string(),
+            ));
+        }
+        self.check_permission(Permission::Read)?;
+        self.scope_org(org_id)
+    }
+
+    /// Only admins manage the webhooks of their organization, defaulting to it if empty,
+    /// admins without one also those of every organization.
+    pub fn scope_webhooks(&self, org_id: &mut String) -> Result<(), abi::Error> {
+        if org_id.is_empty() {
+            org_id.clone_from(&self.org_id);
+        }
+        self.check_webhook(org_id)
+    }
+
+    /// Only admins manage a webhook, of their organization or, for admins without one, of any.
+    pub fn check_webhook(&self, org_id: &str) -> Result<(), abi::Error> {
+        if self.role != Role::Admin {
+            return Err(abi::Error::PermissionDenied(
+                "only admins manage webhooks".to_string(),
+            ));
+        }
+        self.check_permission(Permission::Write)?;
+        self.check_org(org_id)
+    }
+
+    /// Everybody who may read documents may see which collections exist.
+    pub fn check_list_collections(&self) -> Result<(), abi::Error> {
+        self.check_permission(Permission::Read)
+    }
+
+    /// Everybody may look up themselves, admins the users of their organization.
+    pub fn check_user_read(&self, user: &User) -> Result<(), abi::Error> {
+        if self.owns(&user.id) {
+            return self.check_permission(Permission::Read);
+        }
+        self.check_org_admin(&user.org_id)
+    }
+
+    /// An api key may only hold permissions its issuer holds and the role of its user allows.
+    pub fn check_grant(&self, user: &User, permissions: &[Permission]) -> Result<(), abi::Error> {
+        let role = Role::try_from(user.role).unwrap_or_default();
+        for &permission in permissions {
+            self.check_permission(permission)?;
+            if !role_permissions(role).contains(&permission) {
+                return Err(abi::Error::PermissionDenied(format!(
+                    "role {} may not hold permission {}",
+                    role.to_db(),
+                    permission.to_db()
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Like check_user_read, but a user the caller may not look up is not found, so callers cannot
+    /// tell which user ids exist.
+    pub fn check_user_found(&self, user: &User) -> Result<(), abi::Error> {
+        self.check_user_read(user)
+            .map_err(|_| abi::Error::UserNotFound)
+    }
+
+    /// Admins manage the api keys of the users of their organization, everybody else only their own.
+    pub fn check_manage_keys(&self, user: &User) -> Result<(), abi::Error> {
+        self.check_permission(Permission::ManageUsers)?;
+        if self.owns(&user.id) {
+            return Ok(());
+        }
+        self.check_org_admin(&user.org_id)
+    }
+}
+
+/// the permissions api keys of users with a role may hold, auditors never write
+fn role_permissions(role: Role) -> &'static [Permission] {
+    match role {
+        Role::Admin | Role::Store => {
+            &[Permission::Read, Permission::Write, Permission::ManageUsers]
+        }
+        Role::Auditor => &[Permission::Read, Permission::ManageUsers],
+        Role::Unspecified => &[],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const USER_ID: &str = "a8e1a8ec-5a21-4d4b-a2b0-8d5d0b2a7c11";
+    const OTHER_ID: &str = "3f0c6a51-2b7e-4c1d-8e8f-6d1b9a2c4e70";
+    const ORG_ID: &str = "0d6c2c8a-4f3e-4b9a-9d51-7e2f1c3b5a10";
+    const OTHER_ORG_ID: &str = "7b2e4d6f-1a3c-4e5b-8d7f-9a1b3c5d7e90";
+
+    fn principal(role: Role) -> Principal {
+        Principal {
+            user_id: USER_ID.to_string(),
+            role,
+            store_id: String::new(),
+            org_id: ORG_ID.to_string(),
+            permissions: Permission::all(),
+        }
+    }
+
+    fn owner(user_id: &str, org_id: &str) -> Owner {
+        Owner {
+            user_id: user_id.to_string(),
+            org_id: org_id.to_string(),
+        }
+    }
+
+    fn user(id: &str, org_id: &str) -> User {
+        User {
+            id: id.to_string(),
+            org_id: org_id.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn store_user_should_only_touch_own_documents() {
+        let store = principal(Role::Store);
+        assert!(store.check_read(&owner(USER_ID, ORG_ID)).is_ok());
+        assert!(store.check_write(&owner(USER_ID, ORG_ID)).is_ok());
+        assert!(store.check_read(&owner(OTHER_ID, ORG_ID)).is_err());
+        assert!(store.check_write(&owner(OTHER_ID, ORG_ID)).is_err());
+    }
+
+    #[test]
+    fn documents_the_caller_may_not_read_should_not_be_found() {
+        let store = principal(Role::Store);
+        assert!(store.check_found(&owner(USER_ID, ORG_ID)).is_ok());
+        assert!(matches!(
+            store.check_found(&owner(OTHER_ID, ORG_ID)),
+            Err(abi::Error::NotFound)
+        ));
+        assert!(matches!(
+            principal(Role::Admin).check_found(&owner(OTHER_ID, OTHER_ORG_ID)),
+            Err(abi::Error::NotFound)
+        ));
+
+        let write_only = Principal {
+            permissions: vec![Permission::Write],
+            ..store
+        };
+        assert!(matches!(
+            write_only.check_found(&owner(USER_ID, ORG_ID)),
+            Err(abi::Error::PermissionDenied(_))
+        ));
+    }
+
+    #[test]
+    fn admin_should_read_and_write_everything_in_their_organization() {
+        let admin = principal(Role::Admin);
+        assert!(admin.check_read(&owner(OTHER_ID, ORG_ID)).is_ok());
+        assert!(admin.check_write(&owner(OTHER_ID, ORG_ID)).is_ok());
+        assert!(admin.check_read(&owner(OTHER_ID, OTHER_ORG_ID)).is_err());
+        assert!(admin.check_write(&owner(OTHER_ID, "")).is_err());
+
+        let system = Principal {
+            org_id: String::new(),
+            ..principal(Role::Admin)
+        };
+        assert!(system.check_write(&owner(OTHER_ID, OTHER_ORG_ID)).is_ok());
+    }
+
+    #[test]
+    fn only_system_admins_should_reach_every_organization() {
+        let orgless = |role| Principal {
+            org_id: String::new(),
+            ..principal(role)
+        };
+        assert!(orgless(Role::Admin)
+            .check_read(&owner(OTHER_ID, OTHER_ORG_ID))
+            .is_ok());
+        assert!(orgless(Role::Auditor)
+            .check_read(&owner(OTHER_ID, OTHER_ORG_ID))
+            .is_err());
+        assert!(orgless(Role::Auditor)
+            .check_read(&owner(OTHER_ID, ""))
+            .is_err());
+
+        let mut query = DocumentQuery::default();
+        assert!(orgless(Role::Auditor).scope_query(&mut query).is_err());
+    }
+
+    #[test]
+    fn auditor_should_be_read_only() {
+        let auditor = principal(Role::Auditor);
+        assert!(auditor.check_read(&owner(OTHER_ID, ORG_ID)).is_ok());
+        assert!(matches!(
+            auditor.check_write(&owner(USER_ID, ORG_ID)),
+            Err(abi::Error::PermissionDenied(_))
+        ));
+    }
+
+    #[test]
+    fn queries_should_be_scoped_to_own_organization_and_documents() {
+        let store = principal(Role::Store);
+        let mut query = DocumentQuery::default();
+        store.scope_query(&mut query).unwrap();
+        assert_eq!(query.user_id, USER_ID);
+        assert_eq!(query.org_id, ORG_ID);
+
+        let mut query = DocumentQuery {
+            user_id: OTHER_ID.to_string(),
+            ..Default::default()
+        };
+        assert!(store.scope_query(&mut query).is_err());
+
+        let mut query = DocumentQuery::default();
+        principal(Role::Auditor).scope_query(&mut query).unwrap();
+        assert!(query.user_id.is_empty());
+        assert_eq!(query.org_id, ORG_ID);
+
+        let mut query = DocumentQuery {
+            org_id: OTHER_ORG_ID.to_string(),
+            ..Default::default()
+        };
+        assert!(principal(Role::Admin).scope_query(&mut query).is_err());
+    }
+
+    #[test]
+    fn only_admins_should_manage_users_of_their_organization() {
+        let admin = principal(Role::Admin);
+        assert!(admin.check_org_admin(ORG_ID).is_ok());
+        assert!(admin.check_org_admin(OTHER_ORG_ID).is_err());
+        assert!(principal(Role::Store).check_org_admin(ORG_ID).is_err());
+        assert!(principal(Role::Store)
+            .check_user_read(&user(USER_ID, ORG_ID))
+            .is_ok());
+        assert!(principal(Role::Auditor)
+            .check_user_read(&user(OTHER_ID, ORG_ID))
+            .is_err());
+    }
+
+    #[test]
+    fn users_the_caller_may_not_look_up_should_not_be_found() {
+        let store = principal(Role::Store);
+        assert!(store.check_user_found(&user(USER_ID, ORG_ID)).is_ok());
+        assert!(matches!(
+            store.check_user_found(&user(OTHER_ID, ORG_ID)),
+            Err(abi::Error::UserNotFound)
+        ));
+        assert!(matches!(
+            principal(Role::Admin).check_user_found(&user(OTHER_ID, OTHER_ORG_ID)),
+            Err(abi::Error::UserNotFound)
+        ));
+    }
+
+    #[test]
+    fn only_admins_should_register_schemas_of_their_organization() {
+        assert!(principal(Role::Admin).check_register_schema(ORG_ID).is_ok());
+        assert!(principal(Role::Admin)
+            .check_register_schema(OTHER_ORG_ID)
+            .is_err());
+        assert!(principal(Role::Auditor)
+            .check_register_schema(ORG_ID)
+            .is_err());
+
+        let mut org_id = String::new();
+        principal(Role::Store).scope_schemas(&mut org_id).unwrap();
+        assert_eq!(org_id, ORG_ID);
+        let mut org_id = OTHER_ORG_ID.to_string();
+        assert!(principal(Role::Store).scope_schemas(&mut org_id).is_err());
+    }
+
+    #[test]
+    fn only_system_admins_should_manage_collections() {
+        let system = Principal {
+            org_id: String::new(),
+            ..principal(Role::Admin)
+        };
+        assert!(system.check_manage_collections().is_ok());
+        assert!(principal(Role::Admin).check_manage_collections().is_err());
+        assert!(principal(Role::Store).check_list_collections().is_ok());
+    }
+
+    #[test]
+    fn only_admins_and_auditors_should_read_changes() {
+        let mut org_id = String::new();
+        assert!(principal(Role::Auditor).scope_changes(&mut org_id).is_ok());
+        assert_eq!(org_id, ORG_ID);
+        assert!(principal(Role::Store)
+            .scope_changes(&mut String::new())
+            .is_err());
+        assert!(principal(Role::Admin)
+            .scope_changes(&mut OTHER_ORG_ID.to_string())
+            .is_err());
+    }
+
+    #[test]
+    fn only_admins_should_manage_webhooks() {
+        let mut org_id = String::new();
+        assert!(principal(Role::Admin).scope_webhooks(&mut org_id).is_ok());
+        assert_eq!(org_id, ORG_ID);
+        assert!(principal(Role::Auditor)
+            .scope_webhooks(&mut String::new())
+            .is_err());
+        // a webhook of every organization belongs to none
+        assert!(principal(Role::Admin).check_webhook("").is_err());
+        let system = Principal {
+            org_id: String::new(),
+            ..principal(Role::Admin)
+        };
+        assert!(system.check_webhook("").is_ok());
+        assert!(system.check_webhook(OTHER_ORG_ID).is_ok());
+    }
+
+    #[test]
+    fn api_key_permissions_should_limit_the_role() {
+        let read_only = Principal {
+            permissions: vec![Permission::Read],
+            ..principal(Role::Admin)
+        };
+        assert!(read_only.check_read(&owner(OTHER_ID, ORG_ID)).is_ok());
+        assert!(read_only.check_write(&owner(OTHER_ID, ORG_ID)).is_err());
+        assert!(read_only.check_org_admin(ORG_ID).is_err());
+        assert!(read_only.check_manage_keys(&user(USER_ID, ORG_ID)).is_err());
+        assert!(principal(Role::Store)
+            .check_manage_keys(&user(USER_ID, ORG_ID))
+            .is_ok());
+        assert!(principal(Role::Store)
+            .check_manage_keys(&user(OTHER_ID, ORG_ID))
+            .is_err());
+    }
+
+    #[test]
+    fn api_keys_should_only_get_permissions_their_issuer_holds() {
+        let manage_only = Principal {
+            permissions: vec![Permission::ManageUsers],
+            ..principal(Role::Admin)
+        };
+        let store = User {
+            role: Role::Store as i32,
+            ..user(OTHER_ID, ORG_ID)
+        };
+        assert!(manage_only
+            .check_grant(&store, &[Permission::ManageUsers])
+            .is_ok());
+        assert!(matches!(
+            manage_only.check_grant(&store, &[Permission::Write]),
+            Err(abi::Error::PermissionDenied(_))
+        ));
+
+        let auditor = User {
+            role: Role::Auditor as i32,
+            ..user(OTHER_ID, ORG_ID)
+        };
+        let admin = principal(Role::Admin);
+        assert!(admin.check_grant(&auditor, &[Permission::Read]).is_ok());
+        assert!(matches!(
+            admin.check_grant(&auditor, &[Permission::Write]),
+            Err(abi::Error::PermissionDenied(_))
+        ));
+    }
+}
@@ -0,0 +1,33 @@
+use std::time::Duration;
+
+use abi::{ChangesConfig, TrashConfig};
+use chrono::{Days, Utc};
+use document_collection::{Changes, Dc, DcManager, Idempotency};
+use tracing::{info, warn};
+
+/// periodically hard-delete documents that have been in the trash longer than the retention, and
+/// changes recorded longer ago than theirs
+pub async fn run(manager: DcManager, config: TrashConfig, changes: ChangesConfig) {
+    let mut interval = tokio::time::interval(Duration::from_secs(config.purge_interval_secs));
+    loop {
+        interval.tick().await;
+
+        let deleted_before = Utc::now() - Days::new(config.retention_days.into());
+        match manager.purge(deleted_before).await {
+            Ok(0) => {}
+            Ok(n) => info!("Purged {} deleted documents", n),
+            Err(e) => warn!("Purge error: {:?}", e),
+        }
+        let recorded_before = Utc::now() - Days::new(changes.retention_days.into());
+        match manager.purge_changes(recorded_before).await {
+            Ok(0) => {}
+            Ok(n) => info!("Purged {} changes past their retention", n),
+            Err(e) => warn!("Purge error: {:?}", e),
+        }
+        match manager.purge_idempotency_keys().await {
+            Ok(0) => {}
+            Ok(n) => info!("Purged {} expired idempotency keys", n),
+            Err(e) => warn!("Purge error: {:?}", e),
+        }
+    }
+}
@@ -0,0 +1,11 @@
+use document_collection::{Collections, DcManager};
+use tracing::{info, warn};
+
+/// finish creating or dropping the collections a server stopped working on midway
+pub async fn run(manager: DcManager) {
+    match manager.repair_collections().await {
+        Ok(0) => {}
+        Ok(n) => info!("Repaired {} collections", n),
+        Err(e) => warn!("Repair collections error: {:?}", e),
+    }
+}
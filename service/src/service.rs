@@ -1,23 +1,103 @@
-use std::{pin::Pin, task::Poll};
+use std::{pin::Pin, task::Poll, time::Duration};
 
 use abi::{
-    document_collection_server::DocumentCollection, Config, CreateRequest, CreateResponse,
-    DeleteRequest, DeleteResponse, DocumentQuery, GetRequest, GetResponse, QueryRequest,
-    UpdateRequest, UpdateResponse,
+    document_collection_server::DocumentCollection, AggregateRequest, AggregateResponse,
+    BatchCreateRequest, BatchDeleteRequest, BatchResponse, BatchUpdateRequest, Config,
+    CreateCollectionRequest, CreateCollectionResponse, CreateRequest, CreateResponse,
+    CreateWebhookRequest, CreateWebhookResponse, DeleteRequest, DeleteResponse,
+    DeleteWebhookRequest, DeleteWebhookResponse, DropCollectionRequest, DropCollectionResponse,
+    FieldViolation, GetRequest, GetResponse, GetSchemaRequest, GetSchemaResponse,
+    ListCollectionsRequest, ListCollectionsResponse, ListDeliveriesRequest, ListDeliveriesResponse,
+    ListResponse, ListRevisionsRequest, ListRevisionsResponse, ListSchemasRequest,
+    ListSchemasResponse, ListWebhooksRequest, ListWebhooksResponse, PatchRequest, PatchResponse,
+    QueryRequest, ReadChangesRequest, RegisterSchemaRequest, RegisterSchemaResponse,
+    RestoreRequest, RestoreResponse, UndeleteRequest, UndeleteResponse, UpdateRequest,
+    UpdateResponse, Validator, WatchRequest,
+};
+use document_collection::{
+    check_filter, Changes, Collections, Dc, DcManager, Idempotency, IdempotencyKey, Owner, Schemas,
+    Webhooks,
 };
-use document_collection::{Dc, DcManager};
 use futures::Stream;
 use tokio::sync::mpsc;
-use tonic::{async_trait, Request, Response, Status};
+use tonic::{async_trait, Request, Response, Status, Streaming};
+use user::UserManager;
 
-use crate::{DcService, DocumentStream, TonicReceiverStream};
+use crate::{
+    batch::stream_batches, permission::principal, webhook, BatchStream, ChangeStream, DcService,
+    DocumentStream, Principal, TonicReceiverStream, WatchHub,
+};
 
 impl DcService {
     pub async fn from_config(config: &Config) -> Result<Self, anyhow::Error> {
+        let manager = DcManager::from_config(&config.db).await?;
         Ok(Self {
-            manager: DcManager::from_config(&config.db).await?,
+            hub: WatchHub::start(manager.clone()),
+            manager,
+            users: UserManager::from_config(&config.db).await?,
+            idempotency: config.idempotency.clone(),
+            webhooks: config.webhooks.clone(),
         })
     }
+
+    /// the key of a retried write, scoped to the caller
+    fn idempotency_key(&self, principal: &Principal, key: String) -> (IdempotencyKey, Duration) {
+        let key = IdempotencyKey {
+            user_id: principal.user_id.clone(),
+            key,
+        };
+        (key, Duration::from_secs(self.idempotency.ttl_secs))
+    }
+
+    /// check the caller may write the document with the given id
+    pub(crate) async fn check_write(
+        &self,
+        principal: &Principal,
+        collection: &str,
+        id: &str,
+    ) -> Result<(), abi::Error> {
+        let owner = self
+            .manager
+            .owner(collection.to_string(), id.to_string())
+            .await?;
+        principal.check_write(&owner)
+    }
+}
+
+/// deliveries list_deliveries returns if no limit is given
+const DEFAULT_DELIVERIES: i64 = 50;
+
+/// consume the request, rejecting it with every field violation it has
+pub(crate) fn validated<T: Validator>(request: Request<T>) -> Result<T, abi::Error> {
+    let request = request.into_inner();
+    request.validate()?;
+    Ok(request)
+}
+
+/// metadata a client may send the idempotency key of a write in, instead of the request
+const IDEMPOTENCY_KEY_HEADER: &str = "idempotency-key";
+
+/// like validated, taking the idempotency key of a write from its header if the request has none
+fn validated_write<T: Validator>(
+    request: Request<T>,
+    key: impl FnOnce(&mut T) -> &mut String,
+) -> Result<T, abi::Error> {
+    let header = request.metadata().get(IDEMPOTENCY_KEY_HEADER).cloned();
+    let mut request = request.into_inner();
+    let key = key(&mut request);
+    if let Some(header) = header.filter(|_| key.is_empty()) {
+        *key = header
+            .to_str()
+            .map_err(|_| {
+                abi::Error::InvalidRequest(vec![FieldViolation {
+                    field: "idempotency_key".to_string(),
+                    description: format!("the {} header is not ascii", IDEMPOTENCY_KEY_HEADER),
+                }])
+            })?
+            .to_string();
+    }
+    request.validate()?;
+    Ok(request)
 }
 
 #[async_trait]
@@ -26,68 +106,496 @@ impl DocumentCollection for DcService {
         &self,
         request: Request<CreateRequest>,
     ) -> Result<Response<CreateResponse>, Status> {
-        let request = request.into_inner();
-        if let (user_id, Some(data)) = (request.user_id, request.data) {
-            let document = self.manager.create(user_id, data).await?;
-            Ok(Response::new(CreateResponse {
-                document: Some(document),
-            }))
+        let principal = principal(&request)?;
+        let request = validated_write(request, |r| &mut r.idempotency_key)?;
+        let request = self.check_create(&principal, request).await?;
+
+        let document = if request.idempotency_key.is_empty() {
+            let data = request.data.unwrap_or_default();
+            self.manager
+                .create(
+                    request.collection,
+                    request.user_id,
+                    data,
+                    request.items,
+                    request.document_type,
+                )
+                .await?
         } else {
-            Err(Status::invalid_argument("missing data"))
-        }
+            let (key, ttl) = self.idempotency_key(&principal, request.idempotency_key.clone());
+            self.manager.create_once(key, ttl, request).await?
+        };
+        Ok(Response::new(CreateResponse {
+            document: Some(document),
+        }))
     }
 
     async fn update(
         &self,
         request: Request<UpdateRequest>,
     ) -> Result<Response<UpdateResponse>, Status> {
-        let request = request.into_inner();
-        if let (id, Some(data)) = (request.id, request.data) {
-            let document = self.manager.update(id, data).await?;
+        let principal = principal(&request)?;
+        let request = validated_write(request, |r| &mut r.idempotency_key)?;
+        self.check_write(&principal, &request.collection, &request.id)
+            .await?;
 
-            Ok(Response::new(UpdateResponse {
-                document: Some(document),
-            }))
+        let document = if request.idempotency_key.is_empty() {
+            let items = (!request.items.is_empty()).then_some(request.items);
+            let expected_version =
+                (request.expected_version > 0).then_some(request.expected_version);
+            self.manager
+                .update(
+                    request.collection,
+                    request.id,
+                    request.data,
+                    items,
+                    expected_version,
+                )
+                .await?
         } else {
-            Err(Status::invalid_argument("missing data"))
-        }
+            let (key, ttl) = self.idempotency_key(&principal, request.idempotency_key.clone());
+            self.manager.update_once(key, ttl, request).await?
+        };
+
+        Ok(Response::new(UpdateResponse {
+            document: Some(document),
+        }))
+    }
+
+    async fn patch(
+        &self,
+        request: Request<PatchRequest>,
+    ) -> Result<Response<PatchResponse>, Status> {
+        let principal = principal(&request)?;
+        let request = validated(request)?;
+        let Some(patch) = request.patch else {
+            return Err(abi::Error::InvalidPatch("missing patch".to_string()).into());
+        };
+        self.check_write(&principal, &request.collection, &request.id)
+            .await?;
+
+        let expected_version = (request.expected_version > 0).then_some(request.expected_version);
+        let document = self
+            .manager
+            .patch(request.collection, request.id, patch, expected_version)
+            .await?;
+
+        Ok(Response::new(PatchResponse {
+            document: Some(document),
+        }))
     }
 
     async fn delete(
         &self,
         request: Request<DeleteRequest>,
     ) -> Result<Response<DeleteResponse>, Status> {
-        let request = request.into_inner();
-        let document = self.manager.delete(request.id).await?;
+        let principal = principal(&request)?;
+        let request = validated(request)?;
+        self.check_write(&principal, &request.collection, &request.id)
+            .await?;
+        let expected_version = (request.expected_version > 0).then_some(request.expected_version);
+        let document = self
+            .manager
+            .delete(request.collection, request.id, expected_version)
+            .await?;
 
         Ok(Response::new(DeleteResponse {
             document: Some(document),
         }))
     }
 
+    async fn batch_create(
+        &self,
+        request: Request<BatchCreateRequest>,
+    ) -> Result<Response<BatchResponse>, Status> {
+        let principal = principal(&request)?;
+        let request = validated(request)?;
+        let results = self.run_batch_create(&principal, request).await?;
+        Ok(Response::new(BatchResponse { results }))
+    }
+
+    async fn batch_update(
+        &self,
+        request: Request<BatchUpdateRequest>,
+    ) -> Result<Response<BatchResponse>, Status> {
+        let principal = principal(&request)?;
+        let request = validated(request)?;
+        let results = self.run_batch_update(&principal, request).await?;
+        Ok(Response::new(BatchResponse { results }))
+    }
+
+    async fn batch_delete(
+        &self,
+        request: Request<BatchDeleteRequest>,
+    ) -> Result<Response<BatchResponse>, Status> {
+        let principal = principal(&request)?;
+        let request = validated(request)?;
+        let results = self.run_batch_delete(&principal, request).await?;
+        Ok(Response::new(BatchResponse { results }))
+    }
+
+    type batch_create_streamStream = BatchStream;
+    async fn batch_create_stream(
+        &self,
+        request: Request<Streaming<BatchCreateRequest>>,
+    ) -> Result<Response<Self::batch_create_streamStream>, Status> {
+        let principal = principal(&request)?;
+        let svc = self.clone();
+        let stream = stream_batches(request.into_inner(), move |batch| {
+            let (svc, principal) = (svc.clone(), principal.clone());
+            async move { svc.run_batch_create(&principal, batch).await }
+        });
+        Ok(Response::new(stream))
+    }
+
+    type batch_update_streamStream = BatchStream;
+    async fn batch_update_stream(
+        &self,
+        request: Request<Streaming<BatchUpdateRequest>>,
+    ) -> Result<Response<Self::batch_update_streamStream>, Status> {
+        let principal = principal(&request)?;
+        let svc = self.clone();
+        let stream = stream_batches(request.into_inner(), move |batch| {
+            let (svc, principal) = (svc.clone(), principal.clone());
+            async move { svc.run_batch_update(&principal, batch).await }
+        });
+        Ok(Response::new(stream))
+    }
+
+    type batch_delete_streamStream = BatchStream;
+    async fn batch_delete_stream(
+        &self,
+        request: Request<Streaming<BatchDeleteRequest>>,
+    ) -> Result<Response<Self::batch_delete_streamStream>, Status> {
+        let principal = principal(&request)?;
+        let svc = self.clone();
+        let stream = stream_batches(request.into_inner(), move |batch| {
+            let (svc, principal) = (svc.clone(), principal.clone());
+            async move { svc.run_batch_delete(&principal, batch).await }
+        });
+        Ok(Response::new(stream))
+    }
+
+    async fn undelete(
+        &self,
+        request: Request<UndeleteRequest>,
+    ) -> Result<Response<UndeleteResponse>, Status> {
+        let principal = principal(&request)?;
+        let request = validated(request)?;
+        self.check_write(&principal, &request.collection, &request.id)
+            .await?;
+        let document = self
+            .manager
+            .undelete(request.collection, request.id)
+            .await?;
+
+        Ok(Response::new(UndeleteResponse {
+            document: Some(document),
+        }))
+    }
+
     async fn get(&self, request: Request<GetRequest>) -> Result<Response<GetResponse>, Status> {
-        let request = request.into_inner();
-        let document = self.manager.get(request.id).await?;
+        let principal = principal(&request)?;
+        let request = validated(request)?;
+        let document = if request.revision > 0 {
+            self.manager
+                .get_revision(request.collection, request.id, request.revision)
+                .await?
+        } else {
+            self.manager
+                .get(request.collection, request.id, request.include_deleted)
+                .await?
+        };
+        principal.check_found(&Owner {
+            user_id: document.user_id.clone(),
+            org_id: document.org_id.clone(),
+        })?;
 
         Ok(Response::new(GetResponse {
             document: Some(document),
         }))
     }
 
+    async fn list_revisions(
+        &self,
+        request: Request<ListRevisionsRequest>,
+    ) -> Result<Response<ListRevisionsResponse>, Status> {
+        let principal = principal(&request)?;
+        let request = validated(request)?;
+        let owner = self
+            .manager
+            .owner(request.collection.clone(), request.id.clone())
+            .await?;
+        principal.check_found(&owner)?;
+        let revisions = self
+            .manager
+            .list_revisions(request.collection, request.id)
+            .await?;
+
+        Ok(Response::new(ListRevisionsResponse { revisions }))
+    }
+
+    async fn restore(
+        &self,
+        request: Request<RestoreRequest>,
+    ) -> Result<Response<RestoreResponse>, Status> {
+        let principal = principal(&request)?;
+        let request = validated(request)?;
+        self.check_write(&principal, &request.collection, &request.id)
+            .await?;
+        let expected_version = (request.expected_version > 0).then_some(request.expected_version);
+        let document = self
+            .manager
+            .restore(
+                request.collection,
+                request.id,
+                request.revision,
+                expected_version,
+            )
+            .await?;
+
+        Ok(Response::new(RestoreResponse {
+            document: Some(document),
+        }))
+    }
+
     type queryStream = DocumentStream;
     async fn query(
         &self,
         request: Request<QueryRequest>,
     ) -> Result<Response<Self::queryStream>, Status> {
-        let request = request.into_inner();
-        if let Some(request) = request.query {
-            let docs = self.manager.query(request).await;
+        let principal = principal(&request)?;
+        let mut request = validated(request)?;
+        principal.scope_query(request.query.get_or_insert_with(Default::default))?;
 
-            let stream = TonicReceiverStream::new(docs);
-            Ok(Response::new(Box::pin(stream) as Self::queryStream))
-        } else {
-            Err(Status::invalid_argument("missing query"))
+        let docs = self.manager.query(request).await;
+        let stream = TonicReceiverStream::new(docs);
+        Ok(Response::new(Box::pin(stream) as Self::queryStream))
+    }
+
+    async fn aggregate(
+        &self,
+        request: Request<AggregateRequest>,
+    ) -> Result<Response<AggregateResponse>, Status> {
+        let principal = principal(&request)?;
+        let mut request = validated(request)?;
+        principal.scope_query(request.query.get_or_insert_with(Default::default))?;
+        let response = self.manager.aggregate(request).await?;
+
+        Ok(Response::new(response))
+    }
+
+    async fn list(&self, request: Request<QueryRequest>) -> Result<Response<ListResponse>, Status> {
+        let principal = principal(&request)?;
+        let mut request = validated(request)?;
+        principal.scope_query(request.query.get_or_insert_with(Default::default))?;
+
+        let page = self.manager.list(request).await?;
+        Ok(Response::new(page))
+    }
+
+    async fn register_schema(
+        &self,
+        request: Request<RegisterSchemaRequest>,
+    ) -> Result<Response<RegisterSchemaResponse>, Status> {
+        let principal = principal(&request)?;
+        let mut request = validated(request)?;
+        principal.scope_org(&mut request.org_id)?;
+        principal.check_register_schema(&request.org_id)?;
+        let schema = self
+            .manager
+            .register_schema(
+                request.org_id,
+                request.document_type,
+                request.schema.unwrap_or_default(),
+            )
+            .await?;
+
+        Ok(Response::new(RegisterSchemaResponse {
+            schema: Some(schema),
+        }))
+    }
+
+    async fn get_schema(
+        &self,
+        request: Request<GetSchemaRequest>,
+    ) -> Result<Response<GetSchemaResponse>, Status> {
+        let principal = principal(&request)?;
+        let mut request = validated(request)?;
+        principal.scope_schemas(&mut request.org_id)?;
+        let version = (request.version > 0).then_some(request.version);
+        let schema = self
+            .manager
+            .get_schema(request.org_id, request.document_type, version)
+            .await?;
+
+        Ok(Response::new(GetSchemaResponse {
+            schema: Some(schema),
+        }))
+    }
+
+    async fn list_schemas(
+        &self,
+        request: Request<ListSchemasRequest>,
+    ) -> Result<Response<ListSchemasResponse>, Status> {
+        let principal = principal(&request)?;
+        let mut request = validated(request)?;
+        principal.scope_schemas(&mut request.org_id)?;
+        let document_type = (!request.document_type.is_empty()).then_some(request.document_type);
+        let schemas = self
+            .manager
+            .list_schemas(request.org_id, document_type)
+            .await?;
+
+        Ok(Response::new(ListSchemasResponse { schemas }))
+    }
+
+    async fn create_collection(
+        &self,
+        request: Request<CreateCollectionRequest>,
+    ) -> Result<Response<CreateCollectionResponse>, Status> {
+        let principal = principal(&request)?;
+        principal.check_manage_collections()?;
+        let request = validated(request)?;
+        let collection = self
+            .manager
+            .create_collection(request.collection.unwrap_or_default())
+            .await?;
+
+        Ok(Response::new(CreateCollectionResponse {
+            collection: Some(collection),
+        }))
+    }
+
+    async fn list_collections(
+        &self,
+        request: Request<ListCollectionsRequest>,
+    ) -> Result<Response<ListCollectionsResponse>, Status> {
+        principal(&request)?.check_list_collections()?;
+        let collections = self.manager.list_collections().await?;
+
+        Ok(Response::new(ListCollectionsResponse { collections }))
+    }
+
+    async fn drop_collection(
+        &self,
+        request: Request<DropCollectionRequest>,
+    ) -> Result<Response<DropCollectionResponse>, Status> {
+        let principal = principal(&request)?;
+        principal.check_manage_collections()?;
+        let request = validated(request)?;
+        let collection = self
+            .manager
+            .drop_collection(request.name, request.force)
+            .await?;
+
+        Ok(Response::new(DropCollectionResponse {
+            collection: Some(collection),
+        }))
+    }
+
+    type watchStream = ChangeStream;
+    async fn watch(
+        &self,
+        request: Request<WatchRequest>,
+    ) -> Result<Response<Self::watchStream>, Status> {
+        let principal = principal(&request)?;
+        let mut request = validated(request)?;
+        let query = request.query.get_or_insert_with(Default::default);
+        principal.scope_query(query)?;
+        if let Some(filter) = &query.filter {
+            check_filter(filter)?;
+        }
+
+        let (tx, rx) = mpsc::channel(128);
+        self.hub.watch(
+            self.manager.clone(),
+            request.query.unwrap_or_default(),
+            request.after_sequence,
+            tx,
+        );
+        let stream = TonicReceiverStream::new(rx);
+        Ok(Response::new(Box::pin(stream) as Self::watchStream))
+    }
+
+    type read_changesStream = ChangeStream;
+    async fn read_changes(
+        &self,
+        request: Request<ReadChangesRequest>,
+    ) -> Result<Response<Self::read_changesStream>, Status> {
+        let principal = principal(&request)?;
+        let mut request = validated(request)?;
+        principal.scope_changes(&mut request.org_id)?;
+
+        let changes = self.manager.read_changes(request).await;
+        let stream = TonicReceiverStream::new(changes);
+        Ok(Response::new(Box::pin(stream) as Self::read_changesStream))
+    }
+
+    async fn create_webhook(
+        &self,
+        request: Request<CreateWebhookRequest>,
+    ) -> Result<Response<CreateWebhookResponse>, Status> {
+        let principal = principal(&request)?;
+        let request = validated(request)?;
+        let mut webhook = request.webhook.unwrap_or_default();
+        principal.scope_webhooks(&mut webhook.org_id)?;
+        webhook::check_url(&webhook.url, &self.webhooks)?;
+        if let Some(filter) = webhook.query.as_ref().and_then(|q| q.filter.as_ref()) {
+            check_filter(filter)?;
         }
+
+        let webhook = self.manager.create_webhook(webhook).await?;
+        Ok(Response::new(CreateWebhookResponse {
+            webhook: Some(webhook),
+        }))
+    }
+
+    async fn list_webhooks(
+        &self,
+        request: Request<ListWebhooksRequest>,
+    ) -> Result<Response<ListWebhooksResponse>, Status> {
+        let principal = principal(&request)?;
+        let mut request = validated(request)?;
+        principal.scope_webhooks(&mut request.org_id)?;
+
+        let webhooks = self.manager.list_webhooks(request.org_id).await?;
+        Ok(Response::new(ListWebhooksResponse { webhooks }))
+    }
+
+    async fn delete_webhook(
+        &self,
+        request: Request<DeleteWebhookRequest>,
+    ) -> Result<Response<DeleteWebhookResponse>, Status> {
+        let principal = principal(&request)?;
+        let request = validated(request)?;
+        let webhook = self.manager.get_webhook(request.id.clone()).await?;
+        principal.check_webhook(&webhook.org_id)?;
+
+        let webhook = self.manager.delete_webhook(request.id).await?;
+        Ok(Response::new(DeleteWebhookResponse {
+            webhook: Some(webhook),
+        }))
+    }
+
+    async fn list_deliveries(
+        &self,
+        request: Request<ListDeliveriesRequest>,
+    ) -> Result<Response<ListDeliveriesResponse>, Status> {
+        let principal = principal(&request)?;
+        let request = validated(request)?;
+        let webhook = self.manager.get_webhook(request.webhook_id.clone()).await?;
+        principal.check_webhook(&webhook.org_id)?;
+
+        let limit = match request.limit {
+            0 => DEFAULT_DELIVERIES,
+            n => i64::from(n),
+        };
+        let deliveries = self
+            .manager
+            .list_deliveries(request.webhook_id, limit)
+            .await?;
+        Ok(Response::new(ListDeliveriesResponse { deliveries }))
     }
 }
 
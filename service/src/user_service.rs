@@ -0,0 +1,205 @@
+use abi::{
+    user_service_server::UserService, Config, CreateOrganizationRequest,
+    CreateOrganizationResponse, CreateStoreRequest, CreateStoreResponse, CreateUserRequest,
+    CreateUserResponse, GetUserRequest, GetUserResponse, IssueApiKeyRequest, IssueApiKeyResponse,
+    ListApiKeysRequest, ListApiKeysResponse, ListOrganizationsRequest, ListOrganizationsResponse,
+    ListStoresRequest, ListStoresResponse, ListUsersRequest, ListUsersResponse, Permission,
+    RevokeApiKeyRequest, RevokeApiKeyResponse, RotateApiKeyRequest, RotateApiKeyResponse,
+    UpdateUserRequest, UpdateUserResponse,
+};
+use tonic::{async_trait, Request, Response, Status};
+use user::{ApiKeys, Organizations, UserManager, Users};
+
+use crate::{permission::principal, service::validated, UserSvc};
+
+impl UserSvc {
+    pub async fn from_config(config: &Config) -> Result<Self, anyhow::Error> {
+        Ok(Self {
+            manager: UserManager::from_config(&config.db).await?,
+        })
+    }
+}
+
+#[async_trait]
+impl UserService for UserSvc {
+    async fn create_user(
+        &self,
+        request: Request<CreateUserRequest>,
+    ) -> Result<Response<CreateUserResponse>, Status> {
+        let principal = principal(&request)?;
+        let mut user = validated(request)?.user.unwrap_or_default();
+        principal.scope_org(&mut user.org_id)?;
+        principal.check_org_admin(&user.org_id)?;
+        let user = self.manager.create(user).await?;
+        Ok(Response::new(CreateUserResponse { user: Some(user) }))
+    }
+
+    async fn get_user(
+        &self,
+        request: Request<GetUserRequest>,
+    ) -> Result<Response<GetUserResponse>, Status> {
+        let principal = principal(&request)?;
+        let id = validated(request)?.id;
+        let user = self.manager.get(id).await?;
+        principal.check_user_found(&user)?;
+        Ok(Response::new(GetUserResponse { user: Some(user) }))
+    }
+
+    async fn update_user(
+        &self,
+        request: Request<UpdateUserRequest>,
+    ) -> Result<Response<UpdateUserResponse>, Status> {
+        let principal = principal(&request)?;
+        let mut user = validated(request)?.user.unwrap_or_default();
+        let existing = self.manager.get(user.id.clone()).await?;
+        principal.check_org_admin(&existing.org_id)?;
+        principal.scope_org(&mut user.org_id)?;
+        let user = self.manager.update(user).await?;
+        Ok(Response::new(UpdateUserResponse { user: Some(user) }))
+    }
+
+    async fn list_users(
+        &self,
+        request: Request<ListUsersRequest>,
+    ) -> Result<Response<ListUsersResponse>, Status> {
+        let principal = principal(&request)?;
+        let mut request = request.into_inner();
+        principal.scope_org(&mut request.org_id)?;
+        principal.check_org_admin(&request.org_id)?;
+        let users = self
+            .manager
+            .list(request.active_only, request.org_id)
+            .await?;
+        Ok(Response::new(ListUsersResponse { users }))
+    }
+
+    async fn issue_api_key(
+        &self,
+        request: Request<IssueApiKeyRequest>,
+    ) -> Result<Response<IssueApiKeyResponse>, Status> {
+        let principal = principal(&request)?;
+        let request = request.into_inner();
+        let user = self.manager.get(request.user_id.clone()).await?;
+        principal.check_manage_keys(&user)?;
+        let permissions = permissions(request.permissions)?;
+        principal.check_grant(&user, &permissions)?;
+
+        let (api_key, secret) = self
+            .manager
+            .issue_key(request.user_id, request.name, permissions)
+            .await?;
+        Ok(Response::new(IssueApiKeyResponse {
+            api_key: Some(api_key),
+            secret,
+        }))
+    }
+
+    async fn list_api_keys(
+        &self,
+        request: Request<ListApiKeysRequest>,
+    ) -> Result<Response<ListApiKeysResponse>, Status> {
+        let principal = principal(&request)?;
+        let user_id = request.into_inner().user_id;
+        principal.check_manage_keys(&self.manager.get(user_id.clone()).await?)?;
+
+        let api_keys = self.manager.list_keys(user_id).await?;
+        Ok(Response::new(ListApiKeysResponse { api_keys }))
+    }
+
+    async fn rotate_api_key(
+        &self,
+        request: Request<RotateApiKeyRequest>,
+    ) -> Result<Response<RotateApiKeyResponse>, Status> {
+        let principal = principal(&request)?;
+        let id = request.into_inner().id;
+        let key = self.manager.get_key(id.clone()).await?;
+        let user = self.manager.get(key.user_id).await?;
+        principal.check_manage_keys(&user)?;
+        // a new secret for a key is as good as a new key with its permissions
+        principal.check_grant(&user, &permissions(key.permissions)?)?;
+
+        let (api_key, secret) = self.manager.rotate_key(id).await?;
+        Ok(Response::new(RotateApiKeyResponse {
+            api_key: Some(api_key),
+            secret,
+        }))
+    }
+
+    async fn revoke_api_key(
+        &self,
+        request: Request<RevokeApiKeyRequest>,
+    ) -> Result<Response<RevokeApiKeyResponse>, Status> {
+        let principal = principal(&request)?;
+        let id = request.into_inner().id;
+        let key = self.manager.get_key(id.clone()).await?;
+        principal.check_manage_keys(&self.manager.get(key.user_id).await?)?;
+
+        let api_key = self.manager.revoke_key(id).await?;
+        Ok(Response::new(RevokeApiKeyResponse {
+            api_key: Some(api_key),
+        }))
+    }
+
+    async fn create_organization(
+        &self,
+        request: Request<CreateOrganizationRequest>,
+    ) -> Result<Response<CreateOrganizationResponse>, Status> {
+        let principal = principal(&request)?;
+        // only admins without an organization of their own manage organizations
+        principal.check_org_admin("")?;
+        let organization = self
+            .manager
+            .create_organization(request.into_inner().name)
+            .await?;
+        Ok(Response::new(CreateOrganizationResponse {
+            organization: Some(organization),
+        }))
+    }
+
+    async fn list_organizations(
+        &self,
+        request: Request<ListOrganizationsRequest>,
+    ) -> Result<Response<ListOrganizationsResponse>, Status> {
+        principal(&request)?.check_org_admin("")?;
+        let organizations = self.manager.list_organizations().await?;
+        Ok(Response::new(ListOrganizationsResponse { organizations }))
+    }
+
+    async fn create_store(
+        &self,
+        request: Request<CreateStoreRequest>,
+    ) -> Result<Response<CreateStoreResponse>, Status> {
+        let principal = principal(&request)?;
+        let mut request = request.into_inner();
+        principal.scope_org(&mut request.org_id)?;
+        principal.check_org_admin(&request.org_id)?;
+        let store = self
+            .manager
+            .create_store(request.org_id, request.name)
+            .await?;
+        Ok(Response::new(CreateStoreResponse { store: Some(store) }))
+    }
+
+    async fn list_stores(
+        &self,
+        request: Request<ListStoresRequest>,
+    ) -> Result<Response<ListStoresResponse>, Status> {
+        let principal = principal(&request)?;
+        let mut request = request.into_inner();
+        principal.scope_org(&mut request.org_id)?;
+        principal.check_org_admin(&request.org_id)?;
+        let stores = self.manager.list_stores(request.org_id).await?;
+        Ok(Response::new(ListStoresResponse { stores }))
+    }
+}
+
+/// the permissions of a request, unknown values are rejected
+fn permissions(permissions: Vec<i32>) -> Result<Vec<Permission>, abi::Error> {
+    permissions
+        .into_iter()
+        .map(|p| {
+            Permission::try_from(p)
+                .map_err(|_| abi::Error::InvalidUser(format!("unknown permission {}", p)))
+        })
+        .collect()
+}
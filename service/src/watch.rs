@@ -0,0 +1,436 @@
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use abi::{DocumentChange, DocumentQuery};
+use chrono::Utc;
+use document_collection::{matches, Changes, DcManager};
+use tokio::sync::{
+    broadcast::{self, error::RecvError},
+    mpsc,
+};
+use tracing::{info, warn};
+
+/// changes a slow watcher may fall behind before it catches up from the database
+const HUB_CAPACITY: usize = 1024;
+/// changes read from the database at once while catching up
+const REPLAY_PAGE_SIZE: i64 = 500;
+/// wait before listening again after the connection failed
+const RECONNECT_DELAY: Duration = Duration::from_secs(1);
+/// wait at most this long for a notification before looking for changes held back until then
+const HELD_BACK_POLL: Duration = Duration::from_secs(1);
+/// how long committed changes may wait for an older transaction before it is reported
+const HELD_BACK_WARN: Duration = Duration::from_secs(30);
+
+/// Relays every committed change to the watchers of this server from one listening connection.
+#[derive(Clone)]
+pub struct WatchHub {
+    sender: broadcast::Sender<Arc<Relayed>>,
+}
+
+/// A change, in the order `Changes::list_changes` lists them.
+struct Relayed {
+    /// the sequence of the change listed before it, 0 if it is the first
+    after: i64,
+    change: DocumentChange,
+}
+
+impl WatchHub {
+    /// Start listening for changes in the background.
+    pub fn start(manager: DcManager) -> Self {
+        let (sender, _) = broadcast::channel(HUB_CAPACITY);
+        tokio::spawn(run(manager, sender.clone()));
+        Self { sender }
+    }
+
+    /// Stream the changes matching a query to the sender until it is closed, first replaying
+    /// the changes after `after_sequence` if it is set.
+    pub fn watch(
+        &self,
+        manager: DcManager,
+        query: DocumentQuery,
+        after_sequence: i64,
+        sender: mpsc::Sender<Result<DocumentChange, abi::Error>>,
+    ) {
+        // subscribe before replaying, so nothing committed meanwhile is missed
+        let receiver = self.sender.subscribe();
+        let watcher = Watcher {
+            manager,
+            query,
+            sender,
+            cursor: after_sequence,
+            synced: true,
+        };
+        tokio::spawn(watcher.run(receiver, after_sequence > 0));
+    }
+}
+
+async fn run(manager: DcManager, sender: broadcast::Sender<Arc<Relayed>>) {
+    let mut last = None;
+    loop {
+        if let Err(e) = relay(&manager, &sender, &mut last).await {
+            warn!("Watch listener error: {:?}", e);
+        }
+        tokio::time::sleep(RECONNECT_DELAY).await;
+    }
+}
+
+/// broadcast the changes listed after `last` whenever a change is notified on one connection
+async fn relay(
+    manager: &DcManager,
+    sender: &broadcast::Sender<Arc<Relayed>>,
+    last: &mut Option<i64>,
+) -> Result<(), abi::Error> {
+    let mut listener = manager.listen_changes().await?;
+    info!("Listening for document changes");
+    let mut checked = Instant::now();
+    loop {
+        let mut after = match *last {
+            Some(after) => after,
+            None => manager.latest_sequence().await?,
+        };
+        *last = Some(after);
+        loop {
+            let changes = manager.list_changes(after, REPLAY_PAGE_SIZE).await?;
+            if changes.is_empty() {
+                break;
+            }
+            for change in changes {
+                let relayed = Relayed { after, change };
+                after = relayed.change.sequence;
+                *last = Some(after);
+                // nobody watching is not an error
+                let _ = sender.send(Arc::new(relayed));
+            }
+        }
+
+        if checked.elapsed() >= HELD_BACK_WARN {
+            checked = Instant::now();
+            report_held_back(manager).await;
+        }
+
+        // a change held back by an older transaction is listed once that one ends, which
+        // notifies nothing if it changed no document
+        match tokio::time::timeout(HELD_BACK_POLL, listener.recv()).await {
+            Ok(Ok(Some(_))) | Err(_) => {}
+            Ok(Ok(None)) => warn!("Lost the connection listening for changes, catching up"),
+            Ok(Err(e)) => return Err(e),
+        }
+    }
+}
+
+/// warn about changes waiting longer than `HELD_BACK_WARN` for an older transaction, which may run
+/// in any database of the postgres server, so operators can find and end it
+async fn report_held_back(manager: &DcManager) {
+    let held = match manager.held_back().await {
+        Ok(Some(held)) => held,
+        Ok(None) => return,
+        Err(e) => {
+            warn!("Failed to look for held back changes: {:?}", e);
+            return;
+        }
+    };
+    if (Utc::now() - held.since).to_std().unwrap_or_default() < HELD_BACK_WARN {
+        return;
+    }
+    match held.blocking {
+        Some(t) => warn!(
+            "{} changes held back since {} by the transaction of pid {} in database {:?}, {:?} since {:?}, running {:?}",
+            held.changes, held.since, t.pid, t.database, t.state, t.started_at, t.query
+        ),
+        None => warn!(
+            "{} changes held back since {} by a transaction without a session, look for prepared transactions",
+            held.changes, held.since
+        ),
+    }
+}
+
+/// one watch call, forwarding the changes matching its query
+struct Watcher {
+    manager: DcManager,
+    query: DocumentQuery,
+    sender: mpsc::Sender<Result<DocumentChange, abi::Error>>,
+    /// the sequence of the latest change looked at
+    cursor: i64,
+    /// whether the hub has caught up with the cursor, until then what it relays was replayed
+    synced: bool,
+}
+
+impl Watcher {
+    async fn run(mut self, receiver: broadcast::Receiver<Arc<Relayed>>, replay: bool) {
+        let started = if replay {
+            self.replay().await
+        } else {
+            self.start().await
+        };
+        if started.is_ok() {
+            self.follow(receiver).await;
+        }
+    }
+
+    /// look at changes from the latest one on, so a watcher falling behind knows where to catch up
+    /// from, Err once the caller is gone
+    async fn start(&mut self) -> Result<(), ()> {
+        match self.manager.latest_sequence().await {
+            Ok(sequence) => {
+                self.cursor = sequence;
+                Ok(())
+            }
+            Err(e) => {
+                let _ = self.sender.send(Err(e)).await;
+                Err(())
+            }
+        }
+    }
+
+    /// forward what the hub relays until it or the caller is gone
+    async fn follow(mut self, mut receiver: broadcast::Receiver<Arc<Relayed>>) {
+        loop {
+            let relayed = match receiver.recv().await {
+                Ok(relayed) => relayed,
+                Err(RecvError::Lagged(n)) => {
+                    warn!("Watcher fell {} changes behind, catching up", n);
+                    if self.replay().await.is_err() {
+                        return;
+                    }
+                    continue;
+                }
+                Err(RecvError::Closed) => return,
+            };
+            if !self.synced {
+                // the hub relays changes in the order they were replayed, the change after the
+                // cursor is the first not sent yet
+                if relayed.after != self.cursor {
+                    continue;
+                }
+                self.synced = true;
+            }
+            self.cursor = relayed.change.sequence;
+            if self.forward(&relayed.change).await.is_err() {
+                return;
+            }
+        }
+    }
+
+    /// send the matching changes after the cursor from the database, Err once the caller is gone
+    async fn replay(&mut self) -> Result<(), ()> {
+        self.synced = false;
+        loop {
+            let changes = match self
+                .manager
+                .list_changes(self.cursor, REPLAY_PAGE_SIZE)
+                .await
+            {
+                Ok(changes) if changes.is_empty() => return Ok(()),
+                Ok(changes) => changes,
+                Err(e) => {
+                    let _ = self.sender.send(Err(e)).await;
+                    return Err(());
+                }
+            };
+            for change in changes {
+                self.cursor = change.sequence;
+                self.forward(&change).await?;
+            }
+        }
+    }
+
+    /// send the change if it matches the query, Err once the caller is gone or the query is invalid
+    async fn forward(&self, change: &DocumentChange) -> Result<(), ()> {
+        let Some(document) = &change.document else {
+            return Ok(());
+        };
+        match matches(&self.query, document) {
+            Ok(false) => Ok(()),
+            Ok(true) => self.sender.send(Ok(change.clone())).await.map_err(|_| ()),
+            Err(e) => {
+                let _ = self.sender.send(Err(e)).await;
+                Err(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use document_collection::{
+        testing::{new_manager, USER_ID},
+        Dc, DEFAULT_COLLECTION,
+    };
+    use sqlx::PgPool;
+
+    use super::*;
+
+    async fn create_document(manager: &DcManager) -> abi::Document {
+        manager
+            .create(
+                DEFAULT_COLLECTION.to_string(),
+                USER_ID.to_string(),
+                Default::default(),
+                vec![],
+                String::new(),
+            )
+            .await
+            .unwrap()
+    }
+
+    /// changes are listed once every older transaction of the server has ended, also those of
+    /// tests running alongside
+    async fn list_at_least(manager: &DcManager, after: i64, n: usize) -> Vec<DocumentChange> {
+        for _ in 0..50 {
+            let changes = manager.list_changes(after, 10).await.unwrap();
+            if changes.len() >= n {
+                return changes;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+        panic!("expected at least {} changes", n);
+    }
+
+    fn watcher(
+        manager: &DcManager,
+        cursor: i64,
+    ) -> (Watcher, mpsc::Receiver<Result<DocumentChange, abi::Error>>) {
+        let (sender, receiver) = mpsc::channel(16);
+        let watcher = Watcher {
+            manager: manager.clone(),
+            query: DocumentQuery::default(),
+            sender,
+            cursor,
+            synced: true,
+        };
+        (watcher, receiver)
+    }
+
+    fn relay(sender: &broadcast::Sender<Arc<Relayed>>, after: i64, change: &DocumentChange) {
+        let relayed = Relayed {
+            after,
+            change: change.clone(),
+        };
+        assert!(sender.send(Arc::new(relayed)).is_ok());
+    }
+
+    /// the sequences of every change sent until the watcher is done
+    async fn sequences(
+        mut receiver: mpsc::Receiver<Result<DocumentChange, abi::Error>>,
+    ) -> Vec<i64> {
+        let mut sequences = vec![];
+        loop {
+            match tokio::time::timeout(Duration::from_secs(10), receiver.recv()).await {
+                Ok(Some(change)) => sequences.push(change.unwrap().sequence),
+                Ok(None) => return sequences,
+                Err(_) => panic!("watcher did not finish, sent {:?}", sequences),
+            }
+        }
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn watch_should_resume_after_a_change_and_go_on_with_new_ones(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        for _ in 0..3 {
+            create_document(&manager).await;
+        }
+        let changes = list_at_least(&manager, 0, 3).await;
+
+        let hub = WatchHub::start(manager.clone());
+        let (sender, mut receiver) = mpsc::channel(16);
+        hub.watch(
+            manager.clone(),
+            DocumentQuery::default(),
+            changes[0].sequence,
+            sender,
+        );
+        let mut sent = vec![];
+        for _ in 0..2 {
+            sent.push(receiver.recv().await.unwrap().unwrap());
+        }
+        assert_eq!(sent, changes[1..]);
+
+        let document = create_document(&manager).await;
+        let change = tokio::time::timeout(Duration::from_secs(10), receiver.recv())
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        assert_eq!(change.document.unwrap().id, document.id);
+        assert!(change.sequence > changes[2].sequence);
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn changes_relayed_during_a_replay_should_be_sent_once(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        for _ in 0..3 {
+            create_document(&manager).await;
+        }
+        let changes = list_at_least(&manager, 0, 3).await;
+        // committed once the replay read the database
+        let mut later = changes[2].clone();
+        later.sequence += 1;
+
+        let (hub, receiver) = broadcast::channel(HUB_CAPACITY);
+        relay(&hub, changes[0].sequence, &changes[1]);
+        relay(&hub, changes[1].sequence, &changes[2]);
+        relay(&hub, changes[2].sequence, &later);
+        drop(hub);
+        let (watcher, sent) = watcher(&manager, changes[0].sequence);
+        tokio::spawn(watcher.run(receiver, true));
+
+        let expected = [changes[1].sequence, changes[2].sequence, later.sequence];
+        assert_eq!(sequences(sent).await, expected);
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn a_lagging_watcher_should_catch_up_from_the_database(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        for _ in 0..5 {
+            create_document(&manager).await;
+        }
+        let changes = list_at_least(&manager, 0, 5).await;
+        let mut later = changes[4].clone();
+        later.sequence += 1;
+
+        // two changes fit, so the watcher misses the first two relayed
+        let (hub, receiver) = broadcast::channel(2);
+        for pair in changes.windows(2) {
+            relay(&hub, pair[0].sequence, &pair[1]);
+        }
+        let (watcher, sent) = watcher(&manager, changes[0].sequence);
+        let following = tokio::spawn(watcher.follow(receiver));
+        // wait for the replay to take the relayed changes left
+        while !hub.is_empty() {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        relay(&hub, changes[4].sequence, &later);
+        drop(hub);
+        following.await.unwrap();
+
+        let mut expected: Vec<i64> = changes[1..].iter().map(|c| c.sequence).collect();
+        expected.push(later.sequence);
+        assert_eq!(sequences(sent).await, expected);
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn a_watcher_without_a_sequence_should_catch_up_from_when_it_started(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        create_document(&manager).await;
+        let started = list_at_least(&manager, 0, 1).await;
+        let (mut watcher, sent) = watcher(&manager, 0);
+        watcher.start().await.unwrap();
+        assert_eq!(watcher.cursor, started[0].sequence);
+
+        for _ in 0..2 {
+            create_document(&manager).await;
+        }
+        let changes = list_at_least(&manager, started[0].sequence, 2).await;
+        // one change fits, so the watcher misses the first one relayed
+        let (hub, receiver) = broadcast::channel(1);
+        relay(&hub, started[0].sequence, &changes[0]);
+        relay(&hub, changes[0].sequence, &changes[1]);
+        drop(hub);
+        tokio::spawn(watcher.follow(receiver));
+
+        let expected: Vec<i64> = changes.iter().map(|c| c.sequence).collect();
+        assert_eq!(sequences(sent).await, expected);
+    }
+}
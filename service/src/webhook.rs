@@ -0,0 +1,449 @@
+use std::{
+    net::{IpAddr, SocketAddr},
+    sync::Arc,
+    time::Duration,
+};
+
+use abi::WebhookConfig;
+use chrono::{DateTime, Utc};
+use document_collection::{DcManager, PendingDelivery, Webhooks};
+use futures::StreamExt;
+use hmac::{Hmac, Mac};
+use hyper::client::connect::dns::Name;
+use reqwest::{
+    dns::{Addrs, Resolve, Resolving},
+    header::CONTENT_TYPE,
+    redirect::Policy,
+    Client,
+};
+use sha2::Sha256;
+use tracing::{info, warn};
+use url::{Host, Url};
+
+/// changes turned into deliveries, and deliveries posted, at once
+const BATCH_SIZE: usize = 100;
+/// posts of a batch in flight at the same time
+const CONCURRENT_POSTS: usize = 10;
+
+/// periodically turn new changes into deliveries and post the deliveries that are due
+pub async fn run(manager: DcManager, config: WebhookConfig) {
+    let client = match client(&config) {
+        Ok(client) => client,
+        Err(e) => {
+            warn!("Webhooks disabled, failed to build http client: {:?}", e);
+            return;
+        }
+    };
+
+    let mut interval = tokio::time::interval(Duration::from_secs(config.poll_interval_secs));
+    loop {
+        interval.tick().await;
+        if let Err(e) = dispatch(&manager, &client, &config).await {
+            warn!("Webhook error: {:?}", e);
+        }
+    }
+}
+
+/// The client posting deliveries. It follows no redirects and, unless private hosts are allowed,
+/// connects to public addresses only.
+pub fn client(config: &WebhookConfig) -> reqwest::Result<Client> {
+    let builder = Client::builder()
+        .timeout(Duration::from_secs(config.timeout_secs))
+        .redirect(Policy::none());
+    if config.allow_private_hosts {
+        builder.build()
+    } else {
+        builder.dns_resolver(Arc::new(PublicResolver)).build()
+    }
+}
+
+/// Resolves host names to their public addresses only, so a webhook cannot reach into the network
+/// the server runs in.
+struct PublicResolver;
+
+impl Resolve for PublicResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        Box::pin(async move {
+            let addrs = tokio::net::lookup_host((name.as_str(), 0)).await?;
+            let public: Vec<SocketAddr> = addrs.filter(|addr| is_public(addr.ip())).collect();
+            if public.is_empty() {
+                return Err(format!("{} has no public address", name.as_str()).into());
+            }
+            Ok(Box::new(public.into_iter()) as Addrs)
+        })
+    }
+}
+
+/// check a webhook url does not name a private host, unless private hosts are allowed
+pub fn check_url(url: &str, config: &WebhookConfig) -> Result<(), abi::Error> {
+    if config.allow_private_hosts {
+        return Ok(());
+    }
+    let url = Url::parse(url).map_err(|e| abi::Error::InvalidWebhook(e.to_string()))?;
+    let private = match url.host() {
+        Some(Host::Domain(domain)) => domain == "localhost" || domain.ends_with(".localhost"),
+        Some(Host::Ipv4(ip)) => !is_public(ip.into()),
+        Some(Host::Ipv6(ip)) => !is_public(ip.into()),
+        None => true,
+    };
+    if private {
+        return Err(abi::Error::InvalidWebhook(
+            "the url must not point to a loopback, link-local or private address".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// addresses on the internet, not loopback, link-local, private or otherwise meant for local use
+fn is_public(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            let [a, b, ..] = ip.octets();
+            !(ip.is_loopback()
+                || ip.is_private()
+                || ip.is_link_local()
+                || ip.is_unspecified()
+                || ip.is_broadcast()
+                || ip.is_multicast()
+                || ip.is_documentation()
+                || a == 0
+                // shared address space of carrier-grade NAT
+                || (a == 100 && b & 0xc0 == 64))
+        }
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(ip) => is_public(ip.into()),
+            None => {
+                let first = ip.segments()[0];
+                !(ip.is_loopback()
+                    || ip.is_unspecified()
+                    || ip.is_multicast()
+                    // unique local and link-local
+                    || first & 0xfe00 == 0xfc00
+                    || first & 0xffc0 == 0xfe80)
+            }
+        },
+    }
+}
+
+/// create the deliveries of every new change, then post every due delivery once
+pub async fn dispatch(
+    manager: &DcManager,
+    client: &Client,
+    config: &WebhookConfig,
+) -> Result<(), abi::Error> {
+    while manager.enqueue_deliveries(BATCH_SIZE as i64).await? == BATCH_SIZE {}
+
+    // long enough for every post of a batch to time out
+    let lease = Duration::from_secs(config.timeout_secs * (BATCH_SIZE as u64 + 1));
+    loop {
+        let due = manager.claim_deliveries(BATCH_SIZE as i64, lease).await?;
+        let claimed = due.len();
+        let delivered: Vec<_> = futures::stream::iter(due)
+            .map(|delivery| deliver(manager, client, config, delivery))
+            .buffer_unordered(CONCURRENT_POSTS)
+            .collect()
+            .await;
+        delivered.into_iter().collect::<Result<(), _>>()?;
+        if claimed < BATCH_SIZE {
+            return Ok(());
+        }
+    }
+}
+
+async fn deliver(
+    manager: &DcManager,
+    client: &Client,
+    config: &WebhookConfig,
+    delivery: PendingDelivery,
+) -> Result<(), abi::Error> {
+    let body = delivery.payload.to_string();
+    let timestamp = Utc::now().timestamp();
+    // the host may have been allowed when the webhook was created
+    let response = match check_url(&delivery.url, config) {
+        Ok(()) => client
+            .post(&delivery.url)
+            .header(CONTENT_TYPE, "application/json")
+            .header("x-webhook-id", &delivery.id)
+            .header("x-webhook-timestamp", timestamp)
+            .header(
+                "x-webhook-signature",
+                signature(&delivery.secret, timestamp, body.as_bytes()),
+            )
+            .body(body)
+            .send()
+            .await
+            .map_err(|e| e.to_string()),
+        Err(e) => Err(e.to_string()),
+    };
+
+    let (status, error) = match response {
+        Ok(response) if response.status().is_success() => {
+            let status = response.status().as_u16().into();
+            return manager.complete_delivery(delivery.id, status).await;
+        }
+        Ok(response) => (
+            Some(response.status().as_u16().into()),
+            format!("unexpected status {}", response.status()),
+        ),
+        Err(e) => (None, e),
+    };
+
+    let retry_at = retry_at(config, delivery.attempts + 1, Utc::now());
+    if retry_at.is_none() {
+        info!(
+            "Webhook delivery {} failed {} times, moving it to the dead letters",
+            delivery.id,
+            delivery.attempts + 1
+        );
+    }
+    manager
+        .fail_delivery(delivery.id, status, error, retry_at)
+        .await
+}
+
+/// when to retry after the given number of failed attempts, None once every attempt is used up
+fn retry_at(config: &WebhookConfig, attempts: i32, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    if attempts >= config.max_attempts as i32 {
+        return None;
+    }
+    let factor = 1u64.checked_shl(attempts as u32 - 1).unwrap_or(u64::MAX);
+    let backoff = config
+        .backoff_secs
+        .saturating_mul(factor)
+        .min(config.max_backoff_secs);
+    Some(now + chrono::Duration::seconds(backoff as i64))
+}
+
+/// `sha256=` and the hex encoded HMAC-SHA256 of `{timestamp}.{body}`
+pub fn signature(secret: &str, timestamp: i64, body: &[u8]) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("hmac takes keys of any size");
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body);
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        convert::Infallible,
+        net::SocketAddr,
+        sync::{Arc, Mutex},
+    };
+
+    use abi::{ChangeType, DeliveryStatus, Webhook, WebhookDelivery};
+    use document_collection::{
+        testing::{new_manager, USER_ID},
+        Dc, DEFAULT_COLLECTION,
+    };
+    use hyper::{
+        service::{make_service_fn, service_fn},
+        Body, Response, Server,
+    };
+    use sqlx::PgPool;
+
+    use super::*;
+
+    const SECRET: &str = "s3cret";
+
+    /// a request the stand-in endpoint received: its signature and timestamp headers and its body
+    type Received = Arc<Mutex<Vec<(String, String, String)>>>;
+
+    /// serve an endpoint answering every post with the given status, recording what it received
+    fn stand_in(status: u16) -> (SocketAddr, Received) {
+        let received: Received = Default::default();
+        let recorded = received.clone();
+        let make_service = make_service_fn(move |_| {
+            let recorded = recorded.clone();
+            async move {
+                Ok::<_, Infallible>(service_fn(move |request: hyper::Request<Body>| {
+                    let recorded = recorded.clone();
+                    async move {
+                        let header =
+                            |name: &str| request.headers()[name].to_str().unwrap().to_string();
+                        let signature = header("x-webhook-signature");
+                        let timestamp = header("x-webhook-timestamp");
+                        let body = hyper::body::to_bytes(request.into_body()).await.unwrap();
+                        let body = String::from_utf8(body.to_vec()).unwrap();
+                        recorded.lock().unwrap().push((signature, timestamp, body));
+                        Ok::<_, Infallible>(
+                            Response::builder()
+                                .status(status)
+                                .body(Body::empty())
+                                .unwrap(),
+                        )
+                    }
+                }))
+            }
+        });
+        let server = Server::bind(&([127, 0, 0, 1], 0).into()).serve(make_service);
+        let addr = server.local_addr();
+        tokio::spawn(server);
+        (addr, received)
+    }
+
+    async fn create_webhook(manager: &DcManager, addr: SocketAddr) -> Webhook {
+        manager
+            .create_webhook(Webhook {
+                url: format!("http://{}/hook", addr),
+                event_types: vec![ChangeType::Created as i32],
+                secret: SECRET.to_string(),
+                ..Default::default()
+            })
+            .await
+            .unwrap()
+    }
+
+    /// the stand-in endpoint listens on the loopback address
+    fn local_config() -> WebhookConfig {
+        WebhookConfig {
+            allow_private_hosts: true,
+            ..Default::default()
+        }
+    }
+
+    async fn create_document(manager: &DcManager) -> abi::Document {
+        manager
+            .create(
+                DEFAULT_COLLECTION.to_string(),
+                USER_ID.to_string(),
+                Default::default(),
+                vec![],
+                String::new(),
+            )
+            .await
+            .unwrap()
+    }
+
+    /// dispatch until the condition holds, changes only become visible once every older
+    /// transaction of the server has ended, also those of tests running alongside
+    async fn dispatch_until(
+        manager: &DcManager,
+        config: &WebhookConfig,
+        webhook: &Webhook,
+        done: impl Fn(&[WebhookDelivery]) -> bool,
+    ) -> Vec<WebhookDelivery> {
+        let client = client(config).unwrap();
+        for _ in 0..50 {
+            dispatch(manager, &client, config).await.unwrap();
+            let deliveries = manager
+                .list_deliveries(webhook.id.clone(), 10)
+                .await
+                .unwrap();
+            if done(&deliveries) {
+                return deliveries;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+        panic!("deliveries never got there");
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn changes_should_be_posted_signed(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let (addr, received) = stand_in(200);
+        let webhook = create_webhook(&manager, addr).await;
+        let document = create_document(&manager).await;
+
+        let deliveries = dispatch_until(&manager, &local_config(), &webhook, |d| {
+            d.first()
+                .is_some_and(|d| d.status() == DeliveryStatus::Delivered)
+        })
+        .await;
+        assert_eq!(deliveries.len(), 1);
+        assert_eq!(deliveries[0].attempts, 1);
+        assert_eq!(deliveries[0].response_status, 200);
+        assert_eq!(deliveries[0].document_id, document.id);
+
+        let received = received.lock().unwrap();
+        let (signature, timestamp, body) = &received[0];
+        let timestamp: i64 = timestamp.parse().unwrap();
+        assert_eq!(
+            *signature,
+            super::signature(SECRET, timestamp, body.as_bytes())
+        );
+        let body: serde_json::Value = serde_json::from_str(body).unwrap();
+        assert_eq!(body["change_type"], "CREATED");
+        assert_eq!(body["document"]["id"], document.id.as_str());
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn failing_deliveries_should_end_in_the_dead_letters(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let (addr, received) = stand_in(500);
+        let webhook = create_webhook(&manager, addr).await;
+        create_document(&manager).await;
+
+        let config = WebhookConfig {
+            max_attempts: 2,
+            backoff_secs: 0,
+            ..local_config()
+        };
+        let deliveries = dispatch_until(&manager, &config, &webhook, |d| {
+            d.first()
+                .is_some_and(|d| d.status() == DeliveryStatus::Dead)
+        })
+        .await;
+        assert_eq!(deliveries.len(), 1);
+        assert_eq!(deliveries[0].attempts, 2);
+        assert_eq!(deliveries[0].response_status, 500);
+        assert!(!deliveries[0].last_error.is_empty());
+        assert_eq!(received.lock().unwrap().len(), 2);
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn private_hosts_should_not_be_posted_to(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let (addr, received) = stand_in(200);
+        let webhook = create_webhook(&manager, addr).await;
+        create_document(&manager).await;
+
+        let config = WebhookConfig {
+            max_attempts: 1,
+            ..Default::default()
+        };
+        let deliveries = dispatch_until(&manager, &config, &webhook, |d| {
+            d.first()
+                .is_some_and(|d| d.status() == DeliveryStatus::Dead)
+        })
+        .await;
+        assert!(deliveries[0].last_error.contains("private address"));
+        assert!(received.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn check_url_should_reject_private_hosts() {
+        let config = WebhookConfig::default();
+        for url in [
+            "http://localhost/hook",
+            "http://127.0.0.1:8080/hook",
+            "http://10.1.2.3/hook",
+            "http://169.254.169.254/latest/meta-data",
+            "http://[::1]/hook",
+            "http://[::ffff:192.168.0.1]/hook",
+            "http://[fd00::1]/hook",
+        ] {
+            assert!(check_url(url, &config).is_err(), "{}", url);
+        }
+        assert!(check_url("https://example.com/hook", &config).is_ok());
+        assert!(check_url("https://93.184.216.34/hook", &config).is_ok());
+        assert!(check_url("http://127.0.0.1/hook", &local_config()).is_ok());
+    }
+
+    #[test]
+    fn retries_should_back_off_exponentially() {
+        let config = WebhookConfig {
+            max_attempts: 4,
+            backoff_secs: 10,
+            max_backoff_secs: 30,
+            ..Default::default()
+        };
+        let now = Utc::now();
+        let wait = |attempts| retry_at(&config, attempts, now).map(|at| (at - now).num_seconds());
+        assert_eq!(wait(1), Some(10));
+        assert_eq!(wait(2), Some(20));
+        assert_eq!(wait(3), Some(30));
+        assert_eq!(wait(4), None);
+    }
+}
@@ -0,0 +1,226 @@
+use abi::{ApiKey, Permission, User};
+use async_trait::async_trait;
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+use sqlx::types::Uuid;
+
+use crate::{manager::parse_id, ApiKeys, UserManager, Users};
+
+/// prefix of every secret, so leaked keys are easy to recognize
+const SECRET_PREFIX: &str = "dck_";
+/// how many characters of a secret are kept in clear text
+const PREFIX_LEN: usize = 12;
+
+#[async_trait]
+impl ApiKeys for UserManager {
+    async fn issue_key(
+        &self,
+        user_id: String,
+        name: String,
+        permissions: Vec<Permission>,
+    ) -> Result<(ApiKey, String), abi::Error> {
+        if name.trim().is_empty() {
+            return Err(abi::Error::InvalidUser("api key name is empty".to_string()));
+        }
+        let user = self.get(user_id).await?;
+        let user_id = parse_id(&user.id)?;
+
+        let secret = new_secret();
+        let key = sqlx::query_as(
+            "INSERT INTO dc.api_keys (user_id, name, permissions, prefix, key_hash)
+            VALUES ($1, $2, $3, $4, $5) RETURNING *",
+        )
+        .bind(user_id)
+        .bind(name.trim())
+        .bind(permissions_to_db(permissions)?)
+        .bind(&secret[..PREFIX_LEN])
+        .bind(hash(&secret))
+        .fetch_one(&self.pool)
+        .await?;
+        Ok((key, secret))
+    }
+
+    async fn get_key(&self, id: String) -> Result<ApiKey, abi::Error> {
+        let id = parse_key_id(&id)?;
+        sqlx::query_as("SELECT * FROM dc.api_keys WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?
+            .ok_or(abi::Error::ApiKeyNotFound)
+    }
+
+    async fn list_keys(&self, user_id: String) -> Result<Vec<ApiKey>, abi::Error> {
+        let user_id = parse_id(&user_id)?;
+        let keys = sqlx::query_as(
+            "SELECT * FROM dc.api_keys WHERE user_id = $1 ORDER BY created_at DESC, id",
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(keys)
+    }
+
+    async fn rotate_key(&self, id: String) -> Result<(ApiKey, String), abi::Error> {
+        let id = parse_key_id(&id)?;
+        let secret = new_secret();
+        let key = sqlx::query_as(
+            "UPDATE dc.api_keys SET prefix = $1, key_hash = $2, last_used_at = NULL
+            WHERE id = $3 AND revoked_at IS NULL RETURNING *",
+        )
+        .bind(&secret[..PREFIX_LEN])
+        .bind(hash(&secret))
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(abi::Error::ApiKeyNotFound)?;
+        Ok((key, secret))
+    }
+
+    async fn revoke_key(&self, id: String) -> Result<ApiKey, abi::Error> {
+        let id = parse_key_id(&id)?;
+        sqlx::query_as(
+            "UPDATE dc.api_keys SET revoked_at = COALESCE(revoked_at, NOW()) WHERE id = $1 RETURNING *",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(abi::Error::ApiKeyNotFound)
+    }
+
+    async fn authenticate_key(&self, secret: &str) -> Result<(User, ApiKey), abi::Error> {
+        let invalid = || abi::Error::Unauthenticated("invalid api key".to_string());
+        let key: ApiKey = sqlx::query_as(
+            "UPDATE dc.api_keys SET last_used_at = NOW()
+            WHERE key_hash = $1 AND revoked_at IS NULL RETURNING *",
+        )
+        .bind(hash(secret))
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or_else(invalid)?;
+
+        let user = self.get(key.user_id.clone()).await?;
+        if !user.active {
+            return Err(invalid());
+        }
+        Ok((user, key))
+    }
+}
+
+fn parse_key_id(id: &str) -> Result<Uuid, abi::Error> {
+    Uuid::parse_str(id).map_err(|_| abi::Error::ApiKeyNotFound)
+}
+
+fn new_secret() -> String {
+    let mut bytes = [0u8; 32];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    format!("{}{}", SECRET_PREFIX, URL_SAFE_NO_PAD.encode(bytes))
+}
+
+/// secrets are random, a plain sha256 is enough to keep them out of the database
+fn hash(secret: &str) -> String {
+    format!("{:x}", Sha256::digest(secret.as_bytes()))
+}
+
+fn permissions_to_db(permissions: Vec<Permission>) -> Result<Vec<&'static str>, abi::Error> {
+    if permissions.is_empty() || permissions.contains(&Permission::Unspecified) {
+        return Err(abi::Error::InvalidUser(
+            "api keys need at least one valid permission".to_string(),
+        ));
+    }
+    let mut permissions: Vec<_> = permissions.into_iter().map(Permission::to_db).collect();
+    permissions.sort_unstable();
+    permissions.dedup();
+    Ok(permissions)
+}
+
+#[cfg(test)]
+mod tests {
+    use abi::Role;
+    use sqlx::PgPool;
+
+    use super::*;
+    use crate::manager::tests::{new_manager, STORE_ID};
+
+    async fn user(manager: &UserManager) -> User {
+        manager
+            .create(User {
+                display_name: "Till 1".to_string(),
+                email: "till1@example.com".to_string(),
+                role: Role::Store as i32,
+                store_id: STORE_ID.to_string(),
+                ..Default::default()
+            })
+            .await
+            .unwrap()
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn issued_key_should_authenticate_and_record_use(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let user = user(&manager).await;
+        let (key, secret) = manager
+            .issue_key(user.id.clone(), "till".to_string(), vec![Permission::Write])
+            .await
+            .unwrap();
+
+        assert!(secret.starts_with(&key.prefix));
+        assert!(key.last_used_at.is_none());
+
+        let (found_user, found_key) = manager.authenticate_key(&secret).await.unwrap();
+        assert_eq!(found_user.id, user.id);
+        assert_eq!(found_key.permissions, vec![Permission::Write as i32]);
+        assert!(found_key.last_used_at.is_some());
+        assert_eq!(manager.list_keys(user.id).await.unwrap().len(), 1);
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn rotated_key_should_only_accept_new_secret(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let user = user(&manager).await;
+        let (key, old) = manager
+            .issue_key(user.id, "till".to_string(), vec![Permission::Read])
+            .await
+            .unwrap();
+
+        let (rotated, new) = manager.rotate_key(key.id.clone()).await.unwrap();
+        assert_eq!(rotated.id, key.id);
+        assert!(manager.authenticate_key(&old).await.is_err());
+        assert!(manager.authenticate_key(&new).await.is_ok());
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn revoked_key_should_be_rejected(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let user = user(&manager).await;
+        let (key, secret) = manager
+            .issue_key(user.id, "till".to_string(), vec![Permission::Read])
+            .await
+            .unwrap();
+
+        let revoked = manager.revoke_key(key.id.clone()).await.unwrap();
+        assert!(revoked.revoked_at.is_some());
+        assert!(matches!(
+            manager.authenticate_key(&secret).await,
+            Err(abi::Error::Unauthenticated(_))
+        ));
+        assert!(matches!(
+            manager.rotate_key(key.id).await,
+            Err(abi::Error::ApiKeyNotFound)
+        ));
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn key_of_inactive_user_should_be_rejected(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let mut user = user(&manager).await;
+        let (_, secret) = manager
+            .issue_key(user.id.clone(), "till".to_string(), vec![Permission::Read])
+            .await
+            .unwrap();
+
+        user.active = false;
+        manager.update(user).await.unwrap();
+        assert!(manager.authenticate_key(&secret).await.is_err());
+    }
+}
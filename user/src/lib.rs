@@ -1,14 +1,61 @@
-pub fn add(left: usize, right: usize) -> usize {
-    left + right
+mod api_key;
+mod manager;
+mod organization;
+
+use abi::{ApiKey, Organization, Permission, Store, User};
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+#[derive(Debug, Clone)]
+pub struct UserManager {
+    pool: PgPool,
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+#[async_trait]
+pub trait Users {
+    /// Register a new, active user.
+    async fn create(&self, user: User) -> Result<User, abi::Error>;
+    /// Get a user by id.
+    async fn get(&self, id: String) -> Result<User, abi::Error>;
+    /// Get the users with the given ids at once, each looked up like [`Users::get`], in the
+    /// order of the ids.
+    async fn get_many(&self, ids: Vec<String>)
+        -> Result<Vec<Result<User, abi::Error>>, abi::Error>;
+    /// Replace the profile, role, store and active flag of an existing user.
+    async fn update(&self, user: User) -> Result<User, abi::Error>;
+    /// List users ordered by display name, optionally only the active ones or those of one organization.
+    async fn list(&self, active_only: bool, org_id: String) -> Result<Vec<User>, abi::Error>;
+}
+
+#[async_trait]
+pub trait ApiKeys {
+    /// Issue a new api key for a user, return it together with its secret.
+    async fn issue_key(
+        &self,
+        user_id: String,
+        name: String,
+        permissions: Vec<Permission>,
+    ) -> Result<(ApiKey, String), abi::Error>;
+    /// Get an api key by id.
+    async fn get_key(&self, id: String) -> Result<ApiKey, abi::Error>;
+    /// List the api keys of a user, newest first, revoked keys included.
+    async fn list_keys(&self, user_id: String) -> Result<Vec<ApiKey>, abi::Error>;
+    /// Replace the secret of an api key that is not revoked, return the new secret.
+    async fn rotate_key(&self, id: String) -> Result<(ApiKey, String), abi::Error>;
+    /// Revoke an api key, its secret is no longer accepted.
+    async fn revoke_key(&self, id: String) -> Result<ApiKey, abi::Error>;
+    /// Find the active user and the api key a secret belongs to, and record that the key was used.
+    async fn authenticate_key(&self, secret: &str) -> Result<(User, ApiKey), abi::Error>;
+}
 
-    #[test]
-    fn it_works() {
-        let result = add(2, 2);
-        assert_eq!(result, 4);
-    }
+#[async_trait]
+pub trait Organizations {
+    /// Create a new organization.
+    async fn create_organization(&self, name: String) -> Result<Organization, abi::Error>;
+    /// List every organization ordered by name.
+    async fn list_organizations(&self) -> Result<Vec<Organization>, abi::Error>;
+    /// Create a new store in an organization.
+    async fn create_store(&self, org_id: String, name: String) -> Result<Store, abi::Error>;
+    /// List the stores of an organization ordered by name.
+    async fn list_stores(&self, org_id: String) -> Result<Vec<Store>, abi::Error>;
 }
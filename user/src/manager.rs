@@ -0,0 +1,360 @@
+use std::collections::HashMap;
+
+use abi::{DbConfig, Role, User, Validator};
+use async_trait::async_trait;
+use sqlx::{postgres::PgPoolOptions, types::Uuid, PgPool};
+
+use crate::{UserManager, Users};
+
+#[async_trait]
+impl Users for UserManager {
+    async fn create(&self, user: User) -> Result<User, abi::Error> {
+        user.validate()?;
+        let org_id = self.resolve_org(&user).await?;
+        let user = sqlx::query_as(
+            "INSERT INTO dc.users (display_name, email, role, store_id, org_id) VALUES ($1, $2, $3, $4, $5) RETURNING *",
+        )
+        .bind(user.display_name.trim())
+        .bind(&user.email)
+        .bind(role(&user))
+        .bind(store_id(&user)?)
+        .bind(org_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| email_error(e, &user.email))?;
+        Ok(user)
+    }
+
+    async fn get(&self, id: String) -> Result<User, abi::Error> {
+        let id = parse_id(&id)?;
+        sqlx::query_as("SELECT * FROM dc.users WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?
+            .ok_or(abi::Error::UserNotFound)
+    }
+
+    async fn get_many(
+        &self,
+        ids: Vec<String>,
+    ) -> Result<Vec<Result<User, abi::Error>>, abi::Error> {
+        let parsed: Vec<_> = ids.iter().map(|id| parse_id(id)).collect();
+        let valid: Vec<Uuid> = parsed.iter().flatten().copied().collect();
+        let users: Vec<User> = sqlx::query_as("SELECT * FROM dc.users WHERE id = ANY($1)")
+            .bind(valid)
+            .fetch_all(&self.pool)
+            .await?;
+        let users: HashMap<String, User> = users.into_iter().map(|u| (u.id.clone(), u)).collect();
+        Ok(parsed
+            .into_iter()
+            .map(|id| {
+                users
+                    .get(&id?.to_string())
+                    .cloned()
+                    .ok_or(abi::Error::UserNotFound)
+            })
+            .collect())
+    }
+
+    async fn update(&self, user: User) -> Result<User, abi::Error> {
+        user.validate()?;
+        let id = parse_id(&user.id)?;
+        let org_id = self.resolve_org(&user).await?;
+        sqlx::query_as(
+            "UPDATE dc.users SET display_name = $1, email = $2, role = $3, store_id = $4, org_id = $5, active = $6
+            WHERE id = $7 RETURNING *",
+        )
+        .bind(user.display_name.trim())
+        .bind(&user.email)
+        .bind(role(&user))
+        .bind(store_id(&user)?)
+        .bind(org_id)
+        .bind(user.active)
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| email_error(e, &user.email))?
+        .ok_or(abi::Error::UserNotFound)
+    }
+
+    async fn list(&self, active_only: bool, org_id: String) -> Result<Vec<User>, abi::Error> {
+        let org_id = parse_org_id(&org_id)?;
+        let users = sqlx::query_as(
+            "SELECT * FROM dc.users WHERE (active OR NOT $1) AND ($2::UUID IS NULL OR org_id = $2)
+            ORDER BY display_name, id",
+        )
+        .bind(active_only)
+        .bind(org_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(users)
+    }
+}
+
+pub(crate) fn parse_id(id: &str) -> Result<Uuid, abi::Error> {
+    Uuid::parse_str(id).map_err(|_| abi::Error::InvalidUserId(id.to_string()))
+}
+
+/// parse an optional organization id, empty means none
+pub(crate) fn parse_org_id(org_id: &str) -> Result<Option<Uuid>, abi::Error> {
+    if org_id.is_empty() {
+        return Ok(None);
+    }
+    Uuid::parse_str(org_id)
+        .map(Some)
+        .map_err(|_| abi::Error::OrganizationNotFound)
+}
+
+fn role(user: &User) -> &'static str {
+    Role::try_from(user.role).unwrap_or_default().to_db()
+}
+
+fn store_id(user: &User) -> Result<Option<Uuid>, abi::Error> {
+    if user.store_id.is_empty() {
+        return Ok(None);
+    }
+    Uuid::parse_str(&user.store_id)
+        .map(Some)
+        .map_err(|_| abi::Error::InvalidUser(format!("invalid store id: {}", user.store_id)))
+}
+
+/// turn a violation of the unique email constraint into a readable error
+fn email_error(e: sqlx::Error, email: &str) -> abi::Error {
+    match &e {
+        sqlx::Error::Database(db) if db.constraint() == Some("users_email_key") => {
+            abi::Error::EmailTaken(email.to_string())
+        }
+        _ => e.into(),
+    }
+}
+
+impl UserManager {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// the organization of a user: that of the store for store users, otherwise the one given,
+    /// only admins, the system admins, may have none
+    async fn resolve_org(&self, user: &User) -> Result<Option<Uuid>, abi::Error> {
+        let org_id = parse_org_id(&user.org_id)?;
+        let store_org: Option<Uuid> = match store_id(user)? {
+            Some(store_id) => Some(
+                sqlx::query_scalar("SELECT org_id FROM dc.stores WHERE id = $1")
+                    .bind(store_id)
+                    .fetch_optional(&self.pool)
+                    .await?
+                    .ok_or(abi::Error::OrganizationNotFound)?,
+            ),
+            None => None,
+        };
+
+        match (store_org, org_id) {
+            (Some(store_org), Some(org_id)) if store_org != org_id => Err(abi::Error::InvalidUser(
+                "store belongs to another organization".to_string(),
+            )),
+            (Some(store_org), _) => Ok(Some(store_org)),
+            (None, Some(org_id)) => {
+                let exists: Option<Uuid> =
+                    sqlx::query_scalar("SELECT id FROM dc.organizations WHERE id = $1")
+                        .bind(org_id)
+                        .fetch_optional(&self.pool)
+                        .await?;
+                exists.ok_or(abi::Error::OrganizationNotFound).map(Some)
+            }
+            (None, None) if user.role == Role::Admin as i32 => Ok(None),
+            (None, None) => Err(abi::Error::InvalidUser(
+                "only admins may be without an organization".to_string(),
+            )),
+        }
+    }
+
+    pub async fn from_config(config: &DbConfig) -> Result<Self, abi::Error> {
+        let url = config.url();
+        let pool = PgPoolOptions::default()
+            .max_connections(config.max_connections)
+            .connect(&url)
+            .await?;
+        Ok(Self::new(pool))
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use sqlx::PgPool;
+
+    use super::*;
+
+    pub(crate) const ORG_ID: &str = "0d6c2c8a-4f3e-4b9a-9d51-7e2f1c3b5a10";
+    pub(crate) const STORE_ID: &str = "5c1f0c4e-9c55-4c1e-9a3b-1f9f2d8e6a01";
+
+    /// a manager whose pool already knows the test organization and store
+    pub(crate) async fn new_manager(pool: PgPool) -> UserManager {
+        sqlx::query("INSERT INTO dc.organizations (id, name) VALUES ($1, 'Test')")
+            .bind(Uuid::parse_str(ORG_ID).unwrap())
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO dc.stores (id, org_id, name) VALUES ($1, $2, 'Test')")
+            .bind(Uuid::parse_str(STORE_ID).unwrap())
+            .bind(Uuid::parse_str(ORG_ID).unwrap())
+            .execute(&pool)
+            .await
+            .unwrap();
+        UserManager::new(pool)
+    }
+
+    fn store_user(name: &str, email: &str) -> User {
+        User {
+            display_name: name.to_string(),
+            email: email.to_string(),
+            role: Role::Store as i32,
+            store_id: STORE_ID.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn get_many_should_keep_the_order_of_the_ids(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let alice = manager
+            .create(store_user("Alice", "alice@example.com"))
+            .await
+            .unwrap();
+        let bob = manager
+            .create(store_user("Bob", "bob@example.com"))
+            .await
+            .unwrap();
+
+        let ids = vec![
+            bob.id.to_uppercase(),
+            "not-a-uuid".to_string(),
+            alice.id.clone(),
+            Uuid::nil().to_string(),
+        ];
+        let users = manager.get_many(ids).await.unwrap();
+        assert_eq!(users[0].as_ref().unwrap(), &bob);
+        assert!(matches!(users[1], Err(abi::Error::InvalidUserId(_))));
+        assert_eq!(users[2].as_ref().unwrap(), &alice);
+        assert!(matches!(users[3], Err(abi::Error::UserNotFound)));
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn create_and_get_user_should_work(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let user = manager
+            .create(store_user("Alice", "alice@example.com"))
+            .await
+            .unwrap();
+
+        assert!(user.active);
+        assert_eq!(user.role, Role::Store as i32);
+        assert_eq!(user.store_id, STORE_ID);
+        assert_eq!(user.org_id, ORG_ID);
+
+        let fetched = manager.get(user.id.clone()).await.unwrap();
+        assert_eq!(fetched, user);
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn create_user_with_taken_email_should_fail(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        manager
+            .create(store_user("Alice", "alice@example.com"))
+            .await
+            .unwrap();
+
+        let err = manager
+            .create(store_user("Alice Again", "alice@example.com"))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, abi::Error::EmailTaken(_)));
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn create_invalid_user_should_fail(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let mut user = store_user("Alice", "alice@example.com");
+        user.store_id = String::new();
+
+        let err = manager.create(user).await.unwrap_err();
+        assert!(matches!(err, abi::Error::InvalidUser(_)));
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn only_admins_should_be_without_an_organization(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let auditor = User {
+            display_name: "Audrey".to_string(),
+            email: "audrey@example.com".to_string(),
+            role: Role::Auditor as i32,
+            ..Default::default()
+        };
+        let err = manager.create(auditor.clone()).await.unwrap_err();
+        assert!(matches!(err, abi::Error::InvalidUser(_)));
+
+        let admin = User {
+            role: Role::Admin as i32,
+            ..auditor
+        };
+        let admin = manager.create(admin).await.unwrap();
+        assert!(admin.org_id.is_empty());
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn update_user_should_work(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let mut user = manager
+            .create(store_user("Alice", "alice@example.com"))
+            .await
+            .unwrap();
+
+        user.role = Role::Admin as i32;
+        user.store_id = String::new();
+        user.active = false;
+        let updated = manager.update(user).await.unwrap();
+
+        assert_eq!(updated.role, Role::Admin as i32);
+        assert_eq!(updated.store_id, "");
+        assert!(!updated.active);
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn update_unknown_user_should_fail(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        let mut user = store_user("Alice", "alice@example.com");
+        user.id = STORE_ID.to_string();
+
+        let err = manager.update(user).await.unwrap_err();
+        assert!(matches!(err, abi::Error::UserNotFound));
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn list_users_should_skip_inactive_when_asked(pool: PgPool) {
+        let manager = new_manager(pool).await;
+        manager
+            .create(store_user("Alice", "alice@example.com"))
+            .await
+            .unwrap();
+        let mut bob = manager
+            .create(store_user("Bob", "bob@example.com"))
+            .await
+            .unwrap();
+        bob.active = false;
+        manager.update(bob).await.unwrap();
+
+        assert_eq!(manager.list(false, String::new()).await.unwrap().len(), 2);
+        let active = manager.list(true, ORG_ID.to_string()).await.unwrap();
+        assert_eq!(active.len(), 1);
+        assert_eq!(active[0].display_name, "Alice");
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn store_of_another_organization_should_fail(pool: PgPool) {
+        let manager = new_manager(pool.clone()).await;
+        let mut user = store_user("Alice", "alice@example.com");
+        user.org_id = "3f0c6a51-2b7e-4c1d-8e8f-6d1b9a2c4e70".to_string();
+
+        let err = manager.create(user).await.unwrap_err();
+        assert!(matches!(err, abi::Error::InvalidUser(_)));
+    }
+}
@@ -0,0 +1,161 @@
+use abi::{Organization, Store};
+use async_trait::async_trait;
+
+use crate::{manager::parse_org_id, Organizations, UserManager};
+
+#[async_trait]
+impl Organizations for UserManager {
+    async fn create_organization(&self, name: String) -> Result<Organization, abi::Error> {
+        let name = checked_name(&name)?;
+        let organization =
+            sqlx::query_as("INSERT INTO dc.organizations (name) VALUES ($1) RETURNING *")
+                .bind(name)
+                .fetch_one(&self.pool)
+                .await?;
+        Ok(organization)
+    }
+
+    async fn list_organizations(&self) -> Result<Vec<Organization>, abi::Error> {
+        let organizations = sqlx::query_as("SELECT * FROM dc.organizations ORDER BY name, id")
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(organizations)
+    }
+
+    async fn create_store(&self, org_id: String, name: String) -> Result<Store, abi::Error> {
+        let name = checked_name(&name)?;
+        let org_id = parse_org_id(&org_id)?.ok_or(abi::Error::OrganizationNotFound)?;
+        sqlx::query_as(
+            "INSERT INTO dc.stores (org_id, name) SELECT id, $2 FROM dc.organizations WHERE id = $1 RETURNING *",
+        )
+        .bind(org_id)
+        .bind(name)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(abi::Error::OrganizationNotFound)
+    }
+
+    async fn list_stores(&self, org_id: String) -> Result<Vec<Store>, abi::Error> {
+        let org_id = parse_org_id(&org_id)?.ok_or(abi::Error::OrganizationNotFound)?;
+        let stores = sqlx::query_as("SELECT * FROM dc.stores WHERE org_id = $1 ORDER BY name, id")
+            .bind(org_id)
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(stores)
+    }
+}
+
+fn checked_name(name: &str) -> Result<&str, abi::Error> {
+    match name.trim() {
+        "" => Err(abi::Error::InvalidUser("name is empty".to_string())),
+        name => Ok(name),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::{migrate::Migrator, types::Uuid, PgPool};
+
+    use super::*;
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn stores_should_belong_to_their_organization(pool: PgPool) {
+        let manager = UserManager::new(pool);
+        let north = manager
+            .create_organization("North".to_string())
+            .await
+            .unwrap();
+        let south = manager
+            .create_organization("South".to_string())
+            .await
+            .unwrap();
+        manager
+            .create_store(north.id.clone(), "Harbour".to_string())
+            .await
+            .unwrap();
+
+        let stores = manager.list_stores(north.id).await.unwrap();
+        assert_eq!(stores.len(), 1);
+        assert_eq!(stores[0].name, "Harbour");
+        assert!(manager.list_stores(south.id).await.unwrap().is_empty());
+        assert_eq!(manager.list_organizations().await.unwrap().len(), 2);
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn store_of_unknown_organization_should_fail(pool: PgPool) {
+        let manager = UserManager::new(pool);
+        let err = manager
+            .create_store(
+                "5c1f0c4e-9c55-4c1e-9a3b-1f9f2d8e6a01".to_string(),
+                "Harbour".to_string(),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, abi::Error::OrganizationNotFound));
+    }
+
+    /// the migration adding organizations and stores
+    const ORGS_STORES: i64 = 20231202090000;
+
+    #[sqlx::test(migrations = false)]
+    async fn migration_should_move_existing_rows_into_an_organization(pool: PgPool) {
+        let migrator = sqlx::migrate!("../migrations");
+        let before = Migrator {
+            migrations: migrator
+                .migrations
+                .iter()
+                .filter(|m| m.version < ORGS_STORES)
+                .cloned()
+                .collect(),
+            ignore_missing: false,
+            locking: true,
+        };
+        before.run(&pool).await.unwrap();
+
+        let store_id = Uuid::new_v4();
+        let user_id: Uuid = sqlx::query_scalar(
+            "INSERT INTO dc.users (display_name, email, role, store_id)
+            VALUES ('Clerk', 'clerk@example.com', 'store', $1) RETURNING id",
+        )
+        .bind(store_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        sqlx::query("INSERT INTO dc.documents (user_id, data) VALUES ($1, '{}')")
+            .bind(user_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        migrator.run(&pool).await.unwrap();
+
+        let manager = UserManager::new(pool.clone());
+        let organizations = manager.list_organizations().await.unwrap();
+        assert_eq!(organizations.len(), 1);
+        let stores = manager
+            .list_stores(organizations[0].id.clone())
+            .await
+            .unwrap();
+        assert_eq!(stores.len(), 1);
+        assert_eq!(stores[0].id, store_id.to_string());
+
+        let org_id = Uuid::parse_str(&organizations[0].id).unwrap();
+        let users: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM dc.users WHERE org_id = $1")
+            .bind(org_id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(users, 1);
+        let documents: Vec<(Option<Uuid>, Option<Uuid>, i64)> =
+            sqlx::query_as("SELECT org_id, store_id, version FROM dc.documents")
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+        assert_eq!(documents, vec![(Some(org_id), Some(store_id), 1)]);
+        let revisions: Vec<(Option<Uuid>, Option<Uuid>)> =
+            sqlx::query_as("SELECT org_id, store_id FROM dc.document_revisions")
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+        assert_eq!(revisions, vec![(Some(org_id), Some(store_id))]);
+    }
+}